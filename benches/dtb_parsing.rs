@@ -96,6 +96,35 @@ fn bench_high_level_api(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_tree_cache(c: &mut Criterion) {
+    let dtb_data = load_test_dtb();
+
+    let mut group = c.benchmark_group("tree_cache");
+
+    // A fresh parser for every iteration parses the tree on each call
+    group.bench_function("uncached_high_level_calls", |b| {
+        b.iter(|| {
+            let parser = DeviceTreeParser::new(&dtb_data);
+            parser.uart_addresses().unwrap();
+            parser.timebase_frequency().unwrap();
+            parser.discover_mmio_regions().unwrap();
+        })
+    });
+
+    // A warmed parser reuses the cached tree across calls
+    let parser = DeviceTreeParser::new(&dtb_data);
+    parser.parsed().unwrap();
+    group.bench_function("cached_high_level_calls", |b| {
+        b.iter(|| {
+            parser.uart_addresses().unwrap();
+            parser.timebase_frequency().unwrap();
+            parser.discover_mmio_regions().unwrap();
+        })
+    });
+
+    group.finish();
+}
+
 fn bench_data_sizes(c: &mut Criterion) {
     let dtb_data = load_test_dtb();
 
@@ -133,6 +162,7 @@ criterion_group!(
     bench_property_access,
     bench_full_pipeline,
     bench_high_level_api,
+    bench_tree_cache,
     bench_data_sizes
 );
 criterion_main!(benches);
@@ -60,24 +60,15 @@ fn parse_header(parser: &DeviceTreeParser) -> Result<(), DtbError> {
 
     let header = parser.parse_header()?;
 
+    println!("{header}");
     println!(
-        "Magic:           0x{:08x} {}",
-        header.magic,
+        "Magic check:     {}",
         if header.magic == 0xd00d_feed {
             "✅ Valid"
         } else {
             "❌ Invalid"
         }
     );
-    println!("Total size:      {} bytes", header.totalsize);
-    println!("Version:         {}", header.version);
-    println!("Last compatible: {}", header.last_comp_version);
-    println!("Boot CPU ID:     {}", header.boot_cpuid_phys);
-    println!("Struct offset:   0x{:x}", header.off_dt_struct);
-    println!("Struct size:     {} bytes", header.size_dt_struct);
-    println!("Strings offset:  0x{:x}", header.off_dt_strings);
-    println!("Strings size:    {} bytes", header.size_dt_strings);
-    println!("Memory rsv:      0x{:x}", header.off_mem_rsvmap);
     println!();
 
     Ok(())
@@ -136,14 +127,17 @@ fn parse_device_tree(parser: &DeviceTreeParser) -> Result<(), DtbError> {
     println!();
 
     // Show interesting nodes
-    show_cpu_information(&tree);
+    show_cpu_information(parser, &tree)?;
     show_memory_information(&tree);
     show_device_summary(&tree);
 
     Ok(())
 }
 
-fn show_cpu_information(tree: &device_tree_parser::DeviceTreeNode) {
+fn show_cpu_information(
+    parser: &DeviceTreeParser,
+    tree: &device_tree_parser::DeviceTreeNode,
+) -> Result<(), DtbError> {
     println!("🖥️  CPU Information");
     println!("─────────────────");
 
@@ -159,31 +153,27 @@ fn show_cpu_information(tree: &device_tree_parser::DeviceTreeNode) {
         }
 
         // Find individual CPUs
-        let mut cpu_count = 0;
-        for cpu in cpus_node {
-            if cpu.prop_string("device_type") == Some("cpu") {
-                cpu_count += 1;
-                println!("  CPU {}: {}", cpu_count - 1, cpu.name);
-
-                if let Some(compatible) = cpu.prop_string("compatible") {
-                    println!("    Compatible: {compatible}");
-                }
-                if let Some(reg) = cpu.prop_u32("reg") {
-                    println!("    Register: {reg}");
-                }
-                if let Some(freq) = cpu.prop_u32("timebase-frequency") {
-                    println!("    Timebase frequency: {freq} Hz");
-                }
+        let cpus = parser.cpus()?;
+        for (i, cpu) in cpus.iter().enumerate() {
+            println!("  CPU {i}: {}", cpu.name);
+
+            if let Some(compatible) = cpu.compatible.first() {
+                println!("    Compatible: {compatible}");
+            }
+            println!("    Register: {}", cpu.reg);
+            if let Some(freq) = cpu.timebase_frequency {
+                println!("    Timebase frequency: {freq} Hz");
             }
         }
 
-        if cpu_count == 0 {
+        if cpus.is_empty() {
             println!("  No CPU nodes found");
         }
     } else {
         println!("No /cpus node found");
     }
     println!();
+    Ok(())
 }
 
 fn show_memory_information(tree: &device_tree_parser::DeviceTreeNode) {
@@ -318,28 +308,27 @@ fn demonstrate_high_level_api(parser: &DeviceTreeParser) -> Result<(), DtbError>
     println!("─────────────────────────");
 
     // Using Index trait for property access
-    if let Some(cpus_node) = tree.find_node("/cpus") {
-        if cpus_node.has_property("#address-cells") {
-            println!(
-                "✅ Using Index trait: #address-cells = {}",
-                cpus_node["#address-cells"].value
-            );
-        }
+    if let Some(cpus_node) = tree.find_node("/cpus")
+        && cpus_node.has_property("#address-cells")
+    {
+        println!(
+            "✅ Using Index trait: #address-cells = {}",
+            cpus_node["#address-cells"].value
+        );
     }
 
     // Using TryFrom for type conversions
     if let Some(memory_node) = tree
         .iter_nodes()
         .find(|n| n.prop_string("device_type") == Some("memory"))
+        && let Some(reg_property) = memory_node.find_property("reg")
     {
-        if let Some(reg_property) = memory_node.find_property("reg") {
-            match Vec::<u32>::try_from(&reg_property.value) {
-                Ok(reg_values) => println!(
-                    "✅ Using TryFrom: parsed {} u32 values from reg property",
-                    reg_values.len()
-                ),
-                Err(_) => println!("❌ Could not convert reg property to Vec<u32>"),
-            }
+        match Vec::<u32>::try_from(&reg_property.value) {
+            Ok(reg_values) => println!(
+                "✅ Using TryFrom: parsed {} u32 values from reg property",
+                reg_values.len()
+            ),
+            Err(_) => println!("❌ Could not convert reg property to Vec<u32>"),
         }
     }
 
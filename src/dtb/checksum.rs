@@ -0,0 +1,33 @@
+// ABOUTME: Table-free CRC-32 (IEEE 802.3) used to fingerprint DTB contents
+// ABOUTME: Bitwise implementation trades speed for zero static tables in no_std builds
+
+/// Reflected CRC-32 polynomial, as used by zlib, Ethernet, and PNG
+const POLYNOMIAL: u32 = 0xEDB8_8320;
+
+/// Compute the CRC-32 of `data`.
+///
+/// Processes one bit at a time instead of using a 1 KiB lookup table. This is
+/// slower, but DTBs are small and the checksum is typically computed once.
+pub(crate) fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (POLYNOMIAL & mask);
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crc32_check_value() {
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(crc32(&[0xd0, 0x0d, 0xfe, 0xed]), 0x5B4C_A720);
+    }
+}
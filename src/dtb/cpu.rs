@@ -0,0 +1,164 @@
+// ABOUTME: CPU enumeration types for nodes under /cpus
+// ABOUTME: Decodes each CPU's reg (hart/core id) using the /cpus #address-cells
+
+use super::error::DtbError;
+use super::tree::DeviceTreeNode;
+use alloc::vec::Vec;
+
+/// Summary of a CPU node under `/cpus`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use device_tree_parser::{DeviceTreeParser, DtbError};
+/// # fn example() -> Result<(), DtbError> {
+/// # let dtb_data = vec![0u8; 64]; // Mock data
+/// let parser = DeviceTreeParser::new(&dtb_data);
+///
+/// for cpu in parser.cpus()? {
+///     println!("CPU {} ({}): {:?}", cpu.reg, cpu.name, cpu.compatible);
+///     if let Some(freq) = cpu.timebase_frequency {
+///         println!("  Timebase: {} Hz", freq);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CpuInfo<'a> {
+    /// Node name (e.g., `cpu@0`).
+    pub name: &'a str,
+    /// CPU identifier from `reg` (hart ID on RISC-V, MPIDR affinity on ARM).
+    pub reg: u64,
+    /// Entries of the `compatible` property.
+    pub compatible: Vec<&'a str>,
+    /// Value of the `device_type` property (normally `"cpu"`).
+    pub device_type: Option<&'a str>,
+    /// Timer frequency in Hz, inherited from `/cpus` when not set on the CPU.
+    pub timebase_frequency: Option<u64>,
+    /// Core clock frequency in Hz.
+    pub clock_frequency: Option<u64>,
+}
+
+impl<'a> CpuInfo<'a> {
+    /// Builds CPU information from a child of `/cpus`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DtbError::InvalidAddressCells` if `/cpus` uses more than two
+    /// address cells, or `DtbError::InvalidRegFormat` if `reg` is missing or
+    /// shorter than the address cell count.
+    pub fn from_node(
+        cpus: &DeviceTreeNode<'a>,
+        cpu: &DeviceTreeNode<'a>,
+    ) -> Result<Self, DtbError> {
+        let address_cells = cpus.address_cells()?;
+        if address_cells > 2 {
+            return Err(DtbError::InvalidAddressCells(address_cells));
+        }
+
+        let reg = cpu
+            .prop_u32_array("reg")
+            .ok_or(DtbError::InvalidRegFormat)?;
+        let cells = reg
+            .get(..address_cells as usize)
+            .ok_or(DtbError::InvalidRegFormat)?;
+        let reg = cells
+            .iter()
+            .fold(0u64, |acc, &cell| (acc << 32) | u64::from(cell));
+
+        Ok(Self {
+            name: cpu.name,
+            reg,
+            compatible: cpu.compatible(),
+            device_type: cpu.prop_string_index("device_type", 0),
+            timebase_frequency: cpu
+                .timebase_frequency()
+                .or_else(|| cpus.timebase_frequency()),
+            clock_frequency: cpu.clock_frequency(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dtb::tree::{Property, PropertyValue};
+
+    fn cpus_node(address_cells: u32) -> DeviceTreeNode<'static> {
+        let mut cpus = DeviceTreeNode::new("cpus");
+        cpus.add_property(Property {
+            name: "#address-cells",
+            value: PropertyValue::U32(address_cells),
+            raw: None,
+        });
+        cpus.add_property(Property {
+            name: "timebase-frequency",
+            value: PropertyValue::U32(10_000_000),
+            raw: None,
+        });
+        cpus
+    }
+
+    fn cpu_node(reg: PropertyValue<'static>) -> DeviceTreeNode<'static> {
+        let mut cpu = DeviceTreeNode::new("cpu@100");
+        cpu.add_property(Property {
+            name: "device_type",
+            value: PropertyValue::String("cpu"),
+            raw: None,
+        });
+        cpu.add_property(Property {
+            name: "compatible",
+            value: PropertyValue::StringList(alloc::vec!["arm,cortex-a53", "arm,armv8"]),
+            raw: None,
+        });
+        cpu.add_property(Property {
+            name: "reg",
+            value: reg,
+            raw: None,
+        });
+        cpu
+    }
+
+    #[test]
+    fn test_cpu_info_single_cell() {
+        let cpus = cpus_node(1);
+        let cpu = cpu_node(PropertyValue::U32(0x100));
+
+        let info = CpuInfo::from_node(&cpus, &cpu).unwrap();
+        assert_eq!(info.name, "cpu@100");
+        assert_eq!(info.reg, 0x100);
+        assert_eq!(info.compatible, ["arm,cortex-a53", "arm,armv8"]);
+        assert_eq!(info.device_type, Some("cpu"));
+        // Inherited from /cpus
+        assert_eq!(info.timebase_frequency, Some(10_000_000));
+        assert_eq!(info.clock_frequency, None);
+    }
+
+    #[test]
+    fn test_cpu_info_two_cells() {
+        let cpus = cpus_node(2);
+        let cpu = cpu_node(PropertyValue::U32Array(&[0, 0, 0, 0x1, 0, 0, 0x1, 0]));
+
+        let info = CpuInfo::from_node(&cpus, &cpu).unwrap();
+        assert_eq!(info.reg, 0x1_0000_0100);
+    }
+
+    #[test]
+    fn test_cpu_info_invalid_reg() {
+        // reg shorter than #address-cells
+        let cpus = cpus_node(2);
+        let cpu = cpu_node(PropertyValue::U32(0x100));
+        assert_eq!(
+            CpuInfo::from_node(&cpus, &cpu).unwrap_err(),
+            DtbError::InvalidRegFormat
+        );
+
+        // IDs wider than 64 bits
+        let cpus = cpus_node(3);
+        assert_eq!(
+            CpuInfo::from_node(&cpus, &cpu).unwrap_err(),
+            DtbError::InvalidAddressCells(3)
+        );
+    }
+}
@@ -0,0 +1,272 @@
+// ABOUTME: Low-level read-only cursor over the DTB structure block
+// ABOUTME: Yields typed begin-node, end-node, and property events without building a tree
+
+use super::error::DtbError;
+use super::parser::locate_struct_error;
+use super::schema::PropKind;
+use super::tokens::DtbToken;
+use super::tree::{Property, parse_node_name, parse_property_data_with, rebase_utf8_error};
+
+/// Event produced by [`StructCursor::next_token`].
+#[derive(Debug, Clone)]
+pub enum StructEvent<'a> {
+    /// Start of a node, carrying its name (empty for the root).
+    BeginNode(&'a str),
+    /// End of the most recently begun node.
+    EndNode,
+    /// Property of the current node.
+    Property(Property<'a>),
+}
+
+/// Read-only cursor over a structure block for building custom parsers.
+///
+/// Walks the tokens one at a time without allocating a tree, which suits
+/// bootloaders and tools that only need a few nodes. `FDT_NOP` tokens are
+/// skipped, and the cursor stops at `FDT_END`. Offsets are relative to the
+/// start of the structure block.
+///
+/// # Examples
+///
+/// ```rust
+/// # use device_tree_parser::{DeviceTreeParser, DtbError, StructEvent};
+/// # fn example() -> Result<(), DtbError> {
+/// # let dtb_data = vec![0u8; 64]; // Mock data
+/// let parser = DeviceTreeParser::new(&dtb_data);
+/// let mut cursor = parser.cursor()?;
+///
+/// while let Some(event) = cursor.next_token()? {
+///     match event {
+///         // Ignore everything under /cpus
+///         StructEvent::BeginNode("cpus") => cursor.skip_node()?,
+///         StructEvent::BeginNode(name) => println!("node {}", name),
+///         StructEvent::Property(prop) => println!("  {} = {}", prop.name, prop.value),
+///         StructEvent::EndNode => {}
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct StructCursor<'a> {
+    struct_block: &'a [u8],
+    strings_block: &'a [u8],
+    schema: &'a [(&'a str, PropKind)],
+    offset: usize,
+    event_offset: usize,
+    finished: bool,
+}
+
+impl<'a> StructCursor<'a> {
+    /// Creates a cursor at the start of `struct_block`.
+    ///
+    /// Property names are resolved against `strings_block`. Use
+    /// [`DeviceTreeParser::cursor`](super::DeviceTreeParser::cursor) to locate
+    /// both blocks from a DTB header.
+    #[must_use]
+    pub fn new(struct_block: &'a [u8], strings_block: &'a [u8]) -> Self {
+        Self {
+            struct_block,
+            strings_block,
+            schema: &[],
+            offset: 0,
+            event_offset: 0,
+            finished: false,
+        }
+    }
+
+    /// Decode properties listed in `schema` by their declared kind.
+    pub(crate) fn with_schema(mut self, schema: &'a [(&'a str, PropKind)]) -> Self {
+        self.schema = schema;
+        self
+    }
+
+    /// Byte offset of the next token within the structure block.
+    #[must_use]
+    pub fn current_offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Reads the next event, or `None` once `FDT_END` has been reached.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError::StructParse`] with the failing offset if a token,
+    /// node name, or property cannot be decoded, [`DtbError::InvalidUtf8`]
+    /// if a name is not valid UTF-8, or [`DtbError::PropertyLengthOverflow`]
+    /// if a property's padding runs past the end of the block.
+    pub fn next_token(&mut self) -> Result<Option<StructEvent<'a>>, DtbError> {
+        while !self.finished {
+            let offset = self.offset;
+            let (remaining, token) =
+                DtbToken::parse_at(self.struct_block, offset).map_err(|e| match e {
+                    DtbError::InvalidToken => DtbError::StructParse {
+                        offset,
+                        reason: "unknown token",
+                    },
+                    other => locate_struct_error(other, offset, "truncated token"),
+                })?;
+            let body_offset = offset + 4;
+            self.event_offset = offset;
+
+            match token {
+                DtbToken::BeginNode => {
+                    let (rest, name) = parse_node_name(remaining)
+                        .map_err(|e| rebase_utf8_error(e, body_offset))
+                        .map_err(|e| locate_struct_error(e, body_offset, "malformed node name"))?;
+                    self.offset = self.struct_block.len() - rest.len();
+                    return Ok(Some(StructEvent::BeginNode(name)));
+                }
+                DtbToken::EndNode => {
+                    self.offset = body_offset;
+                    return Ok(Some(StructEvent::EndNode));
+                }
+                DtbToken::Property => {
+                    let (rest, property) = parse_property_data_with(
+                        remaining,
+                        self.strings_block,
+                        None,
+                        false,
+                        self.schema,
+                    )
+                    .map_err(|e| locate_struct_error(e, offset, "malformed property"))?;
+                    self.offset = self.struct_block.len() - rest.len();
+                    return Ok(Some(StructEvent::Property(property)));
+                }
+                DtbToken::Nop => self.offset = body_offset,
+                DtbToken::End => {
+                    self.offset = body_offset;
+                    self.finished = true;
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Value length of the property returned by the last
+    /// [`next_token`](Self::next_token) call, as stored in the DTB
+    ///
+    /// Unlike the decoded value's length, this counts every byte, including
+    /// empty strings dropped during decoding. Returns 0 if the last event was
+    /// not a property.
+    pub(crate) fn last_property_len(&self) -> usize {
+        match DtbToken::parse_at(self.struct_block, self.event_offset) {
+            Ok((rest, DtbToken::Property)) if rest.len() >= 4 => {
+                u32::from_be_bytes([rest[0], rest[1], rest[2], rest[3]]) as usize
+            }
+            _ => 0,
+        }
+    }
+
+    /// Skips the rest of the node most recently begun, including its children.
+    ///
+    /// Call this right after receiving [`StructEvent::BeginNode`] to jump over
+    /// a whole subtree; the next event is the one following the node's
+    /// `FDT_END_NODE`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError::UnbalancedNodes`] if the structure block ends
+    /// before the node is closed, or any error from
+    /// [`next_token`](Self::next_token).
+    pub fn skip_node(&mut self) -> Result<(), DtbError> {
+        let mut depth = 1usize;
+        while depth > 0 {
+            match self.next_token()? {
+                Some(StructEvent::BeginNode(_)) => depth += 1,
+                Some(StructEvent::EndNode) => depth -= 1,
+                Some(StructEvent::Property(_)) => {}
+                None => return Err(DtbError::UnbalancedNodes),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dtb::DeviceTreeParser;
+    use crate::dtb::test_support::StructBuilder;
+    use crate::dtb::tree::PropertyValue;
+
+    fn sample_dtb() -> alloc::vec::Vec<u8> {
+        StructBuilder::new()
+            .begin_node("")
+            .named_property("model", b"cursor-board\0")
+            .begin_node("cpus")
+            .begin_node("cpu@0")
+            .named_property("reg", &0u32.to_be_bytes())
+            .end_node()
+            .token(DtbToken::Nop)
+            .end_node()
+            .begin_node("uart@1000")
+            .named_property("status", b"okay\0")
+            .end_node()
+            .end_node()
+            .end()
+            .build_dtb()
+    }
+
+    #[test]
+    fn test_cursor_events() {
+        let data = sample_dtb();
+        let parser = DeviceTreeParser::new(&data);
+        let mut cursor = parser.cursor().unwrap();
+
+        assert_eq!(cursor.current_offset(), 0);
+        assert!(matches!(
+            cursor.next_token().unwrap(),
+            Some(StructEvent::BeginNode(""))
+        ));
+        assert_eq!(cursor.current_offset(), 8);
+        match cursor.next_token().unwrap() {
+            Some(StructEvent::Property(prop)) => {
+                assert_eq!(prop.name, "model");
+                assert_eq!(prop.value, PropertyValue::String("cursor-board"));
+            }
+            other => panic!("unexpected event: {other:?}"),
+        }
+        assert!(matches!(
+            cursor.next_token().unwrap(),
+            Some(StructEvent::BeginNode("cpus"))
+        ));
+    }
+
+    #[test]
+    fn test_cursor_skip_node() {
+        let data = sample_dtb();
+        let parser = DeviceTreeParser::new(&data);
+        let mut cursor = parser.cursor().unwrap();
+
+        let mut names = alloc::vec::Vec::new();
+        while let Some(event) = cursor.next_token().unwrap() {
+            match event {
+                StructEvent::BeginNode("cpus") => cursor.skip_node().unwrap(),
+                StructEvent::BeginNode(name) => names.push(name),
+                _ => {}
+            }
+        }
+        // The cpus subtree, including cpu@0 and the NOP, was jumped over
+        assert_eq!(names, ["", "uart@1000"]);
+        assert!(cursor.next_token().unwrap().is_none());
+
+        // Skipping the root consumes everything up to FDT_END
+        let mut cursor = parser.cursor().unwrap();
+        cursor.next_token().unwrap();
+        cursor.skip_node().unwrap();
+        assert!(cursor.next_token().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_cursor_skip_unclosed_node() {
+        let block = StructBuilder::new()
+            .begin_node("")
+            .begin_node("child")
+            .end()
+            .build();
+        let mut cursor = StructCursor::new(&block, &[]);
+        cursor.next_token().unwrap();
+        cursor.next_token().unwrap();
+        assert_eq!(cursor.skip_node().unwrap_err(), DtbError::UnbalancedNodes);
+    }
+}
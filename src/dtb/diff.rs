@@ -0,0 +1,299 @@
+// ABOUTME: Structural comparison of two device trees by node path
+// ABOUTME: Reports added/removed nodes and added/removed/changed properties
+
+use super::tree::{DeviceTreeNode, Property};
+use alloc::{format, string::String, vec::Vec};
+use core::fmt::{self, Display, Formatter};
+
+/// Single difference between two device trees, as reported by
+/// [`DeviceTreeNode::diff`].
+///
+/// Paths are absolute node paths (e.g., `/soc/uart@1000`); the root is `/`.
+/// Nodes and properties are matched by name, so reordering alone is not
+/// reported as a difference.
+///
+/// # Examples
+///
+/// ```rust
+/// # use device_tree_parser::{DeviceTreeNode, TreeDiff};
+/// # fn example(board_a: &DeviceTreeNode, board_b: &DeviceTreeNode) {
+/// for change in board_a.diff(board_b) {
+///     match change {
+///         TreeDiff::PropertyChanged { path, old, new } => {
+///             println!("{}: {} changed from {} to {}", path, old.name, old.value, new.value);
+///         }
+///         other => println!("{}", other),
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub enum TreeDiff<'b> {
+    /// Node present only in the other tree.
+    NodeAdded {
+        /// Path of the added node.
+        path: String,
+        /// The added node.
+        node: &'b DeviceTreeNode<'b>,
+    },
+    /// Node present only in this tree.
+    NodeRemoved {
+        /// Path of the removed node.
+        path: String,
+        /// The removed node.
+        node: &'b DeviceTreeNode<'b>,
+    },
+    /// Property present only in the other tree's node.
+    PropertyAdded {
+        /// Path of the node holding the property.
+        path: String,
+        /// The added property.
+        property: &'b Property<'b>,
+    },
+    /// Property present only in this tree's node.
+    PropertyRemoved {
+        /// Path of the node holding the property.
+        path: String,
+        /// The removed property.
+        property: &'b Property<'b>,
+    },
+    /// Property present in both nodes with different values.
+    PropertyChanged {
+        /// Path of the node holding the property.
+        path: String,
+        /// Property as it appears in this tree.
+        old: &'b Property<'b>,
+        /// Property as it appears in the other tree.
+        new: &'b Property<'b>,
+    },
+}
+
+impl<'b> DeviceTreeNode<'b> {
+    /// Compare this tree with another, returning the differences by path.
+    ///
+    /// `self` is treated as the old tree and `other` as the new one. A removed
+    /// or added node is reported once; its properties and descendants are not
+    /// listed separately.
+    #[must_use]
+    pub fn diff(&'b self, other: &'b DeviceTreeNode<'b>) -> Vec<TreeDiff<'b>> {
+        let mut diffs = Vec::new();
+        diff_nodes(self, other, "", &mut diffs);
+        diffs
+    }
+
+    /// Check whether two trees have the same content.
+    ///
+    /// Compares node names, property values, and children recursively.
+    /// Property order within a node is ignored, while children must appear in
+    /// the same order. Unlike [`diff`](Self::diff), the root names are
+    /// compared too and nothing is allocated.
+    #[must_use]
+    pub fn structurally_eq(&self, other: &DeviceTreeNode<'_>) -> bool {
+        self.name == other.name
+            && self.properties.len() == other.properties.len()
+            && self.properties.iter().all(|prop| {
+                other
+                    .find_property(prop.name)
+                    .is_some_and(|o| o.value == prop.value)
+            })
+            && self.children.len() == other.children.len()
+            && self
+                .children
+                .iter()
+                .zip(&other.children)
+                .all(|(a, b)| a.structurally_eq(b))
+    }
+}
+
+fn diff_nodes<'b>(
+    old: &'b DeviceTreeNode<'b>,
+    new: &'b DeviceTreeNode<'b>,
+    path: &str,
+    diffs: &mut Vec<TreeDiff<'b>>,
+) {
+    let node_path = || {
+        if path.is_empty() {
+            String::from("/")
+        } else {
+            String::from(path)
+        }
+    };
+
+    for old_prop in &old.properties {
+        match new.find_property(old_prop.name) {
+            None => diffs.push(TreeDiff::PropertyRemoved {
+                path: node_path(),
+                property: old_prop,
+            }),
+            Some(new_prop) if new_prop.value != old_prop.value => {
+                diffs.push(TreeDiff::PropertyChanged {
+                    path: node_path(),
+                    old: old_prop,
+                    new: new_prop,
+                });
+            }
+            Some(_) => {}
+        }
+    }
+    for new_prop in &new.properties {
+        if old.find_property(new_prop.name).is_none() {
+            diffs.push(TreeDiff::PropertyAdded {
+                path: node_path(),
+                property: new_prop,
+            });
+        }
+    }
+
+    for old_child in &old.children {
+        let child_path = format!("{path}/{}", old_child.name);
+        match new.find_child(old_child.name) {
+            Some(new_child) => diff_nodes(old_child, new_child, &child_path, diffs),
+            None => diffs.push(TreeDiff::NodeRemoved {
+                path: child_path,
+                node: old_child,
+            }),
+        }
+    }
+    for new_child in &new.children {
+        if old.find_child(new_child.name).is_none() {
+            diffs.push(TreeDiff::NodeAdded {
+                path: format!("{path}/{}", new_child.name),
+                node: new_child,
+            });
+        }
+    }
+}
+
+/// Formats differences in a unified-diff style (`+`, `-`, `~`).
+impl Display for TreeDiff<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TreeDiff::NodeAdded { path, .. } => write!(f, "+ {path}"),
+            TreeDiff::NodeRemoved { path, .. } => write!(f, "- {path}"),
+            TreeDiff::PropertyAdded { path, property } => write!(f, "+ {path}: {property}"),
+            TreeDiff::PropertyRemoved { path, property } => write!(f, "- {path}: {property}"),
+            TreeDiff::PropertyChanged { path, old, new } => {
+                write!(f, "~ {path}: {} = {} -> {}", old.name, old.value, new.value)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dtb::tree::PropertyValue;
+    use alloc::string::ToString;
+
+    fn board(frequency: u32) -> DeviceTreeNode<'static> {
+        let mut uart = DeviceTreeNode::new("uart@1000");
+        uart.add_property(Property {
+            name: "compatible",
+            value: PropertyValue::String("ns16550a"),
+            raw: None,
+        });
+        uart.add_property(Property {
+            name: "clock-frequency",
+            value: PropertyValue::U32(frequency),
+            raw: None,
+        });
+
+        let mut soc = DeviceTreeNode::new("soc");
+        soc.add_child(uart);
+
+        let mut root = DeviceTreeNode::new("");
+        root.add_property(Property {
+            name: "model",
+            value: PropertyValue::String("test-board"),
+            raw: None,
+        });
+        root.add_child(soc);
+        root
+    }
+
+    #[test]
+    fn test_diff_identical() {
+        let a = board(1_843_200);
+        let b = board(1_843_200);
+        assert!(a.diff(&b).is_empty());
+    }
+
+    #[test]
+    fn test_diff_changed_property() {
+        let a = board(1_843_200);
+        let b = board(3_686_400);
+
+        let diffs = a.diff(&b);
+        assert_eq!(diffs.len(), 1);
+        match &diffs[0] {
+            TreeDiff::PropertyChanged { path, old, new } => {
+                assert_eq!(path, "/soc/uart@1000");
+                assert_eq!(old.name, "clock-frequency");
+                assert_eq!(old.value, PropertyValue::U32(1_843_200));
+                assert_eq!(new.value, PropertyValue::U32(3_686_400));
+            }
+            other => panic!("unexpected diff: {other:?}"),
+        }
+        assert_eq!(
+            diffs[0].to_string(),
+            "~ /soc/uart@1000: clock-frequency = 0x1c2000 -> 0x384000"
+        );
+    }
+
+    #[test]
+    fn test_structurally_eq() {
+        let a = board(1_843_200);
+        assert!(a.structurally_eq(&board(1_843_200)));
+        assert!(!a.structurally_eq(&board(3_686_400)));
+
+        // Property order does not matter
+        let mut b = board(1_843_200);
+        b.children[0].children[0].properties.reverse();
+        assert!(a.structurally_eq(&b));
+
+        // An extra property, child, or a renamed node does
+        let mut b = board(1_843_200);
+        b.add_property(Property {
+            name: "serial-number",
+            value: PropertyValue::String("1234"),
+            raw: None,
+        });
+        assert!(!a.structurally_eq(&b));
+        let mut b = board(1_843_200);
+        b.add_child(DeviceTreeNode::new("chosen"));
+        assert!(!a.structurally_eq(&b));
+        let mut b = board(1_843_200);
+        b.children[0].name = "bus";
+        assert!(!a.structurally_eq(&b));
+    }
+
+    #[test]
+    fn test_diff_added_and_removed() {
+        let a = board(1_843_200);
+        let mut b = board(1_843_200);
+        b.remove_property("model");
+        b.add_property(Property {
+            name: "serial-number",
+            value: PropertyValue::String("1234"),
+            raw: None,
+        });
+        b.children[0].children.clear();
+        b.add_child(DeviceTreeNode::new("chosen"));
+
+        let diffs = a.diff(&b);
+        let lines: Vec<String> = diffs.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            lines,
+            [
+                "- /: model = \"test-board\"",
+                "+ /: serial-number = \"1234\"",
+                "- /soc/uart@1000",
+                "+ /chosen",
+            ]
+        );
+        assert!(matches!(
+            diffs[2],
+            TreeDiff::NodeRemoved { node, .. } if node.name == "uart@1000"
+        ));
+    }
+}
@@ -1,6 +1,7 @@
 // ABOUTME: Error types for device tree blob parsing
 // ABOUTME: Provides no_std compatible error handling for DTB operations
 
+use alloc::string::String;
 use core::fmt;
 
 /// Comprehensive error type for Device Tree Blob parsing operations.
@@ -30,6 +31,7 @@ use core::fmt;
 ///
 /// Some errors may be recoverable depending on use case:
 /// - `InvalidMagic`: File is not a DTB, try other formats
+/// - `WrongEndianness`: File is a byte-swapped DTB, swap it back to big-endian
 /// - `MalformedHeader`: File may be truncated or corrupted
 /// - `InvalidToken`: Specific node/property may be malformed
 /// - `AlignmentError`: Data corruption or non-standard formatting
@@ -42,6 +44,14 @@ pub enum DtbError {
     /// every DTB file.
     InvalidMagic,
 
+    /// DTB data is byte-swapped.
+    ///
+    /// DTBs are always big-endian. This error occurs when the magic number
+    /// reads as `0xedfe0dd0`, which means the data was written or copied with
+    /// the wrong byte order (for example, by a tool that swapped every 32-bit
+    /// word). Swapping the bytes back usually recovers a valid DTB.
+    WrongEndianness,
+
     /// Malformed or corrupted DTB header structure.
     ///
     /// The DTB header contains critical metadata about file layout. This error
@@ -91,6 +101,12 @@ pub enum DtbError {
     /// This error indicates malformed ranges data.
     InvalidRangesFormat,
 
+    /// Invalid reg property format.
+    ///
+    /// The reg property is missing, holds fewer cells than the parent's
+    /// `#address-cells` requires, or is not a whole number of entries.
+    InvalidRegFormat,
+
     /// Translation cycle detected.
     ///
     /// Occurs when multi-level address translation encounters a circular
@@ -103,12 +119,146 @@ pub enum DtbError {
     /// Occurs when multi-level address translation exceeds the maximum
     /// allowed recursion depth, preventing potential stack overflow.
     MaxTranslationDepthExceeded,
+
+    /// Node nesting exceeds the configured maximum depth.
+    ///
+    /// Occurs when the structure block nests nodes deeper than
+    /// `ParseOptions::max_depth`, which protects against crafted DTBs
+    /// designed to exhaust memory.
+    NestingTooDeep,
+
+    /// Node count exceeds the configured maximum.
+    ///
+    /// Occurs when the structure block contains more nodes than
+    /// `ParseOptions::max_nodes`.
+    TooManyNodes,
+
+    /// Property value length exceeds the configured maximum.
+    ///
+    /// Occurs when a property declares a value longer than
+    /// `ParseOptions::max_property_len` bytes.
+    PropertyTooLarge,
+
+    /// Mismatched `FDT_BEGIN_NODE` and `FDT_END_NODE` tokens.
+    ///
+    /// Occurs when the structure block ends with nodes still open, or when
+    /// an `FDT_END_NODE` token appears without a matching `FDT_BEGIN_NODE`.
+    UnbalancedNodes,
+
+    /// Node defines the same property name more than once.
+    ///
+    /// Only reported when `ParseOptions::reject_duplicate_properties` is
+    /// enabled; otherwise the last definition is kept.
+    DuplicateProperty,
+
+    /// Invalid `#address-cells` or `#size-cells` property found while parsing.
+    ///
+    /// Only reported when `ParseOptions::validate_cells` is enabled. Carries
+    /// the path of the offending node and the name of the property.
+    InvalidCells {
+        /// Path of the node defining the property (e.g., `/soc`).
+        path: String,
+        /// Either `#address-cells` or `#size-cells`.
+        property: &'static str,
+    },
+
+    /// Malformed device tree overlay.
+    ///
+    /// Occurs when an overlay fragment has no `target` or `target-path`, or
+    /// when a `__fixups__` or `__local_fixups__` entry is malformed or refers
+    /// to a location that does not exist in the overlay.
+    InvalidOverlay,
+
+    /// Overlay fragment target does not exist in the base tree.
+    ///
+    /// Occurs when a fragment's `target` phandle or `target-path` does not
+    /// match any node in the base device tree.
+    OverlayTargetNotFound,
+
+    /// Overlay references a label missing from the base tree.
+    ///
+    /// Occurs when a `__fixups__` label has no entry in the base tree's
+    /// `__symbols__` node, or the labelled node has no `phandle` property.
+    UnresolvedSymbol,
+
+    /// String in the DTB is not valid UTF-8.
+    ///
+    /// Occurs when a node name or a property name in the strings block
+    /// contains invalid UTF-8. `offset` is the position of the first invalid
+    /// byte, relative to the start of the block holding the string (the
+    /// structure block for node names, the strings block for property names).
+    InvalidUtf8 {
+        /// Byte offset of the first invalid byte within its block.
+        offset: usize,
+    },
+
+    /// Malformed data in the structure block.
+    ///
+    /// Occurs when a token, node name, or property header cannot be decoded
+    /// while building the tree. `offset` is the byte position of the failing
+    /// item relative to the start of the structure block, which makes
+    /// corrupted vendor DTBs much easier to inspect with a hex dump.
+    StructParse {
+        /// Byte offset of the failing item within the structure block.
+        offset: usize,
+        /// Short description of what could not be decoded.
+        reason: &'static str,
+    },
+
+    /// Requested property does not exist on the node.
+    ///
+    /// Returned by strict accessors such as
+    /// `DeviceTreeNode::prop_u32_array_checked`; the lenient `prop_*`
+    /// accessors return `None` instead.
+    PropertyNotFound,
+
+    /// Property length does not fit the requested encoding.
+    ///
+    /// Occurs when a property read as 32-bit cells holds a number of bytes
+    /// that is not a multiple of 4, which usually means the value was
+    /// truncated. Carries the actual length in bytes.
+    InvalidPropertyLength(usize),
+
+    /// Property padding runs past the end of the structure block.
+    ///
+    /// Occurs when a property's value fits in the data left in the block but
+    /// the padding that aligns the next token to 4 bytes does not. Carries
+    /// the declared length in bytes.
+    PropertyLengthOverflow(usize),
+
+    /// Memory reservation region is empty or out of range.
+    ///
+    /// Returned by `MemoryReservation::new` when the size is zero or the
+    /// region's end overflows the 64-bit address space.
+    InvalidReservation,
+
+    /// No node has the referenced phandle.
+    ///
+    /// Occurs when a property such as `gpios` refers to a provider by a
+    /// phandle that no node declares. Carries the missing phandle.
+    PhandleNotFound(u32),
+
+    /// Malformed phandle + specifier list.
+    ///
+    /// Occurs when a referenced provider lacks the `#...-cells` property that
+    /// sizes its specifiers, or when the list ends partway through an entry.
+    InvalidSpecifier,
+
+    /// Memory reservation block has no `(0, 0)` terminating entry.
+    ///
+    /// Only reported by strict parsing; the lenient parser stops at the end
+    /// of the reservation block instead.
+    UnterminatedReservations,
 }
 
 impl fmt::Display for DtbError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             DtbError::InvalidMagic => write!(f, "Invalid magic number in DTB header"),
+            DtbError::WrongEndianness => write!(
+                f,
+                "DTB is byte-swapped (magic 0xedfe0dd0); DTBs must be big-endian"
+            ),
             DtbError::MalformedHeader => write!(f, "Malformed DTB header structure"),
             DtbError::InvalidToken => write!(f, "Invalid token in structure block"),
             DtbError::AlignmentError => write!(f, "Data alignment error"),
@@ -124,12 +274,67 @@ impl fmt::Display for DtbError {
             DtbError::InvalidRangesFormat => {
                 write!(f, "Invalid ranges property format")
             }
+            DtbError::InvalidRegFormat => {
+                write!(f, "Invalid reg property format")
+            }
             DtbError::TranslationCycle => {
                 write!(f, "Translation cycle detected in device tree hierarchy")
             }
             DtbError::MaxTranslationDepthExceeded => {
                 write!(f, "Maximum translation depth exceeded")
             }
+            DtbError::NestingTooDeep => write!(f, "Maximum node nesting depth exceeded"),
+            DtbError::TooManyNodes => write!(f, "Maximum node count exceeded"),
+            DtbError::PropertyTooLarge => write!(f, "Property value exceeds maximum length"),
+            DtbError::UnbalancedNodes => {
+                write!(f, "Unbalanced begin/end node tokens in structure block")
+            }
+            DtbError::DuplicateProperty => write!(f, "Duplicate property name in node"),
+            DtbError::InvalidCells { path, property } => {
+                write!(f, "Invalid {property} value in node {path}")
+            }
+            DtbError::InvalidOverlay => write!(f, "Malformed device tree overlay"),
+            DtbError::OverlayTargetNotFound => {
+                write!(f, "Overlay fragment target not found in base tree")
+            }
+            DtbError::UnresolvedSymbol => {
+                write!(f, "Overlay symbol not found in base tree")
+            }
+            DtbError::InvalidUtf8 { offset } => {
+                write!(f, "Invalid UTF-8 in string at offset {offset}")
+            }
+            DtbError::StructParse { offset, reason } => {
+                write!(
+                    f,
+                    "Structure block parse error at offset {offset}: {reason}"
+                )
+            }
+            DtbError::PropertyNotFound => write!(f, "Property not found"),
+            DtbError::PhandleNotFound(phandle) => {
+                write!(f, "No node with phandle 0x{phandle:x}")
+            }
+            DtbError::InvalidSpecifier => write!(f, "Malformed phandle specifier list"),
+            DtbError::UnterminatedReservations => {
+                write!(f, "Memory reservation block is missing its terminator")
+            }
+            DtbError::InvalidPropertyLength(len) => {
+                write!(
+                    f,
+                    "Invalid property length: {len} bytes (must be a multiple of 4)"
+                )
+            }
+            DtbError::PropertyLengthOverflow(len) => {
+                write!(
+                    f,
+                    "Property length {len} plus padding runs past the end of the structure block"
+                )
+            }
+            DtbError::InvalidReservation => {
+                write!(
+                    f,
+                    "Invalid memory reservation: zero size or address overflow"
+                )
+            }
         }
     }
 }
@@ -0,0 +1,144 @@
+// ABOUTME: GPIO specifier decoding for gpios and *-gpios properties
+// ABOUTME: Splits phandle + specifier cells using each controller's #gpio-cells
+
+use super::error::DtbError;
+use super::parser::DeviceTreeParser;
+use super::tree::DeviceTreeNode;
+use alloc::vec::Vec;
+
+/// GPIO reference decoded from a `gpios` or `*-gpios` property.
+///
+/// Each entry of such a property is the controller's phandle followed by
+/// `#gpio-cells` specifier cells. By convention the first cell is the pin
+/// number and the second holds flags (bit 0 set means active-low).
+///
+/// # Examples
+///
+/// ```rust
+/// # use device_tree_parser::{DeviceTreeNode, DeviceTreeParser, DtbError};
+/// # fn example(parser: &DeviceTreeParser, led: &DeviceTreeNode) -> Result<(), DtbError> {
+/// for gpio in led.gpios("gpios", parser)? {
+///     println!("Controller {} pin {} flags {:#x}", gpio.controller, gpio.pin, gpio.flags);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GpioSpec {
+    /// Phandle of the GPIO controller.
+    pub controller: u32,
+    /// Pin number within the controller (first specifier cell).
+    pub pin: u32,
+    /// Flags from the second specifier cell, or 0 if the controller uses a
+    /// single cell.
+    pub flags: u32,
+}
+
+impl DeviceTreeNode<'_> {
+    /// Decode a `gpios` or `*-gpios` property (e.g., `reset-gpios`).
+    ///
+    /// `name` is the full property name. Each controller is looked up by
+    /// phandle in `parser`'s tree, and its `#gpio-cells` decides how many
+    /// cells follow the phandle. Entries with a zero phandle are placeholders
+    /// and are skipped. Returns an empty list if the property is absent.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError::PhandleNotFound`] if no node has a referenced
+    /// phandle, [`DtbError::InvalidSpecifier`] if a controller lacks
+    /// `#gpio-cells` or the property ends mid-entry, and
+    /// [`DtbError::InvalidPropertyLength`] if the property is not made of
+    /// whole cells.
+    pub fn gpios(
+        &self,
+        name: &str,
+        parser: &DeviceTreeParser<'_>,
+    ) -> Result<Vec<GpioSpec>, DtbError> {
+        let specs = parser
+            .parse_specifier_list(self, name, "#gpio-cells")?
+            .into_iter()
+            .map(|(controller, args)| GpioSpec {
+                controller,
+                pin: args.first().copied().unwrap_or(0),
+                flags: args.get(1).copied().unwrap_or(0),
+            })
+            .collect();
+        Ok(specs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dtb::test_support::{StructBuilder, cells};
+
+    fn gpio_dtb(led_gpios: &[u32]) -> Vec<u8> {
+        StructBuilder::new()
+            .begin_node("")
+            .begin_node("gpio@1000")
+            .named_property("gpio-controller", &[])
+            .named_property("#gpio-cells", &cells(&[2]))
+            .named_property("phandle", &cells(&[1]))
+            .end_node()
+            .begin_node("gpio@2000")
+            .named_property("gpio-controller", &[])
+            .named_property("#gpio-cells", &cells(&[1]))
+            .named_property("phandle", &cells(&[2]))
+            .end_node()
+            .begin_node("led")
+            .named_property("gpios", &cells(led_gpios))
+            .end_node()
+            .end_node()
+            .end()
+            .build_dtb()
+    }
+
+    #[test]
+    fn test_gpios() {
+        let data = gpio_dtb(&[1, 5, 1, 0, 2, 9, 1, 7, 0]);
+        let parser = DeviceTreeParser::new(&data);
+        let led = parser.find_node_ref("/led").unwrap().unwrap();
+
+        assert_eq!(
+            led.gpios("gpios", &parser).unwrap(),
+            [
+                GpioSpec {
+                    controller: 1,
+                    pin: 5,
+                    flags: 1,
+                },
+                GpioSpec {
+                    controller: 2,
+                    pin: 9,
+                    flags: 0,
+                },
+                GpioSpec {
+                    controller: 1,
+                    pin: 7,
+                    flags: 0,
+                },
+            ]
+        );
+        assert!(led.gpios("reset-gpios", &parser).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_gpios_errors() {
+        let data = gpio_dtb(&[3, 5, 0]);
+        let parser = DeviceTreeParser::new(&data);
+        let led = parser.find_node_ref("/led").unwrap().unwrap();
+        assert_eq!(
+            led.gpios("gpios", &parser).unwrap_err(),
+            DtbError::PhandleNotFound(3)
+        );
+
+        // Controller 1 expects two specifier cells
+        let data = gpio_dtb(&[1, 5]);
+        let parser = DeviceTreeParser::new(&data);
+        let led = parser.find_node_ref("/led").unwrap().unwrap();
+        assert_eq!(
+            led.gpios("gpios", &parser).unwrap_err(),
+            DtbError::InvalidSpecifier
+        );
+    }
+}
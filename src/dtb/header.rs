@@ -2,6 +2,8 @@
 // ABOUTME: Handles the 40-byte device tree blob header format
 
 use super::error::DtbError;
+use core::fmt::{self, Display, Formatter};
+use core::ops::Range;
 
 /// Device Tree Blob header containing file metadata and block layout.
 ///
@@ -83,21 +85,35 @@ impl DtbHeader {
     /// DTB magic number constant
     pub const MAGIC: u32 = 0xd00d_feed;
 
+    /// DTB magic number as read from byte-swapped (little-endian) data
+    pub const MAGIC_SWAPPED: u32 = Self::MAGIC.swap_bytes();
+
     /// Header size in bytes
     pub const SIZE: usize = 40;
 
+    /// Header size in bytes for versions before 17 (no `size_dt_struct` field)
+    pub const V16_SIZE: usize = 36;
+
+    /// First DTB version whose header contains the `size_dt_struct` field
+    pub const SIZE_DT_STRUCT_VERSION: u32 = 17;
+
     /// Parse DTB header from input bytes
     ///
+    /// Headers older than version 17 do not carry the `size_dt_struct` field.
+    /// For those, the structure block size is derived from the block offsets
+    /// and the header is treated as 36 bytes long.
+    ///
     /// # Errors
     ///
     /// Returns `DtbError::MalformedHeader` if input is too short or contains invalid data.
-    /// Returns `DtbError::InvalidMagic` if the magic number is incorrect.
+    /// Returns `DtbError::WrongEndianness` if the magic number is byte-swapped.
+    /// Returns `DtbError::InvalidMagic` if the magic number is otherwise incorrect.
     ///
     /// # Panics
     ///
     /// Panics if internal slice operations fail due to data corruption.
     pub fn parse(input: &[u8]) -> Result<(&[u8], Self), DtbError> {
-        if input.len() < Self::SIZE {
+        if input.len() < Self::V16_SIZE {
             return Err(DtbError::MalformedHeader);
         }
 
@@ -110,6 +126,9 @@ impl DtbHeader {
         let mut chunks = input.chunks_exact(4);
 
         let magic = read_be_u32(chunks.next().ok_or(DtbError::MalformedHeader)?);
+        if magic == Self::MAGIC_SWAPPED {
+            return Err(DtbError::WrongEndianness);
+        }
         if magic != Self::MAGIC {
             return Err(DtbError::InvalidMagic);
         }
@@ -122,7 +141,23 @@ impl DtbHeader {
         let last_comp_version = read_be_u32(chunks.next().ok_or(DtbError::MalformedHeader)?);
         let boot_cpuid_phys = read_be_u32(chunks.next().ok_or(DtbError::MalformedHeader)?);
         let size_dt_strings = read_be_u32(chunks.next().ok_or(DtbError::MalformedHeader)?);
-        let size_dt_struct = read_be_u32(chunks.next().ok_or(DtbError::MalformedHeader)?);
+
+        let (header_size, size_dt_struct) = if version >= Self::SIZE_DT_STRUCT_VERSION {
+            let size_dt_struct = read_be_u32(chunks.next().ok_or(DtbError::MalformedHeader)?);
+            (Self::SIZE, size_dt_struct)
+        } else {
+            // The structure block runs up to the strings block when it precedes
+            // it, otherwise up to the end of the blob
+            let end = if off_dt_strings > off_dt_struct {
+                off_dt_strings
+            } else {
+                totalsize
+            };
+            let size_dt_struct = end
+                .checked_sub(off_dt_struct)
+                .ok_or(DtbError::MalformedHeader)?;
+            (Self::V16_SIZE, size_dt_struct)
+        };
 
         let header = DtbHeader {
             magic,
@@ -137,14 +172,173 @@ impl DtbHeader {
             size_dt_struct,
         };
 
-        Ok((&input[Self::SIZE..], header))
+        Ok((&input[header_size..], header))
+    }
+
+    /// Serialize the header to its 40-byte big-endian form
+    ///
+    /// Fields are written in the order listed in the layout table. The
+    /// `size_dt_struct` field is always included, so headers older than
+    /// version 17 still serialize to 40 bytes; parsers for those versions
+    /// ignore the final field.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DtbError, DtbHeader};
+    /// # fn example(header: &DtbHeader) -> Result<(), DtbError> {
+    /// let bytes = header.to_bytes();
+    /// let (_, reparsed) = DtbHeader::parse(&bytes)?;
+    /// assert_eq!(&reparsed, header);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; Self::SIZE] {
+        let fields = [
+            self.magic,
+            self.totalsize,
+            self.off_dt_struct,
+            self.off_dt_strings,
+            self.off_mem_rsvmap,
+            self.version,
+            self.last_comp_version,
+            self.boot_cpuid_phys,
+            self.size_dt_strings,
+            self.size_dt_struct,
+        ];
+        let mut bytes = [0u8; Self::SIZE];
+        for (chunk, field) in bytes.chunks_exact_mut(4).zip(fields) {
+            chunk.copy_from_slice(&field.to_be_bytes());
+        }
+        bytes
+    }
+
+    /// Returns whether the header carries the `size_dt_struct` field.
+    ///
+    /// The field was introduced in version 17. For older headers the
+    /// `size_dt_struct` value is derived from the block offsets during parsing.
+    #[must_use]
+    pub const fn supports_size_dt_struct(&self) -> bool {
+        self.version >= Self::SIZE_DT_STRUCT_VERSION
+    }
+
+    /// Checks that every block offset is suitably aligned.
+    ///
+    /// The memory reservation block must start on an 8-byte boundary and the
+    /// structure block on a 4-byte boundary. The strings block is also
+    /// required to be 4-byte aligned: the specification does not demand it,
+    /// but it follows the structure block, whose size is a multiple of 4, in
+    /// every blob `dtc` writes, so a misaligned offset points to a corrupt
+    /// header. Offsets are relative to the start of the DTB.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DtbError::AlignmentError` if any offset is misaligned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeParser, DtbError};
+    /// # fn example() -> Result<(), DtbError> {
+    /// # let dtb_data = vec![0u8; 64]; // Mock data
+    /// let parser = DeviceTreeParser::new(&dtb_data);
+    /// parser.parse_header()?.validate_alignment()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn validate_alignment(&self) -> Result<(), DtbError> {
+        if !self.off_mem_rsvmap.is_multiple_of(8)
+            || !self.off_dt_struct.is_multiple_of(4)
+            || !self.off_dt_strings.is_multiple_of(4)
+        {
+            return Err(DtbError::AlignmentError);
+        }
+        Ok(())
+    }
+
+    /// Returns the byte range of the structure block within the DTB.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DtbError::MalformedHeader` if the block extends past `totalsize`.
+    /// Returns `DtbError::AlignmentError` if the block does not start on a
+    /// 4-byte boundary relative to the start of the DTB.
+    pub fn struct_block_range(&self) -> Result<Range<usize>, DtbError> {
+        if !self.off_dt_struct.is_multiple_of(4) {
+            return Err(DtbError::AlignmentError);
+        }
+        self.block_range(self.off_dt_struct, self.size_dt_struct)
+    }
+
+    /// Returns the byte range of the strings block within the DTB.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DtbError::MalformedHeader` if the block extends past `totalsize`.
+    pub fn strings_block_range(&self) -> Result<Range<usize>, DtbError> {
+        self.block_range(self.off_dt_strings, self.size_dt_strings)
+    }
+
+    /// Returns the byte offset of the memory reservation block within the DTB.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DtbError::MalformedHeader` if the offset is not within `totalsize`.
+    /// Returns `DtbError::AlignmentError` if the block does not start on an
+    /// 8-byte boundary relative to the start of the DTB.
+    pub fn mem_rsvmap_offset(&self) -> Result<usize, DtbError> {
+        if self.off_mem_rsvmap >= self.totalsize {
+            return Err(DtbError::MalformedHeader);
+        }
+        if !self.off_mem_rsvmap.is_multiple_of(8) {
+            return Err(DtbError::AlignmentError);
+        }
+        Ok(self.off_mem_rsvmap as usize)
+    }
+
+    /// Validate a block's offset and size against `totalsize`
+    fn block_range(&self, offset: u32, size: u32) -> Result<Range<usize>, DtbError> {
+        let end = offset.checked_add(size).ok_or(DtbError::MalformedHeader)?;
+        if end > self.totalsize {
+            return Err(DtbError::MalformedHeader);
+        }
+        Ok(offset as usize..end as usize)
+    }
+}
+
+/// Prints one field per line, with offsets in hex and sizes in bytes.
+///
+/// # Examples
+///
+/// ```rust
+/// # use device_tree_parser::{DeviceTreeParser, DtbError};
+/// # fn example() -> Result<(), DtbError> {
+/// # let dtb_data = vec![0u8; 64]; // Mock data
+/// let header = DeviceTreeParser::new(&dtb_data).parse_header()?;
+/// println!("{header}");
+/// # Ok(())
+/// # }
+/// ```
+impl Display for DtbHeader {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Magic:           0x{:08x}", self.magic)?;
+        writeln!(f, "Total size:      {} bytes", self.totalsize)?;
+        writeln!(f, "Version:         {}", self.version)?;
+        writeln!(f, "Last compatible: {}", self.last_comp_version)?;
+        writeln!(f, "Boot CPU ID:     {}", self.boot_cpuid_phys)?;
+        writeln!(f, "Struct offset:   0x{:x}", self.off_dt_struct)?;
+        writeln!(f, "Struct size:     {} bytes", self.size_dt_struct)?;
+        writeln!(f, "Strings offset:  0x{:x}", self.off_dt_strings)?;
+        writeln!(f, "Strings size:    {} bytes", self.size_dt_strings)?;
+        write!(f, "Memory rsv:      0x{:x}", self.off_mem_rsvmap)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use alloc::vec;
+    use alloc::{string::ToString, vec};
 
     #[test]
     fn test_header_parse_valid() {
@@ -170,4 +364,238 @@ mod tests {
         let result = DtbHeader::parse(&header_data);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_header_parse_byte_swapped() {
+        let mut header_data = vec![0u8; 40];
+        // Header written with little-endian fields
+        header_data[0..4].copy_from_slice(&0xd00dfeedu32.to_le_bytes());
+        header_data[4..8].copy_from_slice(&1024u32.to_le_bytes());
+
+        assert_eq!(
+            DtbHeader::parse(&header_data).unwrap_err(),
+            DtbError::WrongEndianness
+        );
+        assert_eq!(DtbHeader::MAGIC_SWAPPED, 0xedfe_0dd0);
+    }
+
+    #[test]
+    fn test_header_parse_v16() {
+        let mut header_data = vec![0u8; 36];
+        header_data[0..4].copy_from_slice(&0xd00dfeedu32.to_be_bytes());
+        // totalsize
+        header_data[4..8].copy_from_slice(&0x200u32.to_be_bytes());
+        // off_dt_struct
+        header_data[8..12].copy_from_slice(&0x38u32.to_be_bytes());
+        // off_dt_strings
+        header_data[12..16].copy_from_slice(&0x180u32.to_be_bytes());
+        // off_mem_rsvmap
+        header_data[16..20].copy_from_slice(&0x28u32.to_be_bytes());
+        // version, last_comp_version
+        header_data[20..24].copy_from_slice(&16u32.to_be_bytes());
+        header_data[24..28].copy_from_slice(&16u32.to_be_bytes());
+        // size_dt_strings
+        header_data[32..36].copy_from_slice(&0x40u32.to_be_bytes());
+        header_data.extend_from_slice(&[0xAA; 4]);
+
+        let (remaining, header) = DtbHeader::parse(&header_data).unwrap();
+        assert_eq!(header.version, 16);
+        assert!(!header.supports_size_dt_struct());
+        // Structure block runs up to the strings block
+        assert_eq!(header.size_dt_struct, 0x180 - 0x38);
+        assert_eq!(header.size_dt_strings, 0x40);
+        // The v16 header is 36 bytes long
+        assert_eq!(remaining, &[0xAA; 4]);
+
+        // Strings before structure: structure runs to the end of the blob
+        header_data[12..16].copy_from_slice(&0x30u32.to_be_bytes());
+        let (_, header) = DtbHeader::parse(&header_data).unwrap();
+        assert_eq!(header.size_dt_struct, 0x200 - 0x38);
+    }
+
+    #[test]
+    fn test_header_parse_v17_size_dt_struct() {
+        let mut header_data = vec![0u8; 40];
+        header_data[0..4].copy_from_slice(&0xd00dfeedu32.to_be_bytes());
+        header_data[20..24].copy_from_slice(&17u32.to_be_bytes());
+        header_data[36..40].copy_from_slice(&0x1234u32.to_be_bytes());
+
+        let (_, header) = DtbHeader::parse(&header_data).unwrap();
+        assert!(header.supports_size_dt_struct());
+        assert_eq!(header.size_dt_struct, 0x1234);
+
+        // A v17 header must be the full 40 bytes
+        assert!(matches!(
+            DtbHeader::parse(&header_data[..36]),
+            Err(DtbError::MalformedHeader)
+        ));
+    }
+
+    #[test]
+    fn test_header_to_bytes_round_trip() {
+        let header = DtbHeader {
+            magic: DtbHeader::MAGIC,
+            totalsize: 0x200,
+            off_dt_struct: 0x38,
+            off_dt_strings: 0x180,
+            off_mem_rsvmap: 0x28,
+            version: 17,
+            last_comp_version: 16,
+            boot_cpuid_phys: 1,
+            size_dt_strings: 0x40,
+            size_dt_struct: 0x148,
+        };
+        let bytes = header.to_bytes();
+        assert_eq!(bytes[0..4], [0xd0, 0x0d, 0xfe, 0xed]);
+        assert_eq!(bytes[36..40], 0x148u32.to_be_bytes());
+
+        let (remaining, reparsed) = DtbHeader::parse(&bytes).unwrap();
+        assert!(remaining.is_empty());
+        assert_eq!(reparsed, header);
+        assert_eq!(reparsed.to_bytes(), bytes);
+
+        // A v16 header keeps its derived structure size
+        let v16 = DtbHeader {
+            version: 16,
+            size_dt_struct: 0x180 - 0x38,
+            ..header
+        };
+        let (_, reparsed) = DtbHeader::parse(&v16.to_bytes()).unwrap();
+        assert_eq!(reparsed, v16);
+    }
+
+    #[test]
+    fn test_header_display() {
+        let header = DtbHeader {
+            magic: DtbHeader::MAGIC,
+            totalsize: 0x200,
+            off_dt_struct: 0x38,
+            off_dt_strings: 0x180,
+            off_mem_rsvmap: 0x28,
+            version: 17,
+            last_comp_version: 16,
+            boot_cpuid_phys: 1,
+            size_dt_strings: 0x40,
+            size_dt_struct: 0x148,
+        };
+        let text = header.to_string();
+        assert!(text.starts_with("Magic:           0xd00dfeed\n"));
+        assert!(text.contains("Total size:      512 bytes\n"));
+        assert!(text.contains("Version:         17\n"));
+        assert!(text.contains("Last compatible: 16\n"));
+        assert!(text.contains("Boot CPU ID:     1\n"));
+        assert!(text.contains("Struct offset:   0x38\n"));
+        assert!(text.contains("Struct size:     328 bytes\n"));
+        assert!(text.contains("Strings offset:  0x180\n"));
+        assert!(text.contains("Strings size:    64 bytes\n"));
+        assert!(text.ends_with("Memory rsv:      0x28"));
+        assert_eq!(text.lines().count(), 10);
+    }
+
+    #[test]
+    fn test_header_validate_alignment() {
+        let header = DtbHeader {
+            magic: DtbHeader::MAGIC,
+            totalsize: 0x200,
+            off_dt_struct: 0x38,
+            off_dt_strings: 0x180,
+            off_mem_rsvmap: 0x28,
+            version: 17,
+            last_comp_version: 16,
+            boot_cpuid_phys: 0,
+            size_dt_strings: 0x40,
+            size_dt_struct: 0x148,
+        };
+        assert_eq!(header.validate_alignment(), Ok(()));
+
+        let misaligned = [
+            DtbHeader {
+                off_dt_struct: 0x3a,
+                ..header.clone()
+            },
+            DtbHeader {
+                off_dt_strings: 0x181,
+                ..header.clone()
+            },
+            // 4-byte aligned is not enough for the reservation block
+            DtbHeader {
+                off_mem_rsvmap: 0x2c,
+                ..header.clone()
+            },
+        ];
+        for header in misaligned {
+            assert_eq!(
+                header.validate_alignment(),
+                Err(DtbError::AlignmentError),
+                "{header:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_header_block_ranges() {
+        let header = DtbHeader {
+            magic: DtbHeader::MAGIC,
+            totalsize: 0x200,
+            off_dt_struct: 0x38,
+            off_dt_strings: 0x180,
+            off_mem_rsvmap: 0x28,
+            version: 17,
+            last_comp_version: 16,
+            boot_cpuid_phys: 0,
+            size_dt_strings: 0x40,
+            size_dt_struct: 0x148,
+        };
+
+        assert_eq!(header.struct_block_range().unwrap(), 0x38..0x180);
+        assert_eq!(header.strings_block_range().unwrap(), 0x180..0x1c0);
+        assert_eq!(header.mem_rsvmap_offset().unwrap(), 0x28);
+
+        // Blocks extending past totalsize are rejected
+        let oversized = DtbHeader {
+            size_dt_strings: 0x100,
+            ..header.clone()
+        };
+        assert!(matches!(
+            oversized.strings_block_range(),
+            Err(DtbError::MalformedHeader)
+        ));
+
+        // Offset arithmetic overflow is rejected
+        let overflow = DtbHeader {
+            off_dt_struct: u32::MAX - 3,
+            ..header.clone()
+        };
+        assert!(matches!(
+            overflow.struct_block_range(),
+            Err(DtbError::MalformedHeader)
+        ));
+
+        // Blocks must be aligned relative to the start of the DTB
+        let misaligned_struct = DtbHeader {
+            off_dt_struct: 0x3a,
+            ..header.clone()
+        };
+        assert!(matches!(
+            misaligned_struct.struct_block_range(),
+            Err(DtbError::AlignmentError)
+        ));
+        let misaligned_rsvmap = DtbHeader {
+            off_mem_rsvmap: 0x2c,
+            ..header.clone()
+        };
+        assert!(matches!(
+            misaligned_rsvmap.mem_rsvmap_offset(),
+            Err(DtbError::AlignmentError)
+        ));
+
+        let bad_rsvmap = DtbHeader {
+            off_mem_rsvmap: 0x200,
+            ..header
+        };
+        assert!(matches!(
+            bad_rsvmap.mem_rsvmap_offset(),
+            Err(DtbError::MalformedHeader)
+        ));
+    }
 }
@@ -0,0 +1,343 @@
+// ABOUTME: Interrupt routing for the interrupts property
+// ABOUTME: Resolves the inherited interrupt-parent and splits specifiers by #interrupt-cells
+
+use super::error::DtbError;
+use super::parser::DeviceTreeParser;
+use super::tree::DeviceTreeNode;
+use alloc::{string::String, vec, vec::Vec};
+
+/// Interrupt decoded from a node's `interrupts` property.
+///
+/// Produced by [`DeviceTreeNode::interrupt_routing`]. The meaning of the
+/// specifier cells depends on the controller's binding; for an Arm GIC they
+/// are the interrupt type, number, and trigger flags.
+///
+/// # Examples
+///
+/// ```rust
+/// # use device_tree_parser::{DeviceTreeNode, DeviceTreeParser, DtbError};
+/// # fn example(parser: &DeviceTreeParser, uart: &DeviceTreeNode) -> Result<(), DtbError> {
+/// for irq in uart.interrupt_routing(parser)? {
+///     println!("{} {:?}", irq.controller, irq.cells);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InterruptSpec {
+    /// Path of the interrupt controller (e.g., `/intc@8000000`).
+    pub controller: String,
+    /// Phandle of the interrupt controller, or `None` if it was found as the
+    /// node's tree parent and has no phandle.
+    pub phandle: Option<u32>,
+    /// Specifier cells, `#interrupt-cells` of them.
+    pub cells: Vec<u32>,
+}
+
+impl<'a> DeviceTreeNode<'a> {
+    /// Decode the `interrupts` property against the governing controller.
+    ///
+    /// The controller is named by the nearest `interrupt-parent`, looking at
+    /// this node and then its ancestors in `parser`'s tree. If no node on the
+    /// way to the root has one, the tree parent is the controller. The
+    /// controller's `#interrupt-cells` sizes each specifier. Returns an empty
+    /// list if the node has no `interrupts` property.
+    ///
+    /// The node is located in `parser`'s tree by identity, or by name and
+    /// content if it is a copy. A node that is not part of the tree only
+    /// sees its own `interrupt-parent`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError::PhandleNotFound`] if no node has the
+    /// `interrupt-parent` phandle, [`DtbError::InvalidSpecifier`] if there is
+    /// no controller, it lacks `#interrupt-cells`, or `interrupts` ends
+    /// mid-specifier, and [`DtbError::InvalidPropertyLength`] if `interrupts`
+    /// is not made of whole cells.
+    pub fn interrupt_routing(
+        &self,
+        parser: &DeviceTreeParser<'_>,
+    ) -> Result<Vec<InterruptSpec>, DtbError> {
+        let Some(property) = self.find_property("interrupts") else {
+            return Ok(Vec::new());
+        };
+        let interrupts = property.value.as_u32_array_checked()?;
+
+        let root = parser.parsed()?;
+        let controller = self.interrupt_controller(root)?;
+        let cells = specifier_cells(controller)? as usize;
+        if !interrupts.len().is_multiple_of(cells) {
+            return Err(DtbError::InvalidSpecifier);
+        }
+
+        let path = root
+            .iter_paths()
+            .find(|(_, node)| core::ptr::eq(*node, controller))
+            .map(|(path, _)| path)
+            .unwrap_or_default();
+        Ok(interrupts
+            .chunks_exact(cells)
+            .map(|specifier| InterruptSpec {
+                controller: path.clone(),
+                phandle: controller.phandle(),
+                cells: specifier.to_vec(),
+            })
+            .collect())
+    }
+
+    /// Get the `#interrupt-cells` of the node's interrupt controller.
+    ///
+    /// This is the number of cells in each specifier of the node's
+    /// `interrupts` property. The controller is found the same way as in
+    /// [`interrupt_routing`](Self::interrupt_routing), so the node does not
+    /// need an `interrupts` property of its own.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError::PhandleNotFound`] if no node has the
+    /// `interrupt-parent` phandle, and [`DtbError::InvalidSpecifier`] if there
+    /// is no controller or it lacks a non-zero `#interrupt-cells`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeNode, DeviceTreeParser, DtbError};
+    /// # fn example(parser: &DeviceTreeParser, uart: &DeviceTreeNode) -> Result<(), DtbError> {
+    /// let cells = uart.interrupt_cells(parser)?;
+    /// println!("{} cells per interrupt", cells);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn interrupt_cells(&self, parser: &DeviceTreeParser<'_>) -> Result<u32, DtbError> {
+        let root = parser.parsed()?;
+        specifier_cells(self.interrupt_controller(root)?)
+    }
+
+    /// Find the interrupt controller governing this node within `root`
+    fn interrupt_controller<'t>(
+        &self,
+        root: &'t DeviceTreeNode<'a>,
+    ) -> Result<&'t DeviceTreeNode<'a>, DtbError> {
+        let chain = ancestors_of(root, self);
+        let phandle = match &chain {
+            Some(chain) => chain
+                .iter()
+                .rev()
+                .find_map(|n| n.prop_u32("interrupt-parent")),
+            None => self.prop_u32("interrupt-parent"),
+        };
+        match phandle {
+            Some(phandle) => root
+                .find_node_by_phandle(phandle)
+                .ok_or(DtbError::PhandleNotFound(phandle)),
+            None => chain
+                .and_then(|chain| Some(chain[chain.len().checked_sub(2)?]))
+                .ok_or(DtbError::InvalidSpecifier),
+        }
+    }
+}
+
+/// A controller's non-zero `#interrupt-cells`
+fn specifier_cells(controller: &DeviceTreeNode<'_>) -> Result<u32, DtbError> {
+    controller
+        .prop_u32("#interrupt-cells")
+        .filter(|&cells| cells > 0)
+        .ok_or(DtbError::InvalidSpecifier)
+}
+
+/// Nodes from `root` down to `target`, inclusive
+///
+/// Matches `target` by address first, so references into the tree are found
+/// exactly, and otherwise by name and content.
+fn ancestors_of<'t, 'a>(
+    root: &'t DeviceTreeNode<'a>,
+    target: &DeviceTreeNode<'_>,
+) -> Option<Vec<&'t DeviceTreeNode<'a>>> {
+    fn walk<'t, 'a>(
+        chain: &mut Vec<&'t DeviceTreeNode<'a>>,
+        target: &DeviceTreeNode<'_>,
+        same: &impl Fn(&DeviceTreeNode<'_>, &DeviceTreeNode<'_>) -> bool,
+    ) -> bool {
+        let node = chain[chain.len() - 1];
+        if same(node, target) {
+            return true;
+        }
+        for child in &node.children {
+            chain.push(child);
+            if walk(chain, target, same) {
+                return true;
+            }
+            chain.pop();
+        }
+        false
+    }
+
+    let by_address = |a: &DeviceTreeNode<'_>, b: &DeviceTreeNode<'_>| core::ptr::addr_eq(a, b);
+    let by_content = |a: &DeviceTreeNode<'_>, b: &DeviceTreeNode<'_>| a.structurally_eq(b);
+
+    let mut chain = vec![root];
+    if walk(&mut chain, target, &by_address) {
+        return Some(chain);
+    }
+    let mut chain = vec![root];
+    walk(&mut chain, target, &by_content).then_some(chain)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dtb::test_support::{StructBuilder, cells};
+    use crate::dtb::tree::{Property, PropertyValue};
+
+    fn interrupt_dtb() -> Vec<u8> {
+        StructBuilder::new()
+            .begin_node("")
+            .named_property("interrupt-parent", &cells(&[1]))
+            .begin_node("intc@8000000")
+            .named_property("interrupt-controller", &[])
+            .named_property("#interrupt-cells", &cells(&[3]))
+            .named_property("phandle", &cells(&[1]))
+            .end_node()
+            .begin_node("timer")
+            .named_property("interrupts", &cells(&[1, 13, 4, 1, 14, 4]))
+            .end_node()
+            .begin_node("soc")
+            .named_property("interrupt-parent", &cells(&[2]))
+            .begin_node("intc@a000000")
+            .named_property("interrupt-controller", &[])
+            .named_property("#interrupt-cells", &cells(&[1]))
+            .named_property("phandle", &cells(&[2]))
+            .end_node()
+            .begin_node("uart@9000000")
+            .named_property("interrupts", &cells(&[5, 6]))
+            .end_node()
+            .begin_node("gpio@9030000")
+            .named_property("interrupt-parent", &cells(&[1]))
+            .named_property("interrupts", &cells(&[0, 7, 4]))
+            .end_node()
+            .end_node()
+            .end_node()
+            .end()
+            .build_dtb()
+    }
+
+    #[test]
+    fn test_interrupt_routing_inherited() {
+        let data = interrupt_dtb();
+        let parser = DeviceTreeParser::new(&data);
+
+        // interrupt-parent comes from the root
+        let timer = parser.find_node_ref("/timer").unwrap().unwrap();
+        assert_eq!(
+            timer.interrupt_routing(&parser).unwrap(),
+            [
+                InterruptSpec {
+                    controller: String::from("/intc@8000000"),
+                    phandle: Some(1),
+                    cells: vec![1, 13, 4],
+                },
+                InterruptSpec {
+                    controller: String::from("/intc@8000000"),
+                    phandle: Some(1),
+                    cells: vec![1, 14, 4],
+                },
+            ]
+        );
+
+        // The nearest ancestor's interrupt-parent wins over the root's
+        let uart = parser.find_node_ref("/soc/uart@9000000").unwrap().unwrap();
+        let routing = uart.interrupt_routing(&parser).unwrap();
+        assert_eq!(routing.len(), 2);
+        assert_eq!(routing[1].controller, "/soc/intc@a000000");
+        assert_eq!(routing[1].phandle, Some(2));
+        assert_eq!(routing[1].cells, [6]);
+
+        // A copy of the node is found by content
+        let copy = parser.find_node("/soc/uart@9000000").unwrap().unwrap();
+        assert_eq!(copy.interrupt_routing(&parser).unwrap(), routing);
+
+        // The node's own interrupt-parent wins over its ancestors'
+        let gpio = parser.find_node_ref("/soc/gpio@9030000").unwrap().unwrap();
+        let routing = gpio.interrupt_routing(&parser).unwrap();
+        assert_eq!(routing[0].controller, "/intc@8000000");
+        assert_eq!(routing[0].cells, [0, 7, 4]);
+
+        let soc = parser.find_node_ref("/soc").unwrap().unwrap();
+        assert!(soc.interrupt_routing(&parser).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_interrupt_cells_inherited() {
+        let data = interrupt_dtb();
+        let parser = DeviceTreeParser::new(&data);
+        let cells_of = |path| {
+            let node = parser.find_node_ref(path).unwrap().unwrap();
+            node.interrupt_cells(&parser)
+        };
+
+        // Inherited from the root's interrupt-parent
+        assert_eq!(cells_of("/timer"), Ok(3));
+        // The nearest interrupt-parent wins, with or without interrupts
+        assert_eq!(cells_of("/soc"), Ok(1));
+        assert_eq!(cells_of("/soc/uart@9000000"), Ok(1));
+        assert_eq!(cells_of("/soc/gpio@9030000"), Ok(3));
+
+        // A copy outside the tree only sees its own interrupt-parent
+        let mut orphan = DeviceTreeNode::new("orphan");
+        assert_eq!(
+            orphan.interrupt_cells(&parser),
+            Err(DtbError::InvalidSpecifier)
+        );
+        orphan.add_property(Property {
+            name: "interrupt-parent",
+            value: PropertyValue::U32(2),
+            raw: None,
+        });
+        assert_eq!(orphan.interrupt_cells(&parser), Ok(1));
+    }
+
+    #[test]
+    fn test_interrupt_routing_errors() {
+        let data = StructBuilder::new()
+            .begin_node("")
+            .begin_node("intc")
+            .named_property("interrupt-controller", &[])
+            .named_property("#interrupt-cells", &cells(&[2]))
+            .begin_node("timer")
+            .named_property("interrupts", &cells(&[5, 1, 6]))
+            .end_node()
+            .end_node()
+            .begin_node("uart")
+            .named_property("interrupt-parent", &cells(&[5]))
+            .named_property("interrupts", &cells(&[1]))
+            .end_node()
+            .end_node()
+            .end()
+            .build_dtb();
+        let parser = DeviceTreeParser::new(&data);
+
+        // Without interrupt-parent the tree parent is the controller, and
+        // its two-cell specifiers don't divide three cells
+        let timer = parser.find_node_ref("/intc/timer").unwrap().unwrap();
+        assert_eq!(
+            timer.interrupt_routing(&parser).unwrap_err(),
+            DtbError::InvalidSpecifier
+        );
+        let uart = parser.find_node_ref("/uart").unwrap().unwrap();
+        assert_eq!(
+            uart.interrupt_routing(&parser).unwrap_err(),
+            DtbError::PhandleNotFound(5)
+        );
+        assert_eq!(
+            uart.interrupt_cells(&parser),
+            Err(DtbError::PhandleNotFound(5))
+        );
+
+        // The root has no tree parent to fall back to
+        let root = parser.find_node_ref("/").unwrap().unwrap();
+        assert_eq!(
+            root.interrupt_cells(&parser),
+            Err(DtbError::InvalidSpecifier)
+        );
+    }
+}
@@ -1,5 +1,5 @@
 // ABOUTME: Memory reservation block parsing for device tree blobs
-// ABOUTME: Handles (address, size) pairs with 8-byte alignment requirements
+// ABOUTME: Handles (address, size) pairs terminated by a zero entry
 
 use super::error::DtbError;
 use alloc::vec::Vec;
@@ -47,7 +47,9 @@ use alloc::vec::Vec;
 /// # Ok(())
 /// # }
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+///
+/// Reservations order by address, then size.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct MemoryReservation {
     /// Physical address of the start of the reserved memory region.
     pub address: u64,
@@ -59,19 +61,135 @@ impl MemoryReservation {
     /// Size of each reservation entry in bytes (address + size)
     pub const SIZE: usize = 16;
 
+    /// Create a reservation, validating the region
+    ///
+    /// Use this when building reservations to write; parsed entries are
+    /// taken as found.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DtbError::InvalidReservation` if `size` is zero, which would
+    /// either reserve nothing or, at address zero, read back as the list
+    /// terminator, or if the region runs past the end of the 64-bit address
+    /// space.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DtbError, MemoryReservation};
+    /// let firmware = MemoryReservation::new(0x4000_0000, 0x10_0000)?;
+    /// assert_eq!(firmware.address + firmware.size, 0x4010_0000);
+    ///
+    /// assert!(MemoryReservation::new(0x4000_0000, 0).is_err());
+    /// # Ok::<(), DtbError>(())
+    /// ```
+    pub fn new(address: u64, size: u64) -> Result<Self, DtbError> {
+        // The region may end at the very top of memory, so check its last byte
+        if size == 0 || address.checked_add(size - 1).is_none() {
+            return Err(DtbError::InvalidReservation);
+        }
+        Ok(Self { address, size })
+    }
+
     /// Parse memory reservations from input bytes
     ///
+    /// The input may sit at any memory address; alignment of the block within
+    /// the DTB is checked by [`DtbHeader::mem_rsvmap_offset`](super::DtbHeader::mem_rsvmap_offset).
+    ///
+    /// Parsing stops at the `(0, 0)` terminator or, if there is none, at the
+    /// last complete entry in `input`. Bound `input` to the reservation block
+    /// so a missing terminator cannot run into the following data.
+    ///
     /// # Errors
     ///
-    /// Returns `DtbError::AlignmentError` if input is not 8-byte aligned.
     /// Returns `DtbError::MalformedHeader` if data is truncated or malformed.
     pub fn parse_all(input: &[u8]) -> Result<(&[u8], Vec<Self>), DtbError> {
-        // Ensure 8-byte alignment
-        if (input.as_ptr() as usize) % 8 != 0 {
-            return Err(DtbError::AlignmentError);
+        let (remaining, reservations, _terminated) = Self::parse_entries(input)?;
+        Ok((remaining, reservations))
+    }
+
+    /// Parse memory reservations, requiring the `(0, 0)` terminator
+    ///
+    /// # Errors
+    ///
+    /// Returns `DtbError::UnterminatedReservations` if `input` ends before a
+    /// terminating entry, or `DtbError::MalformedHeader` if data is malformed.
+    pub fn parse_all_strict(input: &[u8]) -> Result<(&[u8], Vec<Self>), DtbError> {
+        match Self::parse_entries(input)? {
+            (remaining, reservations, true) => Ok((remaining, reservations)),
+            (_, _, false) => Err(DtbError::UnterminatedReservations),
+        }
+    }
+
+    /// Serialize reservations as a reservation block
+    ///
+    /// Emits each entry as a 16-byte big-endian `(address, size)` pair
+    /// followed by the `(0, 0)` terminator. The block is a multiple of 8 bytes
+    /// long, so it keeps the following block aligned; the caller must place
+    /// it at an 8-byte aligned offset in the DTB. Entries are written as
+    /// given, so a `(0, 0)` entry would end the list early when parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::MemoryReservation;
+    /// let block = MemoryReservation::write_all(&[
+    ///     MemoryReservation { address: 0x4000_0000, size: 0x1000 },
+    /// ]);
+    /// assert_eq!(block.len(), 2 * MemoryReservation::SIZE);
+    /// ```
+    #[must_use]
+    pub fn write_all(entries: &[Self]) -> Vec<u8> {
+        let mut out = Vec::with_capacity((entries.len() + 1) * Self::SIZE);
+        let terminator = Self {
+            address: 0,
+            size: 0,
+        };
+        for entry in entries.iter().chain([&terminator]) {
+            out.extend_from_slice(&entry.address.to_be_bytes());
+            out.extend_from_slice(&entry.size.to_be_bytes());
+        }
+        out
+    }
+
+    /// Sort reservations and coalesce regions that touch or overlap
+    ///
+    /// Returns a new list ordered by address in which no two regions share
+    /// or abut an address. Region ends saturate at `u64::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::MemoryReservation;
+    /// let merged = MemoryReservation::merge_adjacent(&[
+    ///     MemoryReservation { address: 0x2000, size: 0x1000 },
+    ///     MemoryReservation { address: 0x1000, size: 0x1000 },
+    /// ]);
+    /// assert_eq!(merged, [MemoryReservation { address: 0x1000, size: 0x2000 }]);
+    /// ```
+    #[must_use]
+    pub fn merge_adjacent(reservations: &[Self]) -> Vec<Self> {
+        let mut sorted = reservations.to_vec();
+        sorted.sort_unstable();
+
+        let mut merged: Vec<Self> = Vec::with_capacity(sorted.len());
+        for reservation in sorted {
+            let end = reservation.address.saturating_add(reservation.size);
+            if let Some(last) = merged.last_mut()
+                && reservation.address <= last.address.saturating_add(last.size)
+            {
+                last.size = last.size.max(end - last.address);
+            } else {
+                merged.push(reservation);
+            }
         }
+        merged
+    }
 
+    /// Parse entries up to the terminator, reporting whether one was found
+    fn parse_entries(input: &[u8]) -> Result<(&[u8], Vec<Self>, bool), DtbError> {
         let mut reservations = Vec::new();
+        let mut terminated = false;
         let mut chunks = input.chunks_exact(Self::SIZE);
 
         for chunk in &mut chunks {
@@ -88,29 +206,168 @@ impl MemoryReservation {
 
             // Check for terminating entry (0, 0)
             if address == 0 && size == 0 {
+                terminated = true;
                 break;
             }
 
             reservations.push(MemoryReservation { address, size });
         }
 
-        // Calculate remaining input after parsing complete reservation entries
-        let consumed = reservations.len() * Self::SIZE + Self::SIZE; // +SIZE for terminating entry
-        let remaining = if consumed <= input.len() {
-            &input[consumed..]
-        } else {
-            &input[input.len()..]
-        };
-
-        Ok((remaining, reservations))
+        // Skip the parsed entries and the terminator, if present
+        let consumed = (reservations.len() + usize::from(terminated)) * Self::SIZE;
+        Ok((&input[consumed..], reservations, terminated))
     }
 }
 
+/// Region described by a child of the `/reserved-memory` node.
+///
+/// Unlike [`MemoryReservation`], which comes from the legacy reservation
+/// block, these regions carry a name, a binding, and usage flags. A region is
+/// either placed statically by `reg` or allocated dynamically by the OS from
+/// its `size` (and optional `alignment` and `alloc-ranges`).
+///
+/// # Examples
+///
+/// ```rust
+/// # use device_tree_parser::{DeviceTreeParser, DtbError};
+/// # fn example() -> Result<(), DtbError> {
+/// # let dtb_data = vec![0u8; 64]; // Mock data
+/// let parser = DeviceTreeParser::new(&dtb_data);
+///
+/// for region in parser.reserved_memory()? {
+///     match region.address {
+///         Some(address) => println!("{} at 0x{:x}", region.name, address),
+///         None => println!("{}: {} bytes, placed by the OS", region.name, region.size),
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReservedRegion<'a> {
+    /// Name of the child node (e.g., `framebuffer@80000000`).
+    pub name: &'a str,
+    /// Start address from `reg`, or `None` for a dynamically placed region.
+    pub address: Option<u64>,
+    /// Size in bytes, from `reg` or the `size` property.
+    pub size: u64,
+    /// `no-map`: the OS must not map the region or use it for anything else.
+    pub no_map: bool,
+    /// `reusable`: the OS may use the region while its owner doesn't need it.
+    pub reusable: bool,
+    /// Entries of the `compatible` property, empty if absent.
+    pub compatible: Vec<&'a str>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use alloc::vec;
 
+    #[test]
+    fn test_memory_reservation_new() {
+        assert_eq!(
+            MemoryReservation::new(0x4000_0000, 0x1000),
+            Ok(MemoryReservation {
+                address: 0x4000_0000,
+                size: 0x1000,
+            })
+        );
+        // A region may end with the last byte of the address space
+        assert!(MemoryReservation::new(u64::MAX - 0xfff, 0x1000).is_ok());
+        assert!(MemoryReservation::new(u64::MAX, 1).is_ok());
+        assert!(MemoryReservation::new(0, u64::MAX).is_ok());
+    }
+
+    #[test]
+    fn test_memory_reservation_new_invalid() {
+        // Zero size, including the (0, 0) terminator
+        assert_eq!(
+            MemoryReservation::new(0x4000_0000, 0),
+            Err(DtbError::InvalidReservation)
+        );
+        assert_eq!(
+            MemoryReservation::new(0, 0),
+            Err(DtbError::InvalidReservation)
+        );
+        // The region runs past the end of the address space
+        assert_eq!(
+            MemoryReservation::new(u64::MAX - 0xfff, 0x1001),
+            Err(DtbError::InvalidReservation)
+        );
+        assert_eq!(
+            MemoryReservation::new(2, u64::MAX),
+            Err(DtbError::InvalidReservation)
+        );
+    }
+
+    #[test]
+    fn test_memory_reservation_ordering() {
+        let a = MemoryReservation {
+            address: 0x1000,
+            size: 0x100,
+        };
+        let b = MemoryReservation {
+            address: 0x1000,
+            size: 0x200,
+        };
+        let c = MemoryReservation {
+            address: 0x0800,
+            size: 0x1000,
+        };
+        let mut list = vec![b.clone(), a.clone(), c.clone()];
+        list.sort();
+        assert_eq!(list, [c, a, b]);
+    }
+
+    #[test]
+    fn test_write_all_round_trip() {
+        let entries = [
+            MemoryReservation {
+                address: 0x4000_0000,
+                size: 0x10_0000,
+            },
+            MemoryReservation {
+                address: 0x1_0000_0000,
+                size: 0x1000,
+            },
+        ];
+        let block = MemoryReservation::write_all(&entries);
+        assert_eq!(block.len(), 48);
+        assert!(block.len().is_multiple_of(8));
+        assert_eq!(block[0..8], 0x4000_0000u64.to_be_bytes());
+        assert_eq!(block[32..], [0u8; 16]);
+
+        let (remaining, parsed) = MemoryReservation::parse_all_strict(&block).unwrap();
+        assert!(remaining.is_empty());
+        assert_eq!(parsed, entries);
+
+        // No entries is just the terminator
+        assert_eq!(MemoryReservation::write_all(&[]), [0u8; 16]);
+    }
+
+    #[test]
+    fn test_merge_adjacent() {
+        let entry = |address, size| MemoryReservation { address, size };
+        let merged = MemoryReservation::merge_adjacent(&[
+            entry(0x5000, 0x1000),
+            // Overlaps the next entry
+            entry(0x1000, 0x1800),
+            entry(0x2000, 0x1000),
+            // Touches the previous entry
+            entry(0x3000, 0x0800),
+            // Contained in the first merged region
+            entry(0x1100, 0x10),
+        ]);
+        assert_eq!(merged, [entry(0x1000, 0x2800), entry(0x5000, 0x1000)]);
+
+        // Regions reaching the top of the address space saturate
+        let merged =
+            MemoryReservation::merge_adjacent(&[entry(u64::MAX - 0xff, 0x1000), entry(0x0, 0x10)]);
+        assert_eq!(merged, [entry(0x0, 0x10), entry(u64::MAX - 0xff, 0x1000)]);
+        assert!(MemoryReservation::merge_adjacent(&[]).is_empty());
+    }
+
     #[test]
     fn test_memory_reservation_parse_empty() {
         let data = vec![
@@ -167,4 +424,30 @@ mod tests {
         assert_eq!(reservations[1].address, 0x3000);
         assert_eq!(reservations[1].size, 0x4000);
     }
+
+    #[test]
+    fn test_memory_reservation_parse_unterminated() {
+        let data = vec![
+            // Single entry with no terminator: address=0x1000, size=0x2000
+            0, 0, 0, 0, 0, 0, 0x10, 0, // address = 0x1000
+            0, 0, 0, 0, 0, 0, 0x20, 0, // size = 0x2000
+            // Trailing partial entry is ignored
+            0xff, 0xff, 0xff, 0xff,
+        ];
+
+        let (remaining, reservations) = MemoryReservation::parse_all(&data).unwrap();
+        assert_eq!(
+            reservations,
+            [MemoryReservation {
+                address: 0x1000,
+                size: 0x2000
+            }]
+        );
+        assert_eq!(remaining, [0xff; 4]);
+
+        assert_eq!(
+            MemoryReservation::parse_all_strict(&data).unwrap_err(),
+            DtbError::UnterminatedReservations
+        );
+    }
 }
@@ -0,0 +1,41 @@
+// ABOUTME: Memory-mapped register regions translated to the CPU address space
+// ABOUTME: Pairs each reg entry's bus-local address with its physical address
+
+use alloc::string::String;
+
+/// Register region from a node's `reg` property, as returned by
+/// [`DeviceTreeParser::mmio_map`](super::DeviceTreeParser::mmio_map).
+///
+/// `child_address` is the address as written in `reg`, in the parent bus's
+/// address space. `physical_address` is the same address translated through
+/// the `ranges` of every bus up to the root, which is where the CPU sees it.
+/// The two differ for devices behind bridges that remap addresses.
+///
+/// # Examples
+///
+/// ```rust
+/// # use device_tree_parser::{DeviceTreeParser, DtbError};
+/// # fn example() -> Result<(), DtbError> {
+/// # let dtb_data = vec![0u8; 64]; // Mock data
+/// let parser = DeviceTreeParser::new(&dtb_data);
+///
+/// for entry in parser.mmio_map()? {
+///     println!(
+///         "{}: bus 0x{:x} -> cpu 0x{:x} ({} bytes)",
+///         entry.path, entry.child_address, entry.physical_address, entry.size
+///     );
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MmioEntry {
+    /// Full path of the node holding `reg` (e.g., `/soc/uart@1000`).
+    pub path: String,
+    /// Address as written in `reg`, in the parent bus's address space.
+    pub child_address: u64,
+    /// Address in the CPU's physical address space.
+    pub physical_address: u64,
+    /// Size of the region in bytes.
+    pub size: u64,
+}
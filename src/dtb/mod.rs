@@ -1,16 +1,54 @@
 // ABOUTME: Device tree blob parsing module with nom combinators
 // ABOUTME: Provides no_std compatible DTB parsing functionality
 
+pub mod cpu;
+pub mod cursor;
+pub mod diff;
 pub mod error;
+pub mod gpio;
 pub mod header;
+pub mod interrupt;
 pub mod memory;
+pub mod mmio;
+pub mod owned;
 pub mod parser;
+pub mod pci;
+pub mod pretty;
+pub mod repair;
+pub mod schema;
+pub mod specifier;
+pub mod stats;
 pub mod tokens;
 pub mod tree;
+pub mod validate;
 
+mod checksum;
+mod overlay;
+#[cfg(feature = "serde")]
+mod serialize;
+#[cfg(test)]
+mod test_support;
+
+pub use cpu::CpuInfo;
+pub use cursor::{StructCursor, StructEvent};
+pub use diff::TreeDiff;
 pub use error::DtbError;
+pub use gpio::GpioSpec;
 pub use header::DtbHeader;
-pub use memory::MemoryReservation;
-pub use parser::DeviceTreeParser;
+pub use interrupt::InterruptSpec;
+pub use memory::{MemoryReservation, ReservedRegion};
+pub use mmio::MmioEntry;
+pub use owned::{OwnedDeviceTree, OwnedProperty, OwnedPropertyValue};
+pub use parser::{DeviceTreeParser, ParseOptions};
+pub use pci::{PciAddress, PciRange, PciSpace};
+pub use pretty::PrintOptions;
+pub use repair::RepairedStrings;
+pub use schema::PropKind;
+pub use specifier::NamedSpecifier;
+pub use stats::DtbStats;
 pub use tokens::DtbToken;
-pub use tree::{AddressRange, AddressSpec, DeviceTreeNode, NodeIterator, Property, PropertyValue};
+pub use tree::{
+    AddressRange, AddressSpec, DeviceTreeNode, NodeIterator, Property, PropertyValue, RangesKind,
+    Status,
+};
+pub use validate::ValidationWarning;
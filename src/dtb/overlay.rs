@@ -0,0 +1,493 @@
+// ABOUTME: Device tree overlay application for merging .dtbo fragments onto a base tree
+// ABOUTME: Resolves fragment targets, __fixups__ via __symbols__, and renumbers local phandles
+
+use super::error::DtbError;
+use super::owned::{OwnedDeviceTree, OwnedProperty, OwnedPropertyValue};
+use super::tree::{DeviceTreeNode, Property, PropertyValue, parse_property_value};
+use alloc::{vec, vec::Vec};
+
+/// Phandle reference from the overlay's `__fixups__` node
+struct Fixup<'a> {
+    path: &'a str,
+    property: &'a str,
+    offset: usize,
+    phandle: u32,
+}
+
+/// Merges a parsed overlay onto a base tree, producing an owned tree.
+///
+/// The overlay's own phandles are first moved past the base tree's largest
+/// phandle, together with the references listed in `__local_fixups__`, so
+/// merged nodes cannot collide with base nodes.
+pub(crate) fn apply_overlay(
+    base: &DeviceTreeNode<'_>,
+    overlay: &DeviceTreeNode<'_>,
+) -> Result<OwnedDeviceTree, DtbError> {
+    let mut merged = base.to_owned_tree();
+    let mut owned = overlay.to_owned_tree();
+
+    let delta = max_phandle(base);
+    if delta > 0 {
+        shift_phandles(&mut owned, delta)?;
+        if let Some(local_fixups) = overlay.find_child("__local_fixups__") {
+            apply_local_fixups(&mut owned, local_fixups, delta)?;
+        }
+    }
+
+    if let Some(fixups) = overlay.find_child("__fixups__") {
+        for fixup in collect_fixups(base, fixups)? {
+            apply_fixup(&mut owned, &fixup)?;
+        }
+    }
+
+    for mut fragment in core::mem::take(&mut owned.children) {
+        // Skip __fixups__, __local_fixups__, and __symbols__
+        if fragment.name.starts_with("__") {
+            continue;
+        }
+        let Some(index) = fragment
+            .children
+            .iter()
+            .position(|c| c.name == "__overlay__")
+        else {
+            continue;
+        };
+
+        let target = find_target(&merged, &fragment)?;
+        let content = fragment.children.swap_remove(index);
+        let node = target
+            .iter()
+            .fold(&mut merged, |node, &i| &mut node.children[i]);
+        merge_node(node, content);
+    }
+
+    Ok(merged)
+}
+
+/// Largest phandle used in a tree, or 0 if it has none
+fn max_phandle(tree: &DeviceTreeNode<'_>) -> u32 {
+    tree.iter_nodes()
+        .filter_map(DeviceTreeNode::phandle)
+        .filter(|&phandle| phandle != u32::MAX)
+        .max()
+        .unwrap_or(0)
+}
+
+/// Add `delta` to every phandle defined in the overlay
+fn shift_phandles(node: &mut OwnedDeviceTree, delta: u32) -> Result<(), DtbError> {
+    for property in &mut node.properties {
+        if property.name == "phandle" || property.name == "linux,phandle" {
+            patch_cell(property, 0, |phandle| shift_phandle(phandle, delta))?;
+        }
+    }
+    node.children
+        .iter_mut()
+        .try_for_each(|child| shift_phandles(child, delta))
+}
+
+/// Add `delta` to a phandle, rejecting results that overflow or are invalid
+fn shift_phandle(phandle: u32, delta: u32) -> Option<u32> {
+    phandle
+        .checked_add(delta)
+        .filter(|&shifted| shifted != u32::MAX)
+}
+
+/// Shift the overlay's references to its own phandles
+///
+/// `__local_fixups__` mirrors the overlay's node hierarchy. Each of its
+/// properties lists the byte offsets of phandle cells in the same-named
+/// property of the matching overlay node.
+fn apply_local_fixups(
+    node: &mut OwnedDeviceTree,
+    local_fixups: &DeviceTreeNode<'_>,
+    delta: u32,
+) -> Result<(), DtbError> {
+    for fixup in &local_fixups.properties {
+        let property = node
+            .properties
+            .iter_mut()
+            .find(|p| p.name == fixup.name)
+            .ok_or(DtbError::InvalidOverlay)?;
+        for offset in offsets(fixup)? {
+            patch_cell(property, offset, |phandle| shift_phandle(phandle, delta))?;
+        }
+    }
+    for local_child in &local_fixups.children {
+        let child = node
+            .children
+            .iter_mut()
+            .find(|c| c.name == local_child.name)
+            .ok_or(DtbError::InvalidOverlay)?;
+        apply_local_fixups(child, local_child, delta)?;
+    }
+    Ok(())
+}
+
+/// Byte offsets listed by a `__local_fixups__` property
+fn offsets(fixup: &Property<'_>) -> Result<Vec<usize>, DtbError> {
+    let bytes = fixup.stored_bytes().ok_or(DtbError::InvalidOverlay)?;
+    if !bytes.len().is_multiple_of(4) {
+        return Err(DtbError::InvalidOverlay);
+    }
+    Ok(bytes
+        .chunks_exact(4)
+        .map(|chunk| u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]) as usize)
+        .collect())
+}
+
+/// Resolve every `__fixups__` entry to the phandle of its labelled base node
+fn collect_fixups<'a>(
+    base: &DeviceTreeNode<'_>,
+    fixups: &DeviceTreeNode<'a>,
+) -> Result<Vec<Fixup<'a>>, DtbError> {
+    let mut resolved = Vec::new();
+    for prop in &fixups.properties {
+        let phandle = resolve_symbol(base, prop.name)?;
+        let entries = match &prop.value {
+            PropertyValue::String(s) => vec![*s],
+            PropertyValue::StringList(list) => list.clone(),
+            _ => return Err(DtbError::InvalidOverlay),
+        };
+
+        for entry in entries {
+            // Entries are "path:property:offset"
+            let mut parts = entry.rsplitn(3, ':');
+            let (Some(offset), Some(property), Some(path)) =
+                (parts.next(), parts.next(), parts.next())
+            else {
+                return Err(DtbError::InvalidOverlay);
+            };
+            let offset = offset.parse().map_err(|_| DtbError::InvalidOverlay)?;
+            resolved.push(Fixup {
+                path,
+                property,
+                offset,
+                phandle,
+            });
+        }
+    }
+    Ok(resolved)
+}
+
+/// Look up a label in the base tree's `__symbols__` node and return its phandle
+fn resolve_symbol(base: &DeviceTreeNode<'_>, label: &str) -> Result<u32, DtbError> {
+    let path = base
+        .find_child("__symbols__")
+        .and_then(|symbols| symbols.prop_string(label))
+        .ok_or(DtbError::UnresolvedSymbol)?;
+    base.find_node(path)
+        .and_then(DeviceTreeNode::phandle)
+        .ok_or(DtbError::UnresolvedSymbol)
+}
+
+/// Write a resolved phandle into the overlay property named by a fixup
+fn apply_fixup(overlay: &mut OwnedDeviceTree, fixup: &Fixup<'_>) -> Result<(), DtbError> {
+    let node = fixup
+        .path
+        .split('/')
+        .filter(|part| !part.is_empty())
+        .try_fold(overlay, |node, part| {
+            node.children.iter_mut().find(|c| c.name == part)
+        })
+        .ok_or(DtbError::InvalidOverlay)?;
+    let property = node
+        .properties
+        .iter_mut()
+        .find(|p| p.name == fixup.property)
+        .ok_or(DtbError::InvalidOverlay)?;
+    patch_cell(property, fixup.offset, |_| Some(fixup.phandle))
+}
+
+/// Rewrite the big-endian cell at byte `offset` of a property's value
+///
+/// The value is reclassified from the patched bytes, and `raw` is updated
+/// when present.
+fn patch_cell(
+    property: &mut OwnedProperty,
+    offset: usize,
+    update: impl FnOnce(u32) -> Option<u32>,
+) -> Result<(), DtbError> {
+    let mut bytes = property
+        .raw
+        .clone()
+        .unwrap_or_else(|| property.value.as_value().encode());
+    let end = offset.checked_add(4).ok_or(DtbError::InvalidOverlay)?;
+    let cell = bytes.get_mut(offset..end).ok_or(DtbError::InvalidOverlay)?;
+    let value = update(u32::from_be_bytes([cell[0], cell[1], cell[2], cell[3]]))
+        .ok_or(DtbError::InvalidOverlay)?;
+    cell.copy_from_slice(&value.to_be_bytes());
+
+    property.value = OwnedPropertyValue::from(&parse_property_value(&property.name, &bytes));
+    if property.raw.is_some() {
+        property.raw = Some(bytes);
+    }
+    Ok(())
+}
+
+/// Find the base node a fragment applies to, as a path of child indices
+fn find_target(base: &OwnedDeviceTree, fragment: &OwnedDeviceTree) -> Result<Vec<usize>, DtbError> {
+    let target = if let Some(path) = fragment.prop_string("target-path") {
+        base.find_node(path)
+    } else if let Some(phandle) = fragment.prop_u32("target") {
+        base.iter_nodes()
+            .find(|node| node.phandle() == Some(phandle))
+    } else {
+        return Err(DtbError::InvalidOverlay);
+    };
+
+    target
+        .and_then(|target| index_path(base, target))
+        .ok_or(DtbError::OverlayTargetNotFound)
+}
+
+/// Child indices leading from `node` to `target`
+fn index_path(node: &OwnedDeviceTree, target: &OwnedDeviceTree) -> Option<Vec<usize>> {
+    if core::ptr::eq(node, target) {
+        return Some(Vec::new());
+    }
+    node.children.iter().enumerate().find_map(|(i, child)| {
+        let mut path = index_path(child, target)?;
+        path.insert(0, i);
+        Some(path)
+    })
+}
+
+/// Merge overlay properties and children into a target node
+fn merge_node(target: &mut OwnedDeviceTree, overlay: OwnedDeviceTree) {
+    for property in overlay.properties {
+        match target
+            .properties
+            .iter_mut()
+            .find(|p| p.name == property.name)
+        {
+            Some(existing) => *existing = property,
+            None => target.properties.push(property),
+        }
+    }
+    for child in overlay.children {
+        match target.children.iter_mut().find(|c| c.name == child.name) {
+            Some(existing) => merge_node(existing, child),
+            None => target.children.push(child),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dtb::DeviceTreeParser;
+    use crate::dtb::test_support::StructBuilder;
+
+    const UNRESOLVED: &[u8] = &[0xff; 4];
+
+    fn base_dtb() -> Vec<u8> {
+        StructBuilder::new()
+            .begin_node("")
+            .named_property("model", b"test-board\0")
+            .begin_node("__symbols__")
+            .named_property("uart0", b"/soc/uart@1000\0")
+            .end_node()
+            .begin_node("soc")
+            .begin_node("uart@1000")
+            .named_property("compatible", b"ns16550a\0")
+            .named_property("status", b"disabled\0")
+            .named_property("phandle", &1u32.to_be_bytes())
+            .end_node()
+            .end_node()
+            .end_node()
+            .end()
+            .build_dtb()
+    }
+
+    fn overlay_dtb(fixups: &[u8]) -> Vec<u8> {
+        StructBuilder::new()
+            .begin_node("")
+            .begin_node("fragment@0")
+            .named_property("target", UNRESOLVED)
+            .begin_node("__overlay__")
+            .named_property("status", b"okay\0")
+            .named_property("clock-frequency", &1_843_200u32.to_be_bytes())
+            .begin_node("widget")
+            .named_property("compatible", b"acme,widget\0")
+            .end_node()
+            .end_node()
+            .end_node()
+            .begin_node("fragment@1")
+            .named_property("target-path", b"/soc\0")
+            .begin_node("__overlay__")
+            .begin_node("clock@2000")
+            .named_property("clocks", &[0xff, 0xff, 0xff, 0xff, 0, 0, 0, 5])
+            .end_node()
+            .end_node()
+            .end_node()
+            .begin_node("__fixups__")
+            .named_property("uart0", fixups)
+            .end_node()
+            .end_node()
+            .end()
+            .build_dtb()
+    }
+
+    const FIXUPS: &[u8] = b"/fragment@0:target:0\0/fragment@1/__overlay__/clock@2000:clocks:0\0";
+
+    #[test]
+    fn test_apply_overlay() {
+        let base_data = base_dtb();
+        let base = DeviceTreeParser::new(&base_data).parse_tree().unwrap();
+        let overlay_data = overlay_dtb(FIXUPS);
+
+        let merged = DeviceTreeParser::apply_overlay(&base, &overlay_data).unwrap();
+        drop(overlay_data);
+
+        // Base content is preserved
+        assert_eq!(merged.prop_string("model"), Some("test-board"));
+        assert!(merged.find_node("/__symbols__").is_some());
+        assert!(merged.find_node("/fragment@0").is_none());
+
+        // Fragment 0 targeted the uart through its fixed-up phandle
+        let uart = merged.find_node("/soc/uart@1000").unwrap();
+        assert_eq!(uart.prop_string("compatible"), Some("ns16550a"));
+        assert_eq!(uart.prop_string("status"), Some("okay"));
+        assert_eq!(uart.prop_u32("clock-frequency"), Some(1_843_200));
+        assert_eq!(uart.properties.len(), 4);
+        let widget = uart.find_child("widget").unwrap();
+        assert_eq!(widget.prop_string("compatible"), Some("acme,widget"));
+
+        // Fragment 1 targeted /soc by path; its phandle cell was patched
+        let clock = merged.find_node("/soc/clock@2000").unwrap();
+        assert_eq!(clock.prop_u32_array("clocks"), Some(vec![1, 5]));
+    }
+
+    #[test]
+    fn test_apply_overlay_merges_existing_children() {
+        let base_data = base_dtb();
+        let base = DeviceTreeParser::new(&base_data).parse_tree().unwrap();
+        let overlay_data = StructBuilder::new()
+            .begin_node("")
+            .begin_node("fragment@0")
+            .named_property("target-path", b"/\0")
+            .begin_node("__overlay__")
+            .begin_node("soc")
+            .begin_node("uart@1000")
+            .named_property("status", b"okay\0")
+            .end_node()
+            .end_node()
+            .end_node()
+            .end_node()
+            .end_node()
+            .end()
+            .build_dtb();
+
+        let merged = DeviceTreeParser::apply_overlay(&base, &overlay_data).unwrap();
+        let soc = merged.find_node("/soc").unwrap();
+        assert_eq!(soc.children.len(), 1);
+        assert_eq!(soc.children[0].prop_string("status"), Some("okay"));
+    }
+
+    fn local_overlay_dtb(offsets: &[u8]) -> Vec<u8> {
+        StructBuilder::new()
+            .begin_node("")
+            .begin_node("fragment@0")
+            .named_property("target-path", b"/soc\0")
+            .begin_node("__overlay__")
+            .begin_node("clock@3000")
+            .named_property("phandle", &1u32.to_be_bytes())
+            .end_node()
+            .begin_node("timer@4000")
+            .named_property("clocks", &[0, 0, 0, 1, 0, 0, 0, 7])
+            .end_node()
+            .end_node()
+            .end_node()
+            .begin_node("__local_fixups__")
+            .begin_node("fragment@0")
+            .begin_node("__overlay__")
+            .begin_node("timer@4000")
+            .named_property("clocks", offsets)
+            .end_node()
+            .end_node()
+            .end_node()
+            .end_node()
+            .end_node()
+            .end()
+            .build_dtb()
+    }
+
+    #[test]
+    fn test_apply_overlay_renumbers_local_phandles() {
+        let base_data = base_dtb();
+        let base = DeviceTreeParser::new(&base_data).parse_tree().unwrap();
+        let overlay_data = local_overlay_dtb(&0u32.to_be_bytes());
+
+        let merged = DeviceTreeParser::apply_overlay(&base, &overlay_data).unwrap();
+
+        // The overlay's phandle 1 moved past the base uart's phandle 1
+        let uart = merged.find_node("/soc/uart@1000").unwrap();
+        assert_eq!(uart.phandle(), Some(1));
+        let clock = merged.find_node("/soc/clock@3000").unwrap();
+        assert_eq!(clock.phandle(), Some(2));
+
+        // Only the listed reference was updated
+        let timer = merged.find_node("/soc/timer@4000").unwrap();
+        assert_eq!(timer.prop_u32_array("clocks"), Some(vec![2, 7]));
+        assert_eq!(
+            timer.find_property("clocks").unwrap().raw.as_deref(),
+            Some(&[0, 0, 0, 2, 0, 0, 0, 7][..])
+        );
+
+        // Offsets past the end of the property are rejected
+        let overlay_data = local_overlay_dtb(&8u32.to_be_bytes());
+        assert_eq!(
+            DeviceTreeParser::apply_overlay(&base, &overlay_data).unwrap_err(),
+            DtbError::InvalidOverlay
+        );
+    }
+
+    #[test]
+    fn test_apply_overlay_errors() {
+        let base_data = base_dtb();
+        let base = DeviceTreeParser::new(&base_data).parse_tree().unwrap();
+
+        // Label missing from __symbols__
+        let overlay_data = StructBuilder::new()
+            .begin_node("")
+            .begin_node("__fixups__")
+            .named_property("missing", b"/fragment@0:target:0\0")
+            .end_node()
+            .end_node()
+            .end()
+            .build_dtb();
+        assert_eq!(
+            DeviceTreeParser::apply_overlay(&base, &overlay_data).unwrap_err(),
+            DtbError::UnresolvedSymbol
+        );
+
+        // Fixup pointing past the end of the property
+        let overlay_data = overlay_dtb(b"/fragment@0:target:4\0");
+        assert_eq!(
+            DeviceTreeParser::apply_overlay(&base, &overlay_data).unwrap_err(),
+            DtbError::InvalidOverlay
+        );
+
+        // Unresolved target phandle
+        let overlay_data = overlay_dtb(b"/fragment@1/__overlay__/clock@2000:clocks:0\0");
+        assert_eq!(
+            DeviceTreeParser::apply_overlay(&base, &overlay_data).unwrap_err(),
+            DtbError::OverlayTargetNotFound
+        );
+
+        // Fragment without a target
+        let overlay_data = StructBuilder::new()
+            .begin_node("")
+            .begin_node("fragment@0")
+            .begin_node("__overlay__")
+            .end_node()
+            .end_node()
+            .end_node()
+            .end()
+            .build_dtb();
+        assert_eq!(
+            DeviceTreeParser::apply_overlay(&base, &overlay_data).unwrap_err(),
+            DtbError::InvalidOverlay
+        );
+    }
+}
@@ -0,0 +1,385 @@
+// ABOUTME: Owned copy of a device tree that does not borrow the DTB buffer
+// ABOUTME: Copies names and values into owned storage and borrows back as DeviceTreeNode
+
+use super::tree::{DeviceTreeNode, Property, PropertyValue};
+use alloc::{string::String, vec, vec::Vec};
+
+/// Device tree node that owns its names and values.
+///
+/// Created by [`DeviceTreeNode::to_owned_tree`]. Unlike [`DeviceTreeNode`],
+/// which borrows from the DTB buffer, an owned tree can outlive the buffer,
+/// so a temporary buffer can be dropped once the tree is copied out.
+///
+/// The common accessors are mirrored here. For the rest of the
+/// [`DeviceTreeNode`] API, borrow the tree back with
+/// [`as_node`](Self::as_node).
+///
+/// # Examples
+///
+/// ```rust
+/// # use device_tree_parser::{DeviceTreeParser, DtbError, OwnedDeviceTree};
+/// # fn example() -> Result<OwnedDeviceTree, DtbError> {
+/// let tree = {
+///     # let dtb_data = vec![0u8; 64]; // Mock data
+///     let parser = DeviceTreeParser::new(&dtb_data);
+///     parser.parse_tree()?.to_owned_tree()
+/// }; // dtb_data is dropped here
+///
+/// if let Some(uart) = tree.find_node("/pl011@9000000") {
+///     println!("UART compatible: {:?}", uart.compatible());
+/// }
+/// # Ok(tree)
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OwnedDeviceTree {
+    /// Node name (e.g., "cpu@0", "memory@40000000", "uart@9000000").
+    pub name: String,
+    /// Properties describing this node's characteristics.
+    pub properties: Vec<OwnedProperty>,
+    /// Child nodes in the device tree hierarchy.
+    pub children: Vec<OwnedDeviceTree>,
+}
+
+/// Owned counterpart of [`Property`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OwnedProperty {
+    /// Property name (e.g., "compatible", "reg", "interrupts").
+    pub name: String,
+    /// Strongly-typed property value.
+    pub value: OwnedPropertyValue,
+    /// Value bytes exactly as stored in the DTB, if they were kept.
+    pub raw: Option<Vec<u8>>,
+}
+
+/// Owned counterpart of [`PropertyValue`].
+///
+/// Each variant holds the same data as its [`PropertyValue`] namesake, with
+/// strings and byte slices copied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OwnedPropertyValue {
+    /// Empty property (property exists but has no value).
+    Empty,
+    /// Null-terminated string value.
+    String(String),
+    /// Multiple null-terminated strings in sequence.
+    StringList(Vec<String>),
+    /// 32-bit unsigned integer value.
+    U32(u32),
+    /// Array of 32-bit unsigned integers, as big-endian bytes.
+    U32Array(Vec<u8>),
+    /// 64-bit unsigned integer value.
+    U64(u64),
+    /// Array of 64-bit unsigned integers, as big-endian bytes.
+    U64Array(Vec<u8>),
+    /// Raw byte array for binary data.
+    Bytes(Vec<u8>),
+}
+
+impl DeviceTreeNode<'_> {
+    /// Copy the node and its subtree into owned storage.
+    ///
+    /// The result no longer borrows the DTB buffer. Property values keep
+    /// their classification, and raw bytes are copied when present.
+    #[must_use]
+    pub fn to_owned_tree(&self) -> OwnedDeviceTree {
+        OwnedDeviceTree {
+            name: String::from(self.name),
+            properties: self.properties.iter().map(OwnedProperty::from).collect(),
+            children: self.children.iter().map(Self::to_owned_tree).collect(),
+        }
+    }
+}
+
+impl From<&Property<'_>> for OwnedProperty {
+    fn from(property: &Property<'_>) -> Self {
+        Self {
+            name: String::from(property.name),
+            value: OwnedPropertyValue::from(&property.value),
+            raw: property.raw.map(<[u8]>::to_vec),
+        }
+    }
+}
+
+impl From<&PropertyValue<'_>> for OwnedPropertyValue {
+    fn from(value: &PropertyValue<'_>) -> Self {
+        match value {
+            PropertyValue::Empty => Self::Empty,
+            PropertyValue::String(s) => Self::String(String::from(*s)),
+            PropertyValue::StringList(list) => {
+                Self::StringList(list.iter().map(|s| String::from(*s)).collect())
+            }
+            PropertyValue::U32(v) => Self::U32(*v),
+            PropertyValue::U32Array(bytes) => Self::U32Array(bytes.to_vec()),
+            PropertyValue::U64(v) => Self::U64(*v),
+            PropertyValue::U64Array(bytes) => Self::U64Array(bytes.to_vec()),
+            PropertyValue::Bytes(bytes) => Self::Bytes(bytes.to_vec()),
+        }
+    }
+}
+
+impl OwnedPropertyValue {
+    /// Borrow the value as a [`PropertyValue`].
+    #[must_use]
+    pub fn as_value(&self) -> PropertyValue<'_> {
+        match self {
+            Self::Empty => PropertyValue::Empty,
+            Self::String(s) => PropertyValue::String(s),
+            Self::StringList(list) => {
+                PropertyValue::StringList(list.iter().map(String::as_str).collect())
+            }
+            Self::U32(v) => PropertyValue::U32(*v),
+            Self::U32Array(bytes) => PropertyValue::U32Array(bytes),
+            Self::U64(v) => PropertyValue::U64(*v),
+            Self::U64Array(bytes) => PropertyValue::U64Array(bytes),
+            Self::Bytes(bytes) => PropertyValue::Bytes(bytes),
+        }
+    }
+}
+
+impl OwnedProperty {
+    /// Borrow the property as a [`Property`].
+    #[must_use]
+    pub fn as_property(&self) -> Property<'_> {
+        Property {
+            name: &self.name,
+            value: self.value.as_value(),
+            raw: self.raw.as_deref(),
+        }
+    }
+}
+
+impl OwnedDeviceTree {
+    /// Borrow the tree as a [`DeviceTreeNode`].
+    ///
+    /// Gives access to the full [`DeviceTreeNode`] API. The node structure is
+    /// rebuilt on each call, but names and values are borrowed, not copied.
+    #[must_use]
+    pub fn as_node(&self) -> DeviceTreeNode<'_> {
+        DeviceTreeNode {
+            name: &self.name,
+            properties: self
+                .properties
+                .iter()
+                .map(OwnedProperty::as_property)
+                .collect(),
+            children: self.children.iter().map(Self::as_node).collect(),
+        }
+    }
+
+    /// Find a property by name
+    #[must_use]
+    pub fn find_property(&self, name: &str) -> Option<&OwnedProperty> {
+        self.properties.iter().find(|p| p.name == name)
+    }
+
+    /// Check if property exists
+    #[must_use]
+    pub fn has_property(&self, name: &str) -> bool {
+        self.find_property(name).is_some()
+    }
+
+    /// Find a child node by name
+    #[must_use]
+    pub fn find_child(&self, name: &str) -> Option<&OwnedDeviceTree> {
+        self.children.iter().find(|c| c.name == name)
+    }
+
+    /// Find a node by path (e.g., "/cpus/cpu@0")
+    ///
+    /// Matches path components the same way as
+    /// [`DeviceTreeNode::find_node`].
+    #[must_use]
+    pub fn find_node(&self, path: &str) -> Option<&OwnedDeviceTree> {
+        path.split('/')
+            .filter(|part| !part.is_empty())
+            .try_fold(self, |node, part| {
+                node.find_child(part).or_else(|| {
+                    node.children
+                        .iter()
+                        .find(|child| child.name.contains('@') && child.node_base_name() == part)
+                })
+            })
+    }
+
+    /// Get the node name without its unit address.
+    #[must_use]
+    pub fn node_base_name(&self) -> &str {
+        self.name
+            .split_once('@')
+            .map_or(self.name.as_str(), |(base, _)| base)
+    }
+
+    /// Get the node's phandle.
+    ///
+    /// Reads `phandle`, falling back to the legacy `linux,phandle` property.
+    #[must_use]
+    pub fn phandle(&self) -> Option<u32> {
+        self.prop_u32("phandle")
+            .or_else(|| self.prop_u32("linux,phandle"))
+    }
+
+    /// Get property value as u32
+    #[must_use]
+    pub fn prop_u32(&self, name: &str) -> Option<u32> {
+        self.find_property(name).and_then(|p| match &p.value {
+            OwnedPropertyValue::U32(val) => Some(*val),
+            OwnedPropertyValue::U32Array(bytes) if bytes.len() >= 4 => {
+                Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+            }
+            _ => None,
+        })
+    }
+
+    /// Get property value as u64
+    #[must_use]
+    pub fn prop_u64(&self, name: &str) -> Option<u64> {
+        self.find_property(name).and_then(|p| match &p.value {
+            OwnedPropertyValue::U64(val) => Some(*val),
+            OwnedPropertyValue::U64Array(bytes) if bytes.len() >= 8 => Some(u64::from_be_bytes([
+                bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
+            ])),
+            _ => None,
+        })
+    }
+
+    /// Get property value as string
+    #[must_use]
+    pub fn prop_string(&self, name: &str) -> Option<&str> {
+        self.find_property(name).and_then(|p| match &p.value {
+            OwnedPropertyValue::String(s) => Some(s.as_str()),
+            OwnedPropertyValue::StringList(list) => list.first().map(String::as_str),
+            _ => None,
+        })
+    }
+
+    /// Get property value as a list of strings
+    #[must_use]
+    pub fn prop_string_list(&self, name: &str) -> Option<Vec<&str>> {
+        self.find_property(name).and_then(|p| match &p.value {
+            OwnedPropertyValue::String(s) => Some(vec![s.as_str()]),
+            OwnedPropertyValue::StringList(list) => Some(list.iter().map(String::as_str).collect()),
+            _ => None,
+        })
+    }
+
+    /// Get property value as u32 array
+    #[must_use]
+    pub fn prop_u32_array(&self, name: &str) -> Option<Vec<u32>> {
+        self.find_property(name).and_then(|p| match &p.value {
+            OwnedPropertyValue::U32Array(bytes) => Some(
+                bytes
+                    .chunks_exact(4)
+                    .map(|chunk| u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+                    .collect(),
+            ),
+            OwnedPropertyValue::U32(val) => Some(vec![*val]),
+            _ => None,
+        })
+    }
+
+    /// Get property value as bytes
+    #[must_use]
+    pub fn prop_bytes(&self, name: &str) -> Option<&[u8]> {
+        self.find_property(name).and_then(|p| match &p.value {
+            OwnedPropertyValue::Bytes(bytes) => Some(bytes.as_slice()),
+            _ => None,
+        })
+    }
+
+    /// Get the entries of the `compatible` property
+    #[must_use]
+    pub fn compatible(&self) -> Vec<&str> {
+        self.prop_string_list("compatible").unwrap_or_default()
+    }
+
+    /// Check if any entry of the `compatible` property matches `compatible`
+    #[must_use]
+    pub fn is_compatible(&self, compatible: &str) -> bool {
+        self.compatible().contains(&compatible)
+    }
+
+    /// Get iterator over all nodes (depth-first traversal)
+    pub fn iter_nodes(&self) -> impl Iterator<Item = &OwnedDeviceTree> {
+        let mut stack = vec![self];
+        core::iter::from_fn(move || {
+            let node = stack.pop()?;
+            stack.extend(node.children.iter().rev());
+            Some(node)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dtb::DeviceTreeParser;
+    use crate::dtb::test_support::StructBuilder;
+    use alloc::string::ToString;
+
+    fn sample_dtb() -> Vec<u8> {
+        StructBuilder::new()
+            .begin_node("")
+            .named_property("#address-cells", &1u32.to_be_bytes())
+            .named_property("model", b"test-board\0")
+            .begin_node("soc")
+            .begin_node("uart@1000")
+            .named_property("compatible", b"ns16550a\0snps,dw-apb-uart\0")
+            .named_property("reg", &[0, 0, 0x10, 0, 0, 0, 1, 0])
+            .named_property("local-mac-address", &[0x52, 0x54, 0x00, 0x12, 0x34, 0x56])
+            .named_property("phandle", &7u32.to_be_bytes())
+            .end_node()
+            .end_node()
+            .end_node()
+            .end()
+            .build_dtb()
+    }
+
+    #[test]
+    fn test_owned_tree_outlives_buffer() {
+        let data = sample_dtb();
+        let (tree, expected) = {
+            let buffer = data.clone();
+            let parser = DeviceTreeParser::new(&buffer);
+            let root = parser.parse_tree().unwrap();
+            let owned = root.to_owned_tree();
+            (owned, root.to_string())
+        }; // buffer dropped here
+
+        assert_eq!(tree.as_node().to_string(), expected);
+        assert_eq!(tree.prop_u32("#address-cells"), Some(1));
+        assert_eq!(tree.prop_string("model"), Some("test-board"));
+
+        let uart = tree.find_node("/soc/uart").unwrap();
+        assert_eq!(uart.name, "uart@1000");
+        assert_eq!(uart.compatible(), ["ns16550a", "snps,dw-apb-uart"]);
+        assert!(uart.is_compatible("snps,dw-apb-uart"));
+        assert_eq!(uart.prop_u32_array("reg"), Some(vec![0x1000, 0x100]));
+        assert_eq!(
+            uart.prop_bytes("local-mac-address"),
+            Some(&[0x52, 0x54, 0x00, 0x12, 0x34, 0x56][..])
+        );
+        assert_eq!(uart.phandle(), Some(7));
+        assert_eq!(uart.node_base_name(), "uart");
+        assert!(tree.find_node("/soc/spi").is_none());
+
+        let names: Vec<&str> = tree.iter_nodes().map(|n| n.name.as_str()).collect();
+        assert_eq!(names, ["", "soc", "uart@1000"]);
+    }
+
+    #[test]
+    fn test_owned_tree_round_trip() {
+        let data = sample_dtb();
+        let parser = DeviceTreeParser::new(&data);
+        let root = parser.parse_tree().unwrap();
+
+        let owned = root.to_owned_tree();
+        assert!(owned.as_node().structurally_eq(&root));
+        assert_eq!(owned.as_node().to_owned_tree(), owned);
+
+        // The borrowed view supports the full node API
+        let node = owned.as_node();
+        let uart = node.find_node("/soc/uart@1000").unwrap();
+        assert_eq!(uart.prop_mac("local-mac-address").unwrap()[0], 0x52);
+    }
+}
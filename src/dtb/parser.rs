@@ -1,12 +1,119 @@
 // ABOUTME: Core DTB parser implementation using nom combinators
 // ABOUTME: Provides the main DeviceTreeParser struct and parsing logic
 
+use super::checksum;
+use super::cpu::CpuInfo;
+use super::cursor::{StructCursor, StructEvent};
 use super::error::DtbError;
 use super::header::DtbHeader;
-use super::memory::MemoryReservation;
+use super::memory::{MemoryReservation, ReservedRegion};
+use super::mmio::MmioEntry;
+use super::overlay;
+use super::owned::OwnedDeviceTree;
+use super::repair::{Repair, RepairedStrings};
+use super::schema::{PropKind, STANDARD_SCHEMA};
+use super::specifier;
+use super::stats::DtbStats;
 use super::tokens::DtbToken;
-use super::tree::{DeviceTreeNode, parse_node_name, parse_property_data};
-use alloc::vec::Vec;
+use super::tree::{
+    AddressSpec, DeviceTreeNode, PropertyValue, parse_node_name_with, parse_null_terminated_string,
+    parse_property_data_with, rebase_utf8_error,
+};
+use super::validate::ValidationWarning;
+use alloc::{format, string::String, vec, vec::Vec};
+
+/// Resource limits and strictness checks applied while parsing the structure block.
+///
+/// Untrusted DTBs can declare arbitrarily deep nesting, huge node counts, or
+/// oversized properties. These limits bound the work and memory used by
+/// [`DeviceTreeParser::parse_tree_with_options`]. The defaults are generous
+/// enough for any real-world device tree.
+///
+/// # Examples
+///
+/// ```rust
+/// # use device_tree_parser::{DeviceTreeParser, DtbError, ParseOptions};
+/// # fn example() -> Result<(), DtbError> {
+/// # let dtb_data = vec![0u8; 64]; // Mock data
+/// let parser = DeviceTreeParser::new(&dtb_data);
+///
+/// // Tighter limits for untrusted input
+/// let options = ParseOptions {
+///     max_depth: 16,
+///     max_nodes: 1024,
+///     ..ParseOptions::default()
+/// };
+/// let tree = parser.parse_tree_with_options(&options)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// Maximum node nesting depth, counting the root node as depth 1.
+    pub max_depth: usize,
+    /// Maximum total number of nodes in the tree.
+    pub max_nodes: usize,
+    /// Maximum length of a single property value in bytes.
+    pub max_property_len: usize,
+    /// Reject nodes that define the same property name twice.
+    ///
+    /// The specification forbids duplicate property names within a node.
+    /// When `false`, the last definition replaces earlier ones.
+    pub reject_duplicate_properties: bool,
+    /// Validate every `#address-cells` and `#size-cells` property as it is
+    /// parsed.
+    ///
+    /// Address cells must be 1-4 and size cells 0-4, each encoded as a single
+    /// 32-bit cell, the same ranges that [`DeviceTreeNode::address_cells`]
+    /// and [`DeviceTreeNode::size_cells`] accept. Without this option,
+    /// invalid values are only reported when those methods are called.
+    pub validate_cells: bool,
+    /// Keep each property's original value bytes in
+    /// [`Property::raw`](super::Property::raw).
+    ///
+    /// Values are still classified as usual; the raw bytes let tools re-emit
+    /// a property exactly even when the classification guessed wrong.
+    pub keep_raw: bool,
+}
+
+impl ParseOptions {
+    /// Default maximum nesting depth.
+    pub const DEFAULT_MAX_DEPTH: usize = 64;
+
+    /// Default maximum node count.
+    pub const DEFAULT_MAX_NODES: usize = 65_536;
+
+    /// Default maximum property value length (1 MiB).
+    pub const DEFAULT_MAX_PROPERTY_LEN: usize = 1024 * 1024;
+
+    /// Options with no limits, matching the behavior of
+    /// [`DeviceTreeParser::parse_tree`].
+    #[must_use]
+    pub const fn unlimited() -> Self {
+        Self {
+            max_depth: usize::MAX,
+            max_nodes: usize::MAX,
+            max_property_len: usize::MAX,
+            reject_duplicate_properties: false,
+            validate_cells: false,
+            keep_raw: false,
+        }
+    }
+}
+
+impl Default for ParseOptions {
+    /// Creates options with limits suitable for untrusted input.
+    fn default() -> Self {
+        Self {
+            max_depth: Self::DEFAULT_MAX_DEPTH,
+            max_nodes: Self::DEFAULT_MAX_NODES,
+            max_property_len: Self::DEFAULT_MAX_PROPERTY_LEN,
+            reject_duplicate_properties: false,
+            validate_cells: false,
+            keep_raw: false,
+        }
+    }
+}
 
 /// High-performance Device Tree Blob (DTB) parser with zero-copy parsing.
 ///
@@ -80,8 +187,20 @@ use alloc::vec::Vec;
 #[derive(Debug)]
 pub struct DeviceTreeParser<'a> {
     data: &'a [u8],
+    schema: &'a [(&'a str, PropKind)],
+    options: ParseOptions,
+    tree: TreeCache<'a>,
 }
 
+/// Holds the tree cached by [`DeviceTreeParser::parsed`].
+///
+/// `OnceLock` keeps the parser `Sync` when `std` is available; `no_std`
+/// builds fall back to `OnceCell`, which is not `Sync`.
+#[cfg(feature = "std")]
+type TreeCache<'a> = std::sync::OnceLock<DeviceTreeNode<'a>>;
+#[cfg(not(feature = "std"))]
+type TreeCache<'a> = core::cell::OnceCell<DeviceTreeNode<'a>>;
+
 impl<'a> DeviceTreeParser<'a> {
     /// Creates a new parser from raw DTB data.
     ///
@@ -111,7 +230,99 @@ impl<'a> DeviceTreeParser<'a> {
     /// ```
     #[must_use]
     pub fn new(data: &'a [u8]) -> Self {
-        Self { data }
+        Self {
+            data,
+            schema: &[],
+            options: ParseOptions::unlimited(),
+            tree: TreeCache::new(),
+        }
+    }
+
+    /// Decodes the listed properties by their declared kind.
+    ///
+    /// Each `(name, kind)` entry overrides the byte-pattern heuristic for
+    /// properties called `name`, in every node. This removes the ambiguity
+    /// between printable numbers and strings, or between zero-filled values
+    /// and empty strings. Values that don't fit their kind still fall back to
+    /// the heuristic. Applies to every tree and cursor produced by this
+    /// parser. See [`PropKind`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeParser, DtbError, PropKind};
+    /// # fn example() -> Result<(), DtbError> {
+    /// # let dtb_data = vec![0u8; 64]; // Mock data
+    /// let parser = DeviceTreeParser::new(&dtb_data).with_schema(&[
+    ///     ("reg", PropKind::RegArray),
+    ///     ("vendor,serial", PropKind::String),
+    /// ]);
+    /// let tree = parser.parse_tree()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_schema(self, schema: &'a [(&'a str, PropKind)]) -> Self {
+        Self {
+            data: self.data,
+            schema,
+            options: self.options,
+            tree: TreeCache::new(),
+        }
+    }
+
+    /// Sets the options used to build the tree cached by
+    /// [`parsed`](Self::parsed).
+    ///
+    /// The high-level discovery methods all read that cached tree, so this
+    /// applies limits such as [`ParseOptions::max_depth`] to them as well.
+    /// Defaults to [`ParseOptions::unlimited`]. Explicit calls such as
+    /// [`parse_tree_with_options`](Self::parse_tree_with_options) are
+    /// unaffected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeParser, DtbError, ParseOptions};
+    /// # fn example() -> Result<(), DtbError> {
+    /// # let dtb_data = vec![0u8; 64]; // Mock data
+    /// let parser = DeviceTreeParser::new(&dtb_data).with_options(ParseOptions::default());
+    /// let uarts = parser.uart_addresses()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_options(self, options: ParseOptions) -> Self {
+        Self {
+            data: self.data,
+            schema: self.schema,
+            options,
+            tree: TreeCache::new(),
+        }
+    }
+
+    /// Decodes standard properties by their type in the specification.
+    ///
+    /// Shorthand for [`with_schema`](Self::with_schema) with
+    /// [`STANDARD_SCHEMA`], so `reg`, `compatible`, `status`,
+    /// `#address-cells`, `interrupts`, `phandle`, and the other common
+    /// properties never depend on the byte heuristic.
+    /// Replaces any schema set earlier.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeParser, DtbError};
+    /// # fn example() -> Result<(), DtbError> {
+    /// # let dtb_data = vec![0u8; 64]; // Mock data
+    /// let parser = DeviceTreeParser::new(&dtb_data).with_standard_schema();
+    /// let tree = parser.parse_tree()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_standard_schema(self) -> Self {
+        self.with_schema(STANDARD_SCHEMA)
     }
 
     /// Returns a reference to the underlying DTB data.
@@ -132,6 +343,34 @@ impl<'a> DeviceTreeParser<'a> {
         self.data
     }
 
+    /// Computes a CRC-32 checksum of the DTB.
+    ///
+    /// Covers the `totalsize` bytes declared in the header, so trailing data
+    /// after the blob does not affect the result. If the header cannot be
+    /// parsed or declares more bytes than are available, the whole buffer is
+    /// checksummed instead. Useful for detecting a changed DTB between boots;
+    /// the value matches zlib's `crc32`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::DeviceTreeParser;
+    /// # let dtb_data = vec![0u8; 64]; // Mock data
+    /// let parser = DeviceTreeParser::new(&dtb_data);
+    ///
+    /// println!("DTB checksum: {:#010x}", parser.checksum());
+    /// ```
+    #[must_use]
+    pub fn checksum(&self) -> u32 {
+        let len = self
+            .parse_header()
+            .ok()
+            .and_then(|header| usize::try_from(header.totalsize).ok())
+            .filter(|&size| size <= self.data.len())
+            .unwrap_or(self.data.len());
+        checksum::crc32(&self.data[..len])
+    }
+
     /// Parses and returns the DTB file header.
     ///
     /// Contains metadata about the file structure including version information,
@@ -193,12 +432,52 @@ impl<'a> DeviceTreeParser<'a> {
     /// # }
     /// ```
     pub fn parse_memory_reservations(&self) -> Result<Vec<MemoryReservation>, DtbError> {
+        self.memory_reservations_for(&self.parse_header()?)
+    }
+
+    /// Parses all memory reservation entries, requiring the `(0, 0)` terminator.
+    ///
+    /// [`parse_memory_reservations`](Self::parse_memory_reservations) stops at
+    /// the start of the structure block when the terminator is missing. This
+    /// variant reports that as an error instead, for tools that check DTBs
+    /// for conformance.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError::UnterminatedReservations`] if the block ends
+    /// without a terminator, or other [`DtbError`] variants if the header or
+    /// block is malformed.
+    pub fn parse_memory_reservations_strict(&self) -> Result<Vec<MemoryReservation>, DtbError> {
         let header = self.parse_header()?;
-        let reservation_data = &self.data[header.off_mem_rsvmap as usize..];
-        let (_remaining, reservations) = MemoryReservation::parse_all(reservation_data)?;
+        let (_remaining, reservations) =
+            MemoryReservation::parse_all_strict(self.reservation_block(&header)?)?;
+        Ok(reservations)
+    }
+
+    /// Parses the memory reservation block located by `header`.
+    fn memory_reservations_for(
+        &self,
+        header: &DtbHeader,
+    ) -> Result<Vec<MemoryReservation>, DtbError> {
+        let (_remaining, reservations) =
+            MemoryReservation::parse_all(self.reservation_block(header)?)?;
         Ok(reservations)
     }
 
+    /// Returns the memory reservation block located by `header`.
+    ///
+    /// The block has no size field, so it is bounded by the structure block
+    /// when that follows it (the usual layout), and by `totalsize` otherwise.
+    fn reservation_block(&self, header: &DtbHeader) -> Result<&'a [u8], DtbError> {
+        let start = header.mem_rsvmap_offset()?;
+        let end = if header.off_dt_struct > header.off_mem_rsvmap {
+            header.off_dt_struct
+        } else {
+            header.totalsize
+        } as usize;
+        self.data.get(start..end).ok_or(DtbError::MalformedHeader)
+    }
+
     /// Parses and returns the complete device tree structure.
     ///
     /// Main parsing function that builds the entire device tree hierarchy starting
@@ -244,44 +523,52 @@ impl<'a> DeviceTreeParser<'a> {
     /// # }
     /// ```
     pub fn parse_tree(&self) -> Result<DeviceTreeNode<'a>, DtbError> {
-        let header = self.parse_header()?;
-
-        let struct_block_start = header.off_dt_struct as usize;
-        let struct_block_end = struct_block_start + header.size_dt_struct as usize;
-        let strings_block_start = header.off_dt_strings as usize;
-
-        if struct_block_start >= self.data.len()
-            || struct_block_end > self.data.len()
-            || strings_block_start >= self.data.len()
-        {
-            return Err(DtbError::MalformedHeader);
-        }
-
-        let struct_block = &self.data[struct_block_start..struct_block_end];
-        let strings_block = &self.data[strings_block_start..];
-
-        Self::parse_structure_block(struct_block, strings_block)
+        self.parse_tree_with_options(&ParseOptions::unlimited())
     }
 
-    /// Discovers UART device base addresses from the device tree.
-    ///
-    /// Searches for common UART device types and extracts their base addresses
-    /// from the `reg` property. Useful for setting up serial communication in
-    /// embedded systems.
+    /// Parses the complete device tree structure with resource limits.
     ///
-    /// Searches for these compatible strings:
-    /// - `ns16550a`, `ns16550` - PC-style 16550 UARTs
-    /// - `arm,pl011` - ARM `PrimeCell` UART
-    /// - `arm,sbsa-uart` - ARM Server Base System Architecture UART
-    /// - `snps,dw-apb-uart` - Synopsys `DesignWare` APB UART
+    /// Behaves like [`parse_tree`](Self::parse_tree) but enforces the nesting
+    /// depth, node count, and property length limits in `options`. Use this when
+    /// parsing DTBs from untrusted sources.
     ///
     /// # Errors
     ///
-    /// Returns [`DtbError`] if parsing fails.
+    /// Returns [`DtbError::NestingTooDeep`], [`DtbError::TooManyNodes`], or
+    /// [`DtbError::PropertyTooLarge`] if a limit is exceeded, and
+    /// [`DtbError::StructParse`] with the failing offset if a token, node name,
+    /// or property cannot be decoded. Other [`DtbError`] variants report
+    /// structural problems such as unbalanced nodes.
     ///
-    /// # Returns
+    /// # Examples
     ///
-    /// Returns a vector of UART base addresses. An empty vector indicates no UART devices were found.
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeParser, DtbError, ParseOptions};
+    /// # fn example() -> Result<(), DtbError> {
+    /// # let dtb_data = vec![0u8; 64]; // Mock data
+    /// let parser = DeviceTreeParser::new(&dtb_data);
+    /// let tree = parser.parse_tree_with_options(&ParseOptions::default())?;
+    /// println!("Root node has {} children", tree.children.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_tree_with_options(
+        &self,
+        options: &ParseOptions,
+    ) -> Result<DeviceTreeNode<'a>, DtbError> {
+        self.tree_for(&self.parse_header()?, options)
+    }
+
+    /// Parses the header, memory reservations, and device tree in one pass.
+    ///
+    /// Equivalent to calling [`parse_header`](Self::parse_header),
+    /// [`parse_memory_reservations`](Self::parse_memory_reservations), and
+    /// [`parse_tree`](Self::parse_tree), but decodes the header only once.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError`] if the header, reservation block, or structure
+    /// block is malformed.
     ///
     /// # Examples
     ///
@@ -290,267 +577,1561 @@ impl<'a> DeviceTreeParser<'a> {
     /// # fn example() -> Result<(), DtbError> {
     /// # let dtb_data = vec![0u8; 64]; // Mock data
     /// let parser = DeviceTreeParser::new(&dtb_data);
-    /// let uart_addresses = parser.uart_addresses()?;
-    ///
-    /// for (i, addr) in uart_addresses.iter().enumerate() {
-    ///     println!("UART {}: base address 0x{:08x}", i, addr);
-    /// }
+    /// let (header, reservations, tree) = parser.parse()?;
     ///
-    /// // Use first UART for system console
-    /// if let Some(&console_addr) = uart_addresses.first() {
-    ///     println!("Console UART at: 0x{:08x}", console_addr);
-    /// }
+    /// println!("DTB version {}", header.version);
+    /// println!("{} memory reservations", reservations.len());
+    /// println!("Root node has {} children", tree.children.len());
     /// # Ok(())
     /// # }
     /// ```
-    pub fn uart_addresses(&self) -> Result<Vec<u64>, DtbError> {
-        let root = self.parse_tree()?;
-        let mut addresses = Vec::new();
-
-        // Look for common UART compatible strings
-        let uart_compatibles = [
-            "ns16550a",
-            "ns16550",
-            "arm,pl011",
-            "arm,sbsa-uart",
-            "snps,dw-apb-uart",
-        ];
+    pub fn parse(
+        &self,
+    ) -> Result<(DtbHeader, Vec<MemoryReservation>, DeviceTreeNode<'a>), DtbError> {
+        let header = self.parse_header()?;
+        let reservations = self.memory_reservations_for(&header)?;
+        let tree = self.tree_for(&header, &ParseOptions::unlimited())?;
+        Ok((header, reservations, tree))
+    }
 
-        for compatible in &uart_compatibles {
-            let uart_nodes = root.find_compatible_nodes(compatible);
-            for node in uart_nodes {
-                if let Some(reg) = node.prop_u32_array("reg")
-                    && reg.len() >= 2
-                {
-                    // First cell is typically the address
-                    addresses.push(u64::from(reg[0]));
-                }
-            }
-        }
+    /// Parses the structure block located by `header`.
+    fn tree_for(
+        &self,
+        header: &DtbHeader,
+        options: &ParseOptions,
+    ) -> Result<DeviceTreeNode<'a>, DtbError> {
+        let struct_block = self.struct_block_for(header)?;
+        let strings_block = self.strings_block_for(header)?;
 
-        Ok(addresses)
+        parse_device_tree_iterative(struct_block, strings_block, options, self.schema)
     }
 
-    /// Retrieves the CPU timebase frequency from the device tree.
+    /// Parses the device tree, repairing invalid UTF-8 instead of failing.
     ///
-    /// Timebase frequency is used by CPU timers and is critical for accurate timing
-    /// in embedded systems. Searches the `/cpus` node and individual CPU nodes for
-    /// the `timebase-frequency` property.
-    ///
-    /// # Errors
+    /// Behaves like [`parse_tree_with_options`](Self::parse_tree_with_options),
+    /// but node names, property names, and string values that are not valid
+    /// UTF-8 have each invalid sequence replaced with U+FFFD rather than
+    /// failing with [`DtbError::InvalidUtf8`]. Null-terminated values holding
+    /// non-ASCII text are decoded as strings, where strict parsing leaves them
+    /// as [`PropertyValue::Bytes`].
     ///
-    /// Returns [`DtbError`] if parsing fails.
+    /// Valid strings still borrow from the DTB. Repaired ones are stored in
+    /// `repaired`, which the returned tree borrows, so nothing outlives the
+    /// store. Any strings left from an earlier parse are cleared first. A DTB
+    /// that needs repairs is walked twice: once to repair its strings, once
+    /// to build the tree around them.
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// Returns `Some(frequency)` if found, `None` if no timebase frequency is specified.
+    /// Returns the same errors as
+    /// [`parse_tree_with_options`](Self::parse_tree_with_options), except
+    /// [`DtbError::InvalidUtf8`].
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # use device_tree_parser::{DeviceTreeParser, DtbError};
+    /// # use device_tree_parser::{DeviceTreeParser, DtbError, ParseOptions, RepairedStrings};
     /// # fn example() -> Result<(), DtbError> {
     /// # let dtb_data = vec![0u8; 64]; // Mock data
     /// let parser = DeviceTreeParser::new(&dtb_data);
     ///
-    /// match parser.timebase_frequency()? {
-    ///     Some(freq) => {
-    ///         println!("CPU timebase: {} Hz", freq);
-    ///         println!("Timer resolution: {:.2} ns", 1_000_000_000.0 / freq as f64);
-    ///     }
-    ///     None => println!("No timebase frequency found"),
+    /// let mut repaired = RepairedStrings::new();
+    /// let tree = parser.parse_tree_lossy(&ParseOptions::default(), &mut repaired)?;
+    /// for child in &tree.children {
+    ///     println!("{}", child.name);
     /// }
+    /// println!("{} strings repaired", repaired.len());
     /// # Ok(())
     /// # }
     /// ```
-    pub fn timebase_frequency(&self) -> Result<Option<u32>, DtbError> {
-        let root = self.parse_tree()?;
-
-        // Look in /cpus node first
-        if let Some(cpus_node) = root.find_node("/cpus") {
-            if let Some(freq) = cpus_node.prop_u32("timebase-frequency") {
-                return Ok(Some(freq));
-            }
+    pub fn parse_tree_lossy<'s>(
+        &self,
+        options: &ParseOptions,
+        repaired: &'s mut RepairedStrings,
+    ) -> Result<DeviceTreeNode<'s>, DtbError>
+    where
+        'a: 's,
+    {
+        let header = self.parse_header()?;
+        let struct_block = self.struct_block_for(&header)?;
+        let strings_block = self.strings_block_for(&header)?;
 
-            // Check individual CPU nodes
-            for cpu in cpus_node {
-                if let Some(freq) = cpu.prop_u32("timebase-frequency") {
-                    return Ok(Some(freq));
-                }
-            }
+        repaired.clear();
+        let tree = parse_device_tree_repairing(
+            struct_block,
+            strings_block,
+            options,
+            self.schema,
+            Some(&mut Repair::Collect(repaired)),
+        )?;
+        if repaired.is_empty() {
+            return Ok(tree);
         }
 
-        Ok(None)
+        // Build the tree again, borrowing the repaired strings
+        let repaired: &'s RepairedStrings = repaired;
+        parse_device_tree_repairing(
+            struct_block,
+            strings_block,
+            options,
+            self.schema,
+            Some(&mut Repair::Lookup(repaired)),
+        )
     }
 
-    /// Discovers memory-mapped I/O (MMIO) regions from the device tree.
+    /// Returns a low-level cursor over the structure block.
     ///
-    /// Traverses all device nodes and extracts address/size pairs from their `reg`
-    /// properties. MMIO regions represent hardware devices mapped into the system's
-    /// physical address space.
+    /// The cursor yields begin-node, end-node, and property events one at a
+    /// time, for custom parsers that don't need the full tree. See
+    /// [`StructCursor`].
     ///
     /// # Errors
     ///
-    /// Returns [`DtbError`] if parsing fails.
-    ///
-    /// # Returns
-    ///
-    /// Returns a vector of `(address, size)` tuples representing MMIO regions.
+    /// Returns [`DtbError`] if the header is malformed or the blocks lie
+    /// outside the DTB.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # use device_tree_parser::{DeviceTreeParser, DtbError};
+    /// # use device_tree_parser::{DeviceTreeParser, DtbError, StructEvent};
     /// # fn example() -> Result<(), DtbError> {
     /// # let dtb_data = vec![0u8; 64]; // Mock data
     /// let parser = DeviceTreeParser::new(&dtb_data);
-    /// let mmio_regions = parser.discover_mmio_regions()?;
     ///
-    /// for (i, (addr, size)) in mmio_regions.iter().enumerate() {
-    ///     println!("MMIO Region {}: 0x{:08x} - 0x{:08x} (size: {} bytes)",
-    ///         i, addr, addr + size, size);
+    /// let mut cursor = parser.cursor()?;
+    /// let mut nodes = 0;
+    /// while let Some(event) = cursor.next_token()? {
+    ///     if let StructEvent::BeginNode(_) = event {
+    ///         nodes += 1;
+    ///     }
     /// }
-    ///
-    /// // Find regions larger than 1MB
-    /// let large_regions: Vec<_> = mmio_regions
-    ///     .iter()
-    ///     .filter(|(_, size)| *size > 1024 * 1024)
-    ///     .collect();
-    /// println!("Found {} large MMIO regions", large_regions.len());
+    /// println!("{} nodes", nodes);
     /// # Ok(())
     /// # }
     /// ```
-    pub fn discover_mmio_regions(&self) -> Result<Vec<(u64, u64)>, DtbError> {
-        let root = self.parse_tree()?;
-        let mut regions = Vec::new();
-
-        // Traverse all nodes and collect reg properties
-        for node in root.iter_nodes() {
-            if let Some(reg) = node.prop_u32_array("reg") {
-                // Parse reg property as address/size pairs
-                let mut i = 0;
-                while i + 1 < reg.len() {
-                    let address = u64::from(reg[i]);
-                    let size = u64::from(reg[i + 1]);
-                    regions.push((address, size));
-                    i += 2;
-                }
-            }
-        }
-
-        Ok(regions)
+    pub fn cursor(&self) -> Result<StructCursor<'a>, DtbError> {
+        let header = self.parse_header()?;
+        let struct_block = self.struct_block_for(&header)?;
+        let strings_block = self.strings_block_for(&header)?;
+        Ok(StructCursor::new(struct_block, strings_block).with_schema(self.schema))
     }
 
-    /// Discovers MMIO regions with optional address translation.
-    ///
-    /// This enhanced version of `discover_mmio_regions()` can optionally perform
-    /// address translation to convert device addresses to CPU address space.
-    /// This is essential for systems with complex bus hierarchies where device
-    /// register addresses differ from CPU-visible addresses.
-    ///
-    /// # Arguments
+    /// Returns the raw structure block.
     ///
-    /// * `translate_addresses` - Whether to perform address translation
+    /// The slice spans `size_dt_struct` bytes from `off_dt_struct`, validated
+    /// against `totalsize` and the buffer. It holds the node and property
+    /// tokens, for callers running their own low-level parsing or
+    /// diagnostics; [`cursor`](Self::cursor) walks it token by token.
     ///
     /// # Errors
     ///
-    /// Returns [`DtbError`] if parsing or address translation fails.
+    /// Returns [`DtbError::MalformedHeader`] if the header is malformed or the
+    /// block lies outside the DTB, or [`DtbError::AlignmentError`] if it does
+    /// not start on a 4-byte boundary.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use device_tree_parser::{DeviceTreeParser, DtbError};
     /// # fn example() -> Result<(), DtbError> {
-    /// # let dtb_data = vec![0u8; 64]; // Mock data  
+    /// # let dtb_data = vec![0u8; 64]; // Mock data
     /// let parser = DeviceTreeParser::new(&dtb_data);
     ///
-    /// // Get raw device addresses (no translation)
-    /// let raw_regions = parser.discover_mmio_regions_translated(false)?;
-    ///
-    /// // Get CPU-visible addresses (with translation)
+    /// let struct_block = parser.struct_block()?;
+    /// let strings_block = parser.strings_block()?;
+    /// println!(
+    ///     "{} bytes of structure, {} bytes of strings",
+    ///     struct_block.len(),
+    ///     strings_block.len()
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn struct_block(&self) -> Result<&'a [u8], DtbError> {
+        self.struct_block_for(&self.parse_header()?)
+    }
+
+    /// Returns the raw strings block.
+    ///
+    /// The slice spans `size_dt_strings` bytes from `off_dt_strings`,
+    /// validated against `totalsize` and the buffer. It holds the
+    /// null-terminated property names that property headers refer to by
+    /// offset.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError::MalformedHeader`] if the header is malformed or the
+    /// block lies outside the DTB.
+    pub fn strings_block(&self) -> Result<&'a [u8], DtbError> {
+        self.strings_block_for(&self.parse_header()?)
+    }
+
+    /// Returns the structure block located by `header`.
+    fn struct_block_for(&self, header: &DtbHeader) -> Result<&'a [u8], DtbError> {
+        self.data
+            .get(header.struct_block_range()?)
+            .ok_or(DtbError::MalformedHeader)
+    }
+
+    /// Returns the strings block located by `header`.
+    fn strings_block_for(&self, header: &DtbHeader) -> Result<&'a [u8], DtbError> {
+        self.data
+            .get(header.strings_block_range()?)
+            .ok_or(DtbError::MalformedHeader)
+    }
+
+    /// Counts nodes, properties, nesting depth, and property bytes.
+    ///
+    /// Streams the structure block with a [`StructCursor`] instead of building
+    /// the tree, so memory use does not grow with the tree. Each property
+    /// value is still decoded as it is read, which briefly allocates for
+    /// string lists. See [`DtbStats`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError`] if the header or structure block is malformed.
+    pub fn stats(&self) -> Result<DtbStats, DtbError> {
+        DtbStats::from_cursor(self.cursor()?)
+    }
+
+    /// Returns the device tree, parsing it on first use and caching the result.
+    ///
+    /// The high-level discovery methods share this cached tree, so repeated
+    /// calls such as [`uart_addresses`](Self::uart_addresses) and
+    /// [`find_node`](Self::find_node) parse the structure block only once.
+    /// The tree is built with the parser's schema and
+    /// [options](Self::with_options); changing either yields a new parser
+    /// with an empty cache. A failed parse is not cached and will be retried
+    /// on the next call.
+    ///
+    /// With the `std` feature the cache is a `OnceLock` and the parser is
+    /// `Sync`. Without it the cache is a `OnceCell`, so the parser is `Send`
+    /// but not `Sync`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError`] if the structure is malformed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeParser, DtbError};
+    /// # fn example() -> Result<(), DtbError> {
+    /// # let dtb_data = vec![0u8; 64]; // Mock data
+    /// let parser = DeviceTreeParser::new(&dtb_data);
+    ///
+    /// // First call parses, later calls reuse the cached tree
+    /// let root = parser.parsed()?;
+    /// println!("Root node has {} children", root.children.len());
+    /// let root_again = parser.parsed()?;
+    /// assert!(core::ptr::eq(root, root_again));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parsed(&self) -> Result<&DeviceTreeNode<'a>, DtbError> {
+        if let Some(tree) = self.tree.get() {
+            return Ok(tree);
+        }
+        let tree = self.parse_tree_with_options(&self.options)?;
+        Ok(self.tree.get_or_init(|| tree))
+    }
+
+    /// Runs a closure against the cached device tree without cloning nodes.
+    ///
+    /// The closure borrows the tree returned by [`parsed`](Self::parsed), so
+    /// lookups inside it can work with `&DeviceTreeNode` references directly.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError`] if parsing fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeParser, DtbError};
+    /// # fn example() -> Result<(), DtbError> {
+    /// # let dtb_data = vec![0u8; 64]; // Mock data
+    /// let parser = DeviceTreeParser::new(&dtb_data);
+    ///
+    /// let virtio_count = parser.with_tree(|root| {
+    ///     root.find_compatible_nodes("virtio,mmio").len()
+    /// })?;
+    /// println!("Found {} Virtio devices", virtio_count);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tree<R>(&self, f: impl FnOnce(&DeviceTreeNode<'a>) -> R) -> Result<R, DtbError> {
+        Ok(f(self.parsed()?))
+    }
+
+    /// Returns the root node's `model` property.
+    ///
+    /// Identifies the board or platform (e.g., `"linux,dummy-virt"`). Reads only
+    /// the root node's properties from the structure block without building the
+    /// full tree.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError`] if the header or root node is malformed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeParser, DtbError};
+    /// # fn example() -> Result<(), DtbError> {
+    /// # let dtb_data = vec![0u8; 64]; // Mock data
+    /// let parser = DeviceTreeParser::new(&dtb_data);
+    ///
+    /// if let Some(model) = parser.model()? {
+    ///     println!("Board: {}", model);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn model(&self) -> Result<Option<&'a str>, DtbError> {
+        Ok(self.root_property("model")?.and_then(|value| match value {
+            PropertyValue::String(s) => Some(s),
+            PropertyValue::StringList(list) => list.first().copied(),
+            _ => None,
+        }))
+    }
+
+    /// Returns the root node's `compatible` strings.
+    ///
+    /// Lists the platform compatible strings in most-specific to least-specific
+    /// order. Reads only the root node's properties from the structure block
+    /// without building the full tree. Returns an empty vector if the root node
+    /// has no `compatible` property.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError`] if the header or root node is malformed.
+    pub fn root_compatible(&self) -> Result<Vec<&'a str>, DtbError> {
+        Ok(match self.root_property("compatible")? {
+            Some(PropertyValue::String(s)) => vec![s],
+            Some(PropertyValue::StringList(list)) => list,
+            _ => Vec::new(),
+        })
+    }
+
+    /// Returns the value of a root node property.
+    ///
+    /// Streams the structure block with a [`StructCursor`] and stops at the
+    /// root's first child, since root properties precede child nodes. This
+    /// is much cheaper than [`parse_tree`](Self::parse_tree) for reading
+    /// `model`, `compatible`, or `#address-cells`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError`] if the header or root node is malformed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeParser, DtbError};
+    /// # fn example() -> Result<(), DtbError> {
+    /// # let dtb_data = vec![0u8; 64]; // Mock data
+    /// let parser = DeviceTreeParser::new(&dtb_data);
+    ///
+    /// if let Some(cells) = parser.root_property("#address-cells")? {
+    ///     println!("Root #address-cells: {}", cells);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn root_property(&self, name: &str) -> Result<Option<PropertyValue<'a>>, DtbError> {
+        let mut cursor = self.cursor()?;
+        if !matches!(cursor.next_token()?, Some(StructEvent::BeginNode(_))) {
+            return Err(DtbError::InvalidToken);
+        }
+        while let Some(StructEvent::Property(property)) = cursor.next_token()? {
+            if property.name == name {
+                return Ok(Some(property.value));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Reports whether any node in the tree has a property called `name`.
+    ///
+    /// Streams the structure block with a [`StructCursor`] and returns as soon
+    /// as a match is found, so no tree is built. Handy for quick feature
+    /// checks such as "does this DTB use `interrupts-extended`?".
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError`] if the header or the structure block up to the
+    /// first match is malformed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeParser, DtbError};
+    /// # fn example() -> Result<(), DtbError> {
+    /// # let dtb_data = vec![0u8; 64]; // Mock data
+    /// let parser = DeviceTreeParser::new(&dtb_data);
+    ///
+    /// if parser.any_node_has_property("interrupts-extended")? {
+    ///     println!("Tree uses interrupts-extended");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn any_node_has_property(&self, name: &str) -> Result<bool, DtbError> {
+        let mut cursor = self.cursor()?;
+        while let Some(event) = cursor.next_token()? {
+            if let StructEvent::Property(property) = event
+                && property.name == name
+            {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Returns every distinct `compatible` string in the tree, sorted.
+    ///
+    /// Streams the structure block with a [`StructCursor`] instead of building
+    /// the tree. Useful for driver coverage audits: the result is the set of
+    /// bindings a platform may need a driver for. Disabled nodes are included.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError`] if the header or structure block is malformed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeParser, DtbError};
+    /// # fn example() -> Result<(), DtbError> {
+    /// # let dtb_data = vec![0u8; 64]; // Mock data
+    /// let parser = DeviceTreeParser::new(&dtb_data);
+    ///
+    /// for compatible in parser.all_compatibles()? {
+    ///     println!("Needs a driver for {}", compatible);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn all_compatibles(&self) -> Result<Vec<&'a str>, DtbError> {
+        let mut cursor = self.cursor()?;
+        let mut compatibles = Vec::new();
+        while let Some(event) = cursor.next_token()? {
+            if let StructEvent::Property(property) = event
+                && property.name == "compatible"
+            {
+                match property.value {
+                    PropertyValue::String(s) => compatibles.push(s),
+                    PropertyValue::StringList(list) => compatibles.extend(list),
+                    _ => {}
+                }
+            }
+        }
+        compatibles.sort_unstable();
+        compatibles.dedup();
+        Ok(compatibles)
+    }
+
+    /// Returns every property name stored in the strings block.
+    ///
+    /// Reads the strings block directly without parsing the structure block,
+    /// returning the null-terminated names in the order they appear. Useful for
+    /// tooling and for validating that the strings block is well-formed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError::MalformedHeader`] if the strings block lies outside
+    /// the DTB data or contains an unterminated string, and
+    /// [`DtbError::InvalidUtf8`] if a name is not valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeParser, DtbError};
+    /// # fn example() -> Result<(), DtbError> {
+    /// # let dtb_data = vec![0u8; 64]; // Mock data
+    /// let parser = DeviceTreeParser::new(&dtb_data);
+    ///
+    /// for name in parser.property_names()? {
+    ///     println!("Property name: {}", name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn property_names(&self) -> Result<Vec<&'a str>, DtbError> {
+        let header = self.parse_header()?;
+
+        let mut input = self.strings_block_for(&header)?;
+        let strings_len = input.len();
+        let mut names = Vec::new();
+        while !input.is_empty() {
+            let (remaining, name) = parse_null_terminated_string(input)
+                .map_err(|e| rebase_utf8_error(e, strings_len - input.len()))?;
+            names.push(name);
+            input = remaining;
+        }
+
+        Ok(names)
+    }
+
+    /// Discovers UART device base addresses from the device tree.
+    ///
+    /// Searches for common UART device types and extracts their base addresses
+    /// from the `reg` property. Useful for setting up serial communication in
+    /// embedded systems.
+    ///
+    /// Searches for these compatible strings:
+    /// - `ns16550a`, `ns16550` - PC-style 16550 UARTs
+    /// - `arm,pl011` - ARM `PrimeCell` UART
+    /// - `arm,sbsa-uart` - ARM Server Base System Architecture UART
+    /// - `snps,dw-apb-uart` - Synopsys `DesignWare` APB UART
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError`] if parsing fails.
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of UART base addresses. An empty vector indicates no UART devices were found.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeParser, DtbError};
+    /// # fn example() -> Result<(), DtbError> {
+    /// # let dtb_data = vec![0u8; 64]; // Mock data
+    /// let parser = DeviceTreeParser::new(&dtb_data);
+    /// let uart_addresses = parser.uart_addresses()?;
+    ///
+    /// for (i, addr) in uart_addresses.iter().enumerate() {
+    ///     println!("UART {}: base address 0x{:08x}", i, addr);
+    /// }
+    ///
+    /// // Use first UART for system console
+    /// if let Some(&console_addr) = uart_addresses.first() {
+    ///     println!("Console UART at: 0x{:08x}", console_addr);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn uart_addresses(&self) -> Result<Vec<u64>, DtbError> {
+        let root = self.parsed()?;
+        let mut addresses = Vec::new();
+
+        // Look for common UART compatible strings
+        let uart_compatibles = [
+            "ns16550a",
+            "ns16550",
+            "arm,pl011",
+            "arm,sbsa-uart",
+            "snps,dw-apb-uart",
+        ];
+
+        for compatible in &uart_compatibles {
+            let uart_nodes = root.find_compatible_nodes(compatible);
+            for node in uart_nodes {
+                if let Some(reg) = node.prop_u32_array("reg")
+                    && reg.len() >= 2
+                {
+                    // First cell is typically the address
+                    addresses.push(u64::from(reg[0]));
+                }
+            }
+        }
+
+        Ok(addresses)
+    }
+
+    /// Retrieves the CPU timebase frequency from the device tree.
+    ///
+    /// Timebase frequency is used by CPU timers and is critical for accurate timing
+    /// in embedded systems. Searches the `/cpus` node, then individual CPU nodes,
+    /// then the root node for the `timebase-frequency` property. Both 32-bit and
+    /// 64-bit encodings are accepted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError`] if parsing fails.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(frequency)` if found, `None` if no timebase frequency is specified.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeParser, DtbError};
+    /// # fn example() -> Result<(), DtbError> {
+    /// # let dtb_data = vec![0u8; 64]; // Mock data
+    /// let parser = DeviceTreeParser::new(&dtb_data);
+    ///
+    /// match parser.timebase_frequency()? {
+    ///     Some(freq) => {
+    ///         println!("CPU timebase: {} Hz", freq);
+    ///         println!("Timer resolution: {:.2} ns", 1_000_000_000.0 / freq as f64);
+    ///     }
+    ///     None => println!("No timebase frequency found"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn timebase_frequency(&self) -> Result<Option<u64>, DtbError> {
+        let root = self.parsed()?;
+
+        // Look in /cpus node first
+        if let Some(cpus_node) = root.find_node("/cpus") {
+            if let Some(freq) = cpus_node.timebase_frequency() {
+                return Ok(Some(freq));
+            }
+
+            // Check individual CPU nodes
+            for cpu in cpus_node {
+                if let Some(freq) = cpu.timebase_frequency() {
+                    return Ok(Some(freq));
+                }
+            }
+        }
+
+        // Fall back to the root node
+        Ok(root.timebase_frequency())
+    }
+
+    /// Returns the kernel command line from `/chosen`'s `bootargs` property.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError`] if parsing fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeParser, DtbError};
+    /// # fn example() -> Result<(), DtbError> {
+    /// # let dtb_data = vec![0u8; 64]; // Mock data
+    /// let parser = DeviceTreeParser::new(&dtb_data);
+    ///
+    /// if let Some(cmdline) = parser.bootargs()? {
+    ///     println!("Command line: {}", cmdline);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn bootargs(&self) -> Result<Option<&'a str>, DtbError> {
+        Ok(self
+            .parsed()?
+            .find_node("/chosen")
+            .and_then(|chosen| chosen.prop_string_index("bootargs", 0)))
+    }
+
+    /// Returns the initial ramdisk location from `/chosen`.
+    ///
+    /// Reads `linux,initrd-start` and `linux,initrd-end`, each of which may be
+    /// encoded as one or two cells. Returns `(start, end)` where `end` is the
+    /// first byte past the ramdisk, or `None` unless both properties are
+    /// present.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError`] if parsing fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeParser, DtbError};
+    /// # fn example() -> Result<(), DtbError> {
+    /// # let dtb_data = vec![0u8; 64]; // Mock data
+    /// let parser = DeviceTreeParser::new(&dtb_data);
+    ///
+    /// if let Some((start, end)) = parser.initrd_range()? {
+    ///     println!("initrd: 0x{:x} - 0x{:x} ({} bytes)", start, end, end - start);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn initrd_range(&self) -> Result<Option<(u64, u64)>, DtbError> {
+        let Some(chosen) = self.parsed()?.find_node("/chosen") else {
+            return Ok(None);
+        };
+        let start = chosen.prop_one_or_two_cells("linux,initrd-start");
+        let end = chosen.prop_one_or_two_cells("linux,initrd-end");
+        Ok(start.zip(end))
+    }
+
+    /// Enumerates the CPUs described under `/cpus`.
+    ///
+    /// Only children with `device_type = "cpu"` are returned, so helper nodes
+    /// such as `cpu-map` are skipped. Each CPU's `reg` is decoded using the
+    /// `#address-cells` of `/cpus`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError`] if parsing fails or a CPU's `reg` cannot be decoded.
+    ///
+    /// # Returns
+    ///
+    /// Returns an empty vector if the tree has no `/cpus` node.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeParser, DtbError};
+    /// # fn example() -> Result<(), DtbError> {
+    /// # let dtb_data = vec![0u8; 64]; // Mock data
+    /// let parser = DeviceTreeParser::new(&dtb_data);
+    ///
+    /// let cpus = parser.cpus()?;
+    /// println!("Found {} CPUs", cpus.len());
+    /// for cpu in &cpus {
+    ///     println!("  {} (id {})", cpu.name, cpu.reg);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cpus(&self) -> Result<Vec<CpuInfo<'a>>, DtbError> {
+        let root = self.parsed()?;
+        let Some(cpus_node) = root.find_node("/cpus") else {
+            return Ok(Vec::new());
+        };
+
+        cpus_node
+            .children_where(|node| node.prop_string("device_type") == Some("cpu"))
+            .map(|cpu| CpuInfo::from_node(cpus_node, cpu))
+            .collect()
+    }
+
+    /// Finds the CPU that boots the system.
+    ///
+    /// Returns the child of `/cpus` whose first `reg` entry matches the
+    /// header's `boot_cpuid_phys`, the CPU that runs first and brings up the
+    /// others during SMP startup. `reg` is decoded using the `#address-cells`
+    /// of `/cpus`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError`] if the header or tree cannot be parsed.
+    ///
+    /// # Returns
+    ///
+    /// Returns `None` if there is no `/cpus` node or no CPU matches.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeParser, DtbError};
+    /// # fn example() -> Result<(), DtbError> {
+    /// # let dtb_data = vec![0u8; 64]; // Mock data
+    /// let parser = DeviceTreeParser::new(&dtb_data);
+    ///
+    /// if let Some(cpu) = parser.boot_cpu()? {
+    ///     println!("Booting on {}", cpu.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn boot_cpu(&self) -> Result<Option<DeviceTreeNode<'a>>, DtbError> {
+        let boot_id = u64::from(self.parse_header()?.boot_cpuid_phys);
+        let Some(cpus_node) = self.parsed()?.find_node("/cpus") else {
+            return Ok(None);
+        };
+
+        let address_cells =
+            cpus_node.prop_u32_or("#address-cells", AddressSpec::DEFAULT_ADDRESS_CELLS);
+        Ok(cpus_node
+            .children_where(|node| node.prop_string("device_type") == Some("cpu"))
+            .find(|cpu| cpu.prop_u64_at("reg", 0, address_cells) == Some(boot_id))
+            .cloned())
+    }
+
+    /// Discovers memory-mapped I/O (MMIO) regions from the device tree.
+    ///
+    /// Traverses all device nodes and extracts address/size pairs from their `reg`
+    /// properties. MMIO regions represent hardware devices mapped into the system's
+    /// physical address space.
+    ///
+    /// Each `reg` is read as single-cell address/size pairs and left
+    /// untranslated; use [`mmio_map`](Self::mmio_map) for multi-cell
+    /// addresses translated through every parent bus.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError`] if parsing fails.
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of `(address, size)` tuples representing MMIO regions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeParser, DtbError};
+    /// # fn example() -> Result<(), DtbError> {
+    /// # let dtb_data = vec![0u8; 64]; // Mock data
+    /// let parser = DeviceTreeParser::new(&dtb_data);
+    /// let mmio_regions = parser.discover_mmio_regions()?;
+    ///
+    /// for (i, (addr, size)) in mmio_regions.iter().enumerate() {
+    ///     println!("MMIO Region {}: 0x{:08x} - 0x{:08x} (size: {} bytes)",
+    ///         i, addr, addr + size, size);
+    /// }
+    ///
+    /// // Find regions larger than 1MB
+    /// let large_regions: Vec<_> = mmio_regions
+    ///     .iter()
+    ///     .filter(|(_, size)| *size > 1024 * 1024)
+    ///     .collect();
+    /// println!("Found {} large MMIO regions", large_regions.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn discover_mmio_regions(&self) -> Result<Vec<(u64, u64)>, DtbError> {
+        let root = self.parsed()?;
+        let mut regions = Vec::new();
+
+        // Traverse all nodes and collect reg properties
+        for node in root.iter_nodes() {
+            if let Some(reg) = node.prop_u32_array("reg") {
+                // Parse reg property as address/size pairs
+                let mut i = 0;
+                while i + 1 < reg.len() {
+                    let address = u64::from(reg[i]);
+                    let size = u64::from(reg[i + 1]);
+                    regions.push((address, size));
+                    i += 2;
+                }
+            }
+        }
+
+        Ok(regions)
+    }
+
+    /// Discovers MMIO regions with optional address translation.
+    ///
+    /// This enhanced version of `discover_mmio_regions()` can optionally perform
+    /// address translation to convert device addresses to CPU address space.
+    /// This is essential for systems with complex bus hierarchies where device
+    /// register addresses differ from CPU-visible addresses.
+    ///
+    /// # Arguments
+    ///
+    /// * `translate_addresses` - Whether to perform address translation
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError`] if parsing or address translation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeParser, DtbError};
+    /// # fn example() -> Result<(), DtbError> {
+    /// # let dtb_data = vec![0u8; 64]; // Mock data  
+    /// let parser = DeviceTreeParser::new(&dtb_data);
+    ///
+    /// // Get raw device addresses (no translation)
+    /// let raw_regions = parser.discover_mmio_regions_translated(false)?;
+    ///
+    /// // Get CPU-visible addresses (with translation)
     /// let cpu_regions = parser.discover_mmio_regions_translated(true)?;
     ///
-    /// for ((raw_addr, size), (cpu_addr, _)) in raw_regions.iter().zip(cpu_regions.iter()) {
-    ///     println!("Device 0x{:x} -> CPU 0x{:x} (size: {})", raw_addr, cpu_addr, size);
-    /// }
+    /// for ((raw_addr, size), (cpu_addr, _)) in raw_regions.iter().zip(cpu_regions.iter()) {
+    ///     println!("Device 0x{:x} -> CPU 0x{:x} (size: {})", raw_addr, cpu_addr, size);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn discover_mmio_regions_translated(
+        &self,
+        translate_addresses: bool,
+    ) -> Result<Vec<(u64, u64)>, DtbError> {
+        let root = self.parsed()?;
+        let mut regions = Vec::new();
+
+        // Traverse all nodes and collect reg properties
+        for node in root.iter_nodes() {
+            if let Some(reg) = node.prop_u32_array("reg") {
+                // Get address cell configuration for this node's parent context
+                let address_cells = node.address_cells().unwrap_or(2);
+                let size_cells = node.size_cells().unwrap_or(1);
+
+                // Calculate entry size (address + size cells)
+                let entry_size = (address_cells + size_cells) as usize;
+
+                // Parse reg property as address/size pairs with proper cell sizes
+                let mut i = 0;
+                while i + entry_size <= reg.len() {
+                    // Parse address (may be multi-cell)
+                    let mut address = 0u64;
+                    for j in 0..address_cells as usize {
+                        address = (address << 32) | u64::from(reg[i + j]);
+                    }
+
+                    // Parse size (may be multi-cell)
+                    let mut size = 0u64;
+                    for j in 0..size_cells as usize {
+                        size = (size << 32) | u64::from(reg[i + address_cells as usize + j]);
+                    }
+
+                    // Optionally translate address to CPU address space
+                    let final_address = if translate_addresses {
+                        // Try to translate using single-level translation first
+                        // In a complete implementation, we would walk up the tree hierarchy
+                        match node.translate_address(address, None, address_cells) {
+                            Ok(translated) => translated,
+                            Err(_) => {
+                                // If translation fails, try recursive translation
+                                // For now, fall back to original address
+                                address
+                            }
+                        }
+                    } else {
+                        address
+                    };
+
+                    regions.push((final_address, size));
+                    i += entry_size;
+                }
+            }
+        }
+
+        Ok(regions)
+    }
+
+    /// Finds a device tree node by its absolute path.
+    ///
+    /// Device tree paths use Unix-style notation starting from the root (`/`).
+    /// Provides convenient access to specific nodes when you know their location
+    /// in the tree hierarchy.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Absolute path to the node (e.g., `/cpus/cpu@0`, `/chosen`)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError`] if parsing fails.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(node)` if found, `None` if the path doesn't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeParser, DtbError};
+    /// # fn example() -> Result<(), DtbError> {
+    /// # let dtb_data = vec![0u8; 64]; // Mock data
+    /// let parser = DeviceTreeParser::new(&dtb_data);
+    ///
+    /// // Find specific system nodes
+    /// if let Some(chosen) = parser.find_node("/chosen")? {
+    ///     if let Some(bootargs) = chosen.prop_string("bootargs") {
+    ///         println!("Boot arguments: {}", bootargs);
+    ///     }
+    /// }
+    ///
+    /// // Find CPU information
+    /// if let Some(cpu0) = parser.find_node("/cpus/cpu@0")? {
+    ///     if let Some(compatible) = cpu0.prop_string("compatible") {
+    ///         println!("CPU type: {}", compatible);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn find_node(&self, path: &str) -> Result<Option<DeviceTreeNode<'a>>, DtbError> {
+        let root = self.parsed()?;
+        Ok(root.find_node(path).cloned())
+    }
+
+    /// Finds all device tree nodes with a specific compatible string.
+    ///
+    /// The `compatible` property lists the devices that a node is compatible with,
+    /// typically in most-specific to least-specific order. Searches for nodes that
+    /// contain the specified string in their compatible property.
+    ///
+    /// # Arguments
+    ///
+    /// * `compatible` - Compatible string to search for (e.g., `"arm,pl011"`)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError`] if parsing fails.
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of matching nodes. An empty vector indicates no matching nodes were found.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeParser, DtbError};
+    /// # fn example() -> Result<(), DtbError> {
+    /// # let dtb_data = vec![0u8; 64]; // Mock data
+    /// let parser = DeviceTreeParser::new(&dtb_data);
+    ///
+    /// // Find all ARM PL011 UART devices
+    /// let uart_nodes = parser.find_compatible_nodes("arm,pl011")?;
+    /// for (i, node) in uart_nodes.iter().enumerate() {
+    ///     println!("UART {}: {}", i, node.name);
+    ///     if let Some(reg) = node.prop_u32_array("reg") {
+    ///         println!("  Base address: 0x{:08x}", reg[0]);
+    ///     }
+    /// }
+    ///
+    /// // Find all Virtio devices
+    /// let virtio_nodes = parser.find_compatible_nodes("virtio,mmio")?;
+    /// println!("Found {} Virtio devices", virtio_nodes.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn find_compatible_nodes(
+        &self,
+        compatible: &str,
+    ) -> Result<Vec<DeviceTreeNode<'a>>, DtbError> {
+        let root = self.parsed()?;
+        let nodes = root.find_compatible_nodes(compatible);
+        Ok(nodes.into_iter().cloned().collect())
+    }
+
+    /// Finds a device tree node by path, borrowing it from the cached tree.
+    ///
+    /// Zero-copy counterpart of [`find_node`](Self::find_node).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError`] if parsing fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeParser, DtbError};
+    /// # fn example() -> Result<(), DtbError> {
+    /// # let dtb_data = vec![0u8; 64]; // Mock data
+    /// let parser = DeviceTreeParser::new(&dtb_data);
+    ///
+    /// if let Some(chosen) = parser.find_node_ref("/chosen")? {
+    ///     println!("Chosen node has {} properties", chosen.properties.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn find_node_ref(&self, path: &str) -> Result<Option<&DeviceTreeNode<'a>>, DtbError> {
+        Ok(self.parsed()?.find_node(path))
+    }
+
+    /// Finds all nodes with a specific compatible string, borrowing them from
+    /// the cached tree.
+    ///
+    /// Zero-copy counterpart of [`find_compatible_nodes`](Self::find_compatible_nodes).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError`] if parsing fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeParser, DtbError};
+    /// # fn example() -> Result<(), DtbError> {
+    /// # let dtb_data = vec![0u8; 64]; // Mock data
+    /// let parser = DeviceTreeParser::new(&dtb_data);
+    ///
+    /// for uart in parser.find_compatible_nodes_ref("arm,pl011")? {
+    ///     println!("UART: {}", uart.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn find_compatible_nodes_ref(
+        &self,
+        compatible: &str,
+    ) -> Result<Vec<&DeviceTreeNode<'a>>, DtbError> {
+        Ok(self.parsed()?.find_compatible_nodes(compatible))
+    }
+
+    /// Calls `f` for each node compatible with `compatible`, in tree order.
+    ///
+    /// Visits the cached tree directly, without cloning nodes or collecting
+    /// matches into a vector.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError`] if parsing fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeParser, DtbError};
+    /// # fn example() -> Result<(), DtbError> {
+    /// # let dtb_data = vec![0u8; 64]; // Mock data
+    /// let parser = DeviceTreeParser::new(&dtb_data);
+    ///
+    /// let mut count = 0;
+    /// parser.for_each_compatible("virtio,mmio", |node| {
+    ///     println!("Virtio device: {}", node.name);
+    ///     count += 1;
+    /// })?;
     /// # Ok(())
     /// # }
     /// ```
-    pub fn discover_mmio_regions_translated(
+    pub fn for_each_compatible(
         &self,
-        translate_addresses: bool,
-    ) -> Result<Vec<(u64, u64)>, DtbError> {
-        let root = self.parse_tree()?;
-        let mut regions = Vec::new();
+        compatible: &str,
+        f: impl FnMut(&DeviceTreeNode<'a>),
+    ) -> Result<(), DtbError> {
+        self.parsed()?
+            .iter_nodes()
+            .filter(|node| node.is_compatible(compatible))
+            .for_each(f);
+        Ok(())
+    }
 
-        // Traverse all nodes and collect reg properties
-        for node in root.iter_nodes() {
-            if let Some(reg) = node.prop_u32_array("reg") {
-                // Get address cell configuration for this node's parent context
-                let address_cells = node.address_cells().unwrap_or(2);
-                let size_cells = node.size_cells().unwrap_or(1);
+    /// Finds a node by alias, absolute path, or alias-relative path.
+    ///
+    /// Resolves paths the way Linux's `of_find_node_by_path` does:
+    /// - `serial0` is looked up in `/aliases` and resolved to its target node
+    /// - `/soc/uart@1000` is treated as an absolute path
+    /// - `ethernet0/mdio` expands the `ethernet0` alias, then looks up `mdio`
+    ///   beneath the aliased node
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError`] if parsing fails.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(node)` if found, `None` if the alias is undefined or the
+    /// path doesn't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeParser, DtbError};
+    /// # fn example() -> Result<(), DtbError> {
+    /// # let dtb_data = vec![0u8; 64]; // Mock data
+    /// let parser = DeviceTreeParser::new(&dtb_data);
+    ///
+    /// if let Some(console) = parser.find_node_flexible("serial0")? {
+    ///     println!("Console UART: {}", console.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn find_node_flexible(&self, path: &str) -> Result<Option<&DeviceTreeNode<'a>>, DtbError> {
+        let root = self.parsed()?;
+        if path.starts_with('/') {
+            return Ok(root.find_node(path));
+        }
+        Ok(find_aliased_node(root, path))
+    }
 
-                // Calculate entry size (address + size cells)
-                let entry_size = (address_cells + size_cells) as usize;
+    /// Translates a bus address of a node to a CPU physical address.
+    ///
+    /// `address` is interpreted in the address space of the node's parent
+    /// (typically a value from the node's `reg`). The `ranges` property of
+    /// every ancestor bus is applied in turn, from the node's parent up to the
+    /// root, using each bus's own cell counts and its parent's
+    /// `#address-cells`. An empty `ranges` maps 1:1.
+    ///
+    /// # Arguments
+    ///
+    /// * `node_path` - Absolute path to the node (e.g., `/soc/bus@0/uart@1000`)
+    /// * `address` - Address in the parent bus's address space
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError::AddressTranslationError`] if the node does not exist,
+    /// an ancestor bus has no `ranges` property, or no range contains the
+    /// address. Returns other errors if parsing fails or cell counts and
+    /// `ranges` data are invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeParser, DtbError};
+    /// # fn example() -> Result<(), DtbError> {
+    /// # let dtb_data = vec![0u8; 64]; // Mock data
+    /// let parser = DeviceTreeParser::new(&dtb_data);
+    ///
+    /// let cpu_addr = parser.translate_address("/soc/serial@1000", 0x1000)?;
+    /// println!("UART registers at 0x{:x}", cpu_addr);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn translate_address(&self, node_path: &str, address: u64) -> Result<u64, DtbError> {
+        let root = self.parsed()?;
+        let chain = root
+            .find_node_chain(node_path)
+            .ok_or(DtbError::AddressTranslationError(address))?;
 
-                // Parse reg property as address/size pairs with proper cell sizes
-                let mut i = 0;
-                while i + entry_size <= reg.len() {
-                    // Parse address (may be multi-cell)
-                    let mut address = 0u64;
-                    for j in 0..address_cells as usize {
-                        address = (address << 32) | u64::from(reg[i + j]);
-                    }
+        translate_through_chain(&chain, address)
+    }
 
-                    // Parse size (may be multi-cell)
-                    let mut size = 0u64;
-                    for j in 0..size_cells as usize {
-                        size = (size << 32) | u64::from(reg[i + address_cells as usize + j]);
-                    }
+    /// Finds the device whose `reg` region contains a physical address.
+    ///
+    /// Walks every node with a `reg` property, translates each region to a
+    /// CPU physical address through the ancestor buses' `ranges`, and returns
+    /// the path of the node containing `phys` together with the offset of
+    /// `phys` within that region. When regions nest (e.g., a bus and one of
+    /// its devices), the deepest node wins. Nodes whose `reg` or ancestor
+    /// `ranges` cannot be decoded or translated are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError`] if parsing fails.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some((path, offset))` for the owning node, or `None` if no
+    /// region contains the address.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeParser, DtbError};
+    /// # fn example() -> Result<(), DtbError> {
+    /// # let dtb_data = vec![0u8; 64]; // Mock data
+    /// let parser = DeviceTreeParser::new(&dtb_data);
+    ///
+    /// let fault_address = 0x0900_0018;
+    /// if let Some((path, offset)) = parser.device_at_address(fault_address)? {
+    ///     println!("Fault in {} at register offset 0x{:x}", path, offset);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn device_at_address(&self, phys: u64) -> Result<Option<(String, u64)>, DtbError> {
+        let root = self.parsed()?;
+        let mut chain = vec![root];
+        let mut found = None;
+        find_device_at(&mut chain, "", phys, &mut found);
+        Ok(found.map(|(path, offset, _depth)| (path, offset)))
+    }
 
-                    // Optionally translate address to CPU address space
-                    let final_address = if translate_addresses {
-                        // Try to translate using single-level translation first
-                        // In a complete implementation, we would walk up the tree hierarchy
-                        match node.translate_address(address, None, address_cells) {
-                            Ok(translated) => translated,
-                            Err(_) => {
-                                // If translation fails, try recursive translation
-                                // For now, fall back to original address
-                                address
-                            }
-                        }
-                    } else {
-                        address
-                    };
+    /// Lists every device's physical address region, sorted by start address.
+    ///
+    /// Each `reg` entry is translated to the CPU address space through the
+    /// ancestor buses' `ranges` and reported as `(path, start, end)`, where
+    /// `end` is exclusive (saturating at `u64::MAX`). A node with several
+    /// `reg` entries appears once per entry. Overlapping regions, such as a
+    /// bus and its devices, are all listed. Zero-sized entries (e.g., CPU
+    /// IDs under `/cpus`) and regions that cannot be translated are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError`] if parsing fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeParser, DtbError};
+    /// # fn example() -> Result<(), DtbError> {
+    /// # let dtb_data = vec![0u8; 64]; // Mock data
+    /// let parser = DeviceTreeParser::new(&dtb_data);
+    ///
+    /// for (path, start, end) in parser.address_space_summary()? {
+    ///     println!("{:#012x}-{:#012x} {}", start, end, path);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn address_space_summary(&self) -> Result<Vec<(String, u64, u64)>, DtbError> {
+        let root = self.parsed()?;
+        let mut regions = Vec::new();
+        for_each_region(&mut vec![root], "", &mut |path, _, base, size, _depth| {
+            if size > 0 {
+                regions.push((String::from(path), base, base.saturating_add(size)));
+            }
+        });
+        regions.sort_by_key(|&(_, start, _)| start);
+        Ok(regions)
+    }
 
-                    regions.push((final_address, size));
-                    i += entry_size;
+    /// Builds a map of every register region with its translated address.
+    ///
+    /// Each `reg` entry is decoded with its parent's cell counts and
+    /// translated to the CPU address space through the `ranges` of every bus
+    /// up to the root. Entries are listed in tree order (depth-first), once
+    /// per `reg` entry. Zero-sized entries (e.g., CPU IDs under `/cpus`) and
+    /// regions that cannot be translated are skipped.
+    ///
+    /// Unlike [`discover_mmio_regions`](Self::discover_mmio_regions), this
+    /// honors multi-cell addresses and multi-level translation, and keeps
+    /// the bus-local address alongside the physical one. See [`MmioEntry`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError`] if parsing fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeParser, DtbError};
+    /// # fn example() -> Result<(), DtbError> {
+    /// # let dtb_data = vec![0u8; 64]; // Mock data
+    /// let parser = DeviceTreeParser::new(&dtb_data);
+    ///
+    /// let remapped = parser
+    ///     .mmio_map()?
+    ///     .into_iter()
+    ///     .filter(|entry| entry.child_address != entry.physical_address);
+    /// for entry in remapped {
+    ///     println!("{} is remapped to 0x{:x}", entry.path, entry.physical_address);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn mmio_map(&self) -> Result<Vec<MmioEntry>, DtbError> {
+        let root = self.parsed()?;
+        let mut entries = Vec::new();
+        for_each_region(
+            &mut vec![root],
+            "",
+            &mut |path, address, base, size, _depth| {
+                if size > 0 {
+                    entries.push(MmioEntry {
+                        path: String::from(path),
+                        child_address: address,
+                        physical_address: base,
+                        size,
+                    });
+                }
+            },
+        );
+        Ok(entries)
+    }
+
+    /// Finds all enabled device tree nodes with a specific compatible string.
+    ///
+    /// Like [`find_compatible_nodes`](Self::find_compatible_nodes), but skips
+    /// nodes whose `status` property marks them as disabled, reserved, or failed.
+    /// Drivers should use this to avoid binding to hardware that is not usable.
+    ///
+    /// # Arguments
+    ///
+    /// * `compatible` - Compatible string to search for (e.g., `"arm,pl011"`)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError`] if parsing fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeParser, DtbError};
+    /// # fn example() -> Result<(), DtbError> {
+    /// # let dtb_data = vec![0u8; 64]; // Mock data
+    /// let parser = DeviceTreeParser::new(&dtb_data);
+    ///
+    /// for uart in parser.find_enabled_compatible_nodes("arm,pl011")? {
+    ///     println!("Usable UART: {}", uart.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn find_enabled_compatible_nodes(
+        &self,
+        compatible: &str,
+    ) -> Result<Vec<DeviceTreeNode<'a>>, DtbError> {
+        let root = self.parsed()?;
+        let nodes = root.find_compatible_nodes(compatible);
+        Ok(nodes
+            .into_iter()
+            .filter(|node| node.is_enabled())
+            .cloned()
+            .collect())
+    }
+
+    /// Decodes the children of the `/reserved-memory` node.
+    ///
+    /// Each child's `reg` entries become statically placed regions. A child
+    /// without `reg` is placed dynamically, and its `size` property (in
+    /// `/reserved-memory`'s `#size-cells`) gives the size. The `no-map` and
+    /// `reusable` flags and `compatible` strings are included. Returns an
+    /// empty list if there is no `/reserved-memory` node. This is separate
+    /// from the legacy reservation block returned by
+    /// [`parse_memory_reservations`](Self::parse_memory_reservations).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError`] if parsing fails or a child's `reg` is malformed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeParser, DtbError};
+    /// # fn example() -> Result<(), DtbError> {
+    /// # let dtb_data = vec![0u8; 64]; // Mock data
+    /// let parser = DeviceTreeParser::new(&dtb_data);
+    ///
+    /// for region in parser.reserved_memory()? {
+    ///     if region.no_map {
+    ///         println!("{} must stay unmapped", region.name);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn reserved_memory(&self) -> Result<Vec<ReservedRegion<'a>>, DtbError> {
+        let root = self.parsed()?;
+        let Some(reserved_memory) = root.find_child("reserved-memory") else {
+            return Ok(Vec::new());
+        };
+        let size_cells = reserved_memory.size_cells()?;
+
+        let mut regions = Vec::new();
+        for child in &reserved_memory.children {
+            let region = |address, size| ReservedRegion {
+                name: child.name,
+                address,
+                size,
+                no_map: child.has_property("no-map"),
+                reusable: child.has_property("reusable"),
+                compatible: child.compatible(),
+            };
+            if child.has_property("reg") {
+                let mut index = 0;
+                while let Some((address, size)) = child.reg_index(index, Some(reserved_memory))? {
+                    regions.push(region(Some(address), size));
+                    index += 1;
                 }
+            } else if let Some(size) = child.prop_u64_at("size", 0, size_cells) {
+                regions.push(region(None, size));
             }
         }
-
         Ok(regions)
     }
 
-    /// Finds a device tree node by its absolute path.
-    ///
-    /// Device tree paths use Unix-style notation starting from the root (`/`).
-    /// Provides convenient access to specific nodes when you know their location
-    /// in the tree hierarchy.
-    ///
-    /// # Arguments
+    /// Returns the free RAM regions left after removing reserved memory.
+    ///
+    /// Starts from the `reg` regions of the root's memory nodes
+    /// (`device_type = "memory"`) and subtracts the legacy memory
+    /// reservation block and the statically placed (`reg`) children of
+    /// `/reserved-memory`. Reservations that partially overlap a region
+    /// split it. Returns `(base, size)` pairs sorted by base address, as a
+    /// starting point for a physical memory allocator.
+    ///
+    /// Dynamically placed `/reserved-memory` children (with `size` but no
+    /// `reg`) have no fixed address yet and are not subtracted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError`] if parsing fails or a memory node's or
+    /// `/reserved-memory` child's `reg` is malformed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeParser, DtbError};
+    /// # fn example() -> Result<(), DtbError> {
+    /// # let dtb_data = vec![0u8; 64]; // Mock data
+    /// let parser = DeviceTreeParser::new(&dtb_data);
+    ///
+    /// for (base, size) in parser.usable_memory()? {
+    ///     println!("Free: 0x{:x} ({} MiB)", base, size >> 20);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn usable_memory(&self) -> Result<Vec<(u64, u64)>, DtbError> {
+        let root = self.parsed()?;
+
+        // Work with exclusive end addresses, saturating at the top of memory
+        let span = |(base, size): (u64, u64)| (base, base.saturating_add(size));
+
+        let mut free = Vec::new();
+        for memory in root
+            .children
+            .iter()
+            .filter(|n| n.prop_string("device_type") == Some("memory"))
+        {
+            let mut index = 0;
+            while let Some(region) = memory.reg_index(index, Some(root))? {
+                free.push(span(region));
+                index += 1;
+            }
+        }
+
+        let mut reserved: Vec<(u64, u64)> = self
+            .parse_memory_reservations()?
+            .iter()
+            .map(|r| span((r.address, r.size)))
+            .collect();
+        for region in self.reserved_memory()? {
+            if let Some(address) = region.address {
+                reserved.push(span((address, region.size)));
+            }
+        }
+
+        // Zero-size reservations cover nothing and must not split a region
+        for (hole_start, hole_end) in reserved.into_iter().filter(|(start, end)| start != end) {
+            free = free
+                .into_iter()
+                .flat_map(|(start, end)| {
+                    if hole_end <= start || hole_start >= end {
+                        return [Some((start, end)), None];
+                    }
+                    [
+                        (start < hole_start).then_some((start, hole_start)),
+                        (hole_end < end).then_some((hole_end, end)),
+                    ]
+                })
+                .flatten()
+                .collect();
+        }
+        free.sort_unstable();
+        Ok(free
+            .into_iter()
+            .map(|(start, end)| (start, end - start))
+            .collect())
+    }
+
+    /// Finds all interrupt controllers in the tree.
+    ///
+    /// Returns every node with the empty `interrupt-controller` property, in
+    /// depth-first order. Each node carries the `#interrupt-cells` and
+    /// `phandle` needed to build an IRQ routing map; read them with
+    /// [`prop_u32`](DeviceTreeNode::prop_u32) and
+    /// [`phandle`](DeviceTreeNode::phandle).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError`] if parsing fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeParser, DtbError};
+    /// # fn example() -> Result<(), DtbError> {
+    /// # let dtb_data = vec![0u8; 64]; // Mock data
+    /// let parser = DeviceTreeParser::new(&dtb_data);
+    ///
+    /// for controller in parser.interrupt_controllers()? {
+    ///     println!(
+    ///         "{}: phandle {:?}, {:?} cells",
+    ///         controller.name,
+    ///         controller.phandle(),
+    ///         controller.prop_u32("#interrupt-cells")
+    ///     );
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn interrupt_controllers(&self) -> Result<Vec<DeviceTreeNode<'a>>, DtbError> {
+        let root = self.parsed()?;
+        let nodes = root.find_nodes_with_property("interrupt-controller");
+        Ok(nodes.into_iter().cloned().collect())
+    }
+
+    /// Checks the device tree for common structural mistakes.
     ///
-    /// * `path` - Absolute path to the node (e.g., `/cpus/cpu@0`, `/chosen`)
+    /// Runs [`DeviceTreeNode::validate`] on the cached tree. See
+    /// [`ValidationWarning`] for the issues reported.
     ///
     /// # Errors
     ///
     /// Returns [`DtbError`] if parsing fails.
     ///
-    /// # Returns
-    ///
-    /// Returns `Some(node)` if found, `None` if the path doesn't exist.
-    ///
     /// # Examples
     ///
     /// ```rust
@@ -559,44 +2140,87 @@ impl<'a> DeviceTreeParser<'a> {
     /// # let dtb_data = vec![0u8; 64]; // Mock data
     /// let parser = DeviceTreeParser::new(&dtb_data);
     ///
-    /// // Find specific system nodes
-    /// if let Some(chosen) = parser.find_node("/chosen")? {
-    ///     if let Some(bootargs) = chosen.prop_string("bootargs") {
-    ///         println!("Boot arguments: {}", bootargs);
-    ///     }
-    /// }
-    ///
-    /// // Find CPU information
-    /// if let Some(cpu0) = parser.find_node("/cpus/cpu@0")? {
-    ///     if let Some(compatible) = cpu0.prop_string("compatible") {
-    ///         println!("CPU type: {}", compatible);
-    ///     }
+    /// let warnings = parser.validate()?;
+    /// for warning in &warnings {
+    ///     println!("{}", warning);
     /// }
     /// # Ok(())
     /// # }
     /// ```
-    pub fn find_node(&self, path: &str) -> Result<Option<DeviceTreeNode<'a>>, DtbError> {
-        let root = self.parse_tree()?;
-        Ok(root.find_node(path).cloned())
+    pub fn validate(&self) -> Result<Vec<ValidationWarning>, DtbError> {
+        Ok(self.parsed()?.validate())
     }
 
-    /// Finds all device tree nodes with a specific compatible string.
+    /// Applies a device tree overlay (`.dtbo`) to a base tree.
     ///
-    /// The `compatible` property lists the devices that a node is compatible with,
-    /// typically in most-specific to least-specific order. Searches for nodes that
-    /// contain the specified string in their compatible property.
+    /// Each overlay fragment's `__overlay__` node is merged into the base node
+    /// selected by the fragment's `target` phandle or `target-path`. Properties
+    /// in the overlay replace base properties with the same name, and child
+    /// nodes are merged recursively. Labels listed in the overlay's
+    /// `__fixups__` node are resolved through the base tree's `__symbols__`
+    /// node before fragments are applied.
     ///
-    /// # Arguments
+    /// Phandles defined by the overlay are renumbered above the largest
+    /// phandle in `base`, and the references listed in the overlay's
+    /// `__local_fixups__` node are updated to match, so overlay nodes never
+    /// collide with base nodes.
     ///
-    /// * `compatible` - Compatible string to search for (e.g., `"arm,pl011"`)
+    /// The merged tree is an [`OwnedDeviceTree`], so it outlives both inputs
+    /// and frees its storage when dropped. Overlay properties keep their
+    /// [`raw`](super::Property::raw) bytes.
     ///
     /// # Errors
     ///
-    /// Returns [`DtbError`] if parsing fails.
+    /// Returns [`DtbError`] if the overlay fails to parse, or
+    /// [`DtbError::UnresolvedSymbol`], [`DtbError::OverlayTargetNotFound`], or
+    /// [`DtbError::InvalidOverlay`] if it cannot be applied to `base`,
+    /// including when a `__local_fixups__` entry names a missing location or
+    /// a renumbered phandle would overflow.
     ///
-    /// # Returns
+    /// # Examples
     ///
-    /// Returns a vector of matching nodes. An empty vector indicates no matching nodes were found.
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeParser, DtbError};
+    /// # fn example() -> Result<(), DtbError> {
+    /// # let dtb_data = vec![0u8; 64]; // Mock data
+    /// # let dtbo_data = vec![0u8; 64]; // Mock data
+    /// let base = DeviceTreeParser::new(&dtb_data).parse_tree()?;
+    /// let merged = DeviceTreeParser::apply_overlay(&base, &dtbo_data)?;
+    /// println!("Merged tree has {} nodes", merged.iter_nodes().count());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn apply_overlay(
+        base: &DeviceTreeNode<'_>,
+        overlay_bytes: &[u8],
+    ) -> Result<OwnedDeviceTree, DtbError> {
+        // Raw bytes keep phandle cells exact however their values classify
+        let options = ParseOptions {
+            keep_raw: true,
+            ..ParseOptions::unlimited()
+        };
+        let overlay = DeviceTreeParser::new(overlay_bytes).parse_tree_with_options(&options)?;
+        overlay::apply_overlay(base, &overlay)
+    }
+
+    /// Splits a phandle + specifier property into its entries.
+    ///
+    /// Properties such as `clocks`, `gpios`, `dmas`, `resets`, and `pwms` are
+    /// lists of a provider phandle followed by a provider-defined number of
+    /// specifier cells. Each provider is looked up by phandle in the cached
+    /// tree, and its `cells_prop` (e.g., `#clock-cells`) gives the specifier
+    /// length. Returns `(phandle, specifier)` pairs in property order.
+    ///
+    /// Zero phandles are placeholders for unused entries and are skipped. A
+    /// missing property yields an empty list.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError::PhandleNotFound`] if no node has a referenced
+    /// phandle, [`DtbError::InvalidSpecifier`] if a provider lacks
+    /// `cells_prop` or the property ends mid-entry,
+    /// [`DtbError::InvalidPropertyLength`] if the property is not made of
+    /// whole cells, and other [`DtbError`] variants if parsing fails.
     ///
     /// # Examples
     ///
@@ -606,73 +2230,198 @@ impl<'a> DeviceTreeParser<'a> {
     /// # let dtb_data = vec![0u8; 64]; // Mock data
     /// let parser = DeviceTreeParser::new(&dtb_data);
     ///
-    /// // Find all ARM PL011 UART devices
-    /// let uart_nodes = parser.find_compatible_nodes("arm,pl011")?;
-    /// for (i, node) in uart_nodes.iter().enumerate() {
-    ///     println!("UART {}: {}", i, node.name);
-    ///     if let Some(reg) = node.prop_u32_array("reg") {
-    ///         println!("  Base address: 0x{:08x}", reg[0]);
+    /// if let Some(mmc) = parser.find_node_ref("/soc/mmc@10000")? {
+    ///     for (provider, specifier) in parser.parse_specifier_list(mmc, "dmas", "#dma-cells")? {
+    ///         println!("DMA provider {}: {:?}", provider, specifier);
     ///     }
     /// }
-    ///
-    /// // Find all Virtio devices
-    /// let virtio_nodes = parser.find_compatible_nodes("virtio,mmio")?;
-    /// println!("Found {} Virtio devices", virtio_nodes.len());
     /// # Ok(())
     /// # }
     /// ```
-    pub fn find_compatible_nodes(
+    pub fn parse_specifier_list(
         &self,
-        compatible: &str,
-    ) -> Result<Vec<DeviceTreeNode<'a>>, DtbError> {
-        let root = self.parse_tree()?;
-        let nodes = root.find_compatible_nodes(compatible);
-        Ok(nodes.into_iter().cloned().collect())
+        node: &DeviceTreeNode<'_>,
+        prop_name: &str,
+        cells_prop: &str,
+    ) -> Result<Vec<(u32, Vec<u32>)>, DtbError> {
+        let entries = specifier::split_specifiers(self.parsed()?, node, prop_name, cells_prop)?;
+        Ok(entries
+            .into_iter()
+            .map(|(_, phandle, args)| (phandle, args))
+            .collect())
+    }
+}
+
+/// Translate an address from the parent bus of the last node in `chain` up to
+/// the root's address space
+///
+/// `chain[0]` is the root and the node itself is last. Each bus between them
+/// maps its children's addresses into its own parent's address space.
+fn translate_through_chain(chain: &[&DeviceTreeNode<'_>], address: u64) -> Result<u64, DtbError> {
+    let mut address = address;
+    for i in (1..chain.len().saturating_sub(1)).rev() {
+        address = chain[i].translate_to_parent(address, chain[i - 1])?;
     }
+    Ok(address)
+}
 
-    /// Parse the structure block to build the device tree
-    fn parse_structure_block(
-        struct_block: &'a [u8],
-        strings_block: &'a [u8],
-    ) -> Result<DeviceTreeNode<'a>, DtbError> {
-        parse_device_tree_iterative(struct_block, strings_block)
+/// Depth-first search below the last node in `chain` for the deepest `reg`
+/// region containing `phys`, recording `(path, offset, depth)` in `found`
+fn find_device_at<'t, 'a>(
+    chain: &mut Vec<&'t DeviceTreeNode<'a>>,
+    path: &str,
+    phys: u64,
+    found: &mut Option<(String, u64, usize)>,
+) {
+    for_each_region(chain, path, &mut |region_path, _, base, size, depth| {
+        let deeper = found.as_ref().is_none_or(|(_, _, d)| depth > *d);
+        if phys >= base && phys - base < size && deeper {
+            *found = Some((String::from(region_path), phys - base, depth));
+        }
+    });
+}
+
+/// Call `f(path, address, base, size, depth)` for every translatable `reg`
+/// region below the last node in `chain`
+///
+/// `address` is the `reg` address in the parent bus's address space and
+/// `base` its translation to the CPU address space. `depth` is the length of
+/// the ancestor chain including the node itself. Regions whose address cannot
+/// be translated to the CPU address space are skipped.
+fn for_each_region<'t, 'a>(
+    chain: &mut Vec<&'t DeviceTreeNode<'a>>,
+    path: &str,
+    f: &mut impl FnMut(&str, u64, u64, u64, usize),
+) {
+    let parent = chain[chain.len() - 1];
+    for child in &parent.children {
+        let child_path = format!("{path}/{}", child.name);
+        chain.push(child);
+
+        let mut index = 0;
+        while let Ok(Some((address, size))) = child.reg_index(index, Some(parent)) {
+            index += 1;
+            if let Ok(base) = translate_through_chain(chain, address) {
+                f(&child_path, address, base, size, chain.len());
+            }
+        }
+
+        for_each_region(chain, &child_path, f);
+        chain.pop();
+    }
+}
+
+/// Resolve `alias` or `alias/relative/path` through the `/aliases` node
+fn find_aliased_node<'t, 'a>(
+    root: &'t DeviceTreeNode<'a>,
+    path: &str,
+) -> Option<&'t DeviceTreeNode<'a>> {
+    let (alias, rest) = path.split_once('/').unwrap_or((path, ""));
+    let target = root.find_node("/aliases")?.prop_string(alias)?;
+    if !target.starts_with('/') {
+        return None;
     }
+    root.find_node(target)?.find_node(rest)
 }
 
 /// Parse device tree structure using an iterative approach with a stack
+///
+/// Token alignment is checked relative to the start of `struct_block`, so the
+/// block may sit at any memory address.
 fn parse_device_tree_iterative<'a>(
-    mut input: &'a [u8],
+    struct_block: &'a [u8],
+    strings_block: &'a [u8],
+    options: &ParseOptions,
+    schema: &[(&str, PropKind)],
+) -> Result<DeviceTreeNode<'a>, DtbError> {
+    parse_device_tree_repairing(struct_block, strings_block, options, schema, None)
+}
+
+/// Parse the structure block, repairing invalid UTF-8 through `repair` if given
+fn parse_device_tree_repairing<'a>(
+    struct_block: &'a [u8],
     strings_block: &'a [u8],
+    options: &ParseOptions,
+    schema: &[(&str, PropKind)],
+    mut repair: Option<&mut Repair<'_, 'a>>,
 ) -> Result<DeviceTreeNode<'a>, DtbError> {
     use alloc::vec::Vec;
 
     // Stack to keep track of node hierarchy
     let mut node_stack: Vec<DeviceTreeNode<'a>> = Vec::new();
+    let mut node_count = 0usize;
+    let mut input = struct_block;
 
     loop {
-        let (remaining, token) = DtbToken::parse(input)?;
+        let offset = struct_block.len() - input.len();
+        let (remaining, token) = DtbToken::parse_at(struct_block, offset).map_err(|e| match e {
+            DtbError::InvalidToken => DtbError::StructParse {
+                offset,
+                reason: "unknown token",
+            },
+            other => locate_struct_error(other, offset, "truncated token"),
+        })?;
         input = remaining;
 
         match token {
             DtbToken::BeginNode => {
                 // Parse node name
-                let (remaining, name) = parse_node_name(input)?;
+                let name_offset = struct_block.len() - input.len();
+                let (remaining, name) = parse_node_name_with(input, repair.as_deref_mut())
+                    .map_err(|e| rebase_utf8_error(e, name_offset))
+                    .map_err(|e| locate_struct_error(e, name_offset, "malformed node name"))?;
                 input = remaining;
 
+                // Enforce resource limits before growing the tree
+                node_count += 1;
+                if node_count > options.max_nodes {
+                    return Err(DtbError::TooManyNodes);
+                }
+                if node_stack.len() >= options.max_depth {
+                    return Err(DtbError::NestingTooDeep);
+                }
+
                 // Create new node and push to stack
                 let node = DeviceTreeNode::new(name);
                 node_stack.push(node);
             }
             DtbToken::Property => {
+                // Check the declared value length against the limit
+                if input.len() >= 4 {
+                    let prop_len = u32::from_be_bytes([input[0], input[1], input[2], input[3]]);
+                    if prop_len as usize > options.max_property_len {
+                        return Err(DtbError::PropertyTooLarge);
+                    }
+                }
+
                 // Parse property and add to current node
-                let (remaining, property) = parse_property_data(input, strings_block)?;
+                let raw = input;
+                let (remaining, property) = parse_property_data_with(
+                    input,
+                    strings_block,
+                    repair.as_deref_mut(),
+                    options.keep_raw,
+                    schema,
+                )
+                .map_err(|e| locate_struct_error(e, offset, "malformed property"))?;
                 input = remaining;
 
+                if options.validate_cells {
+                    check_cells_property(&node_stack, property.name, raw)?;
+                }
+
                 // Add property to the current (top) node
-                if let Some(current_node) = node_stack.last_mut() {
-                    current_node.add_property(property);
-                } else {
+                let Some(current_node) = node_stack.last_mut() else {
                     return Err(DtbError::InvalidToken);
+                };
+                if current_node.has_property(property.name) {
+                    if options.reject_duplicate_properties {
+                        return Err(DtbError::DuplicateProperty);
+                    }
+                    // Last definition wins
+                    current_node.set_property(property);
+                } else {
+                    current_node.add_property(property);
                 }
             }
             DtbToken::EndNode => {
@@ -687,18 +2436,1200 @@ fn parse_device_tree_iterative<'a>(
                         parent_node.add_child(completed_node);
                     }
                 } else {
-                    return Err(DtbError::InvalidToken);
+                    // More EndNode tokens than BeginNode tokens
+                    return Err(DtbError::UnbalancedNodes);
                 }
             }
+            DtbToken::Nop => {
+                // Placeholder left by in-place edits; carries no data
+            }
             DtbToken::End => {
-                // Should not reach here with a well-formed DTB if we properly handle EndNode
-                if let Some(root_node) = node_stack.pop()
-                    && node_stack.is_empty()
-                {
-                    return Ok(root_node);
+                // A well-formed DTB closes the root node before FDT_END
+                if node_stack.is_empty() {
+                    return Err(DtbError::InvalidToken);
                 }
-                return Err(DtbError::InvalidToken);
+                return Err(DtbError::UnbalancedNodes);
             }
         }
     }
 }
+
+/// Attach a structure block offset to a decoding error.
+///
+/// `InvalidUtf8` already carries its own position and is passed through, as
+/// is `PropertyLengthOverflow`, which names the problem more precisely.
+pub(crate) fn locate_struct_error(
+    error: DtbError,
+    offset: usize,
+    reason: &'static str,
+) -> DtbError {
+    match error {
+        DtbError::InvalidUtf8 { .. } | DtbError::PropertyLengthOverflow(_) => error,
+        _ => DtbError::StructParse { offset, reason },
+    }
+}
+
+/// Validate a `#address-cells` or `#size-cells` property from its raw encoding.
+///
+/// `raw` starts at the property's length field. Raw bytes are checked because
+/// a zero cell count is indistinguishable from an empty property once parsed.
+fn check_cells_property(
+    node_stack: &[DeviceTreeNode<'_>],
+    name: &str,
+    raw: &[u8],
+) -> Result<(), DtbError> {
+    let (property, min, max) = match name {
+        "#address-cells" => ("#address-cells", 1, AddressSpec::MAX_ADDRESS_CELLS),
+        "#size-cells" => ("#size-cells", 0, AddressSpec::MAX_SIZE_CELLS),
+        _ => return Ok(()),
+    };
+
+    let valid = match raw.get(..12) {
+        Some([0, 0, 0, 4, _, _, _, _, a, b, c, d]) => {
+            (min..=max).contains(&u32::from_be_bytes([*a, *b, *c, *d]))
+        }
+        _ => false,
+    };
+    if valid {
+        return Ok(());
+    }
+
+    let mut path = String::new();
+    for node in node_stack.iter().skip(1) {
+        path.push('/');
+        path.push_str(node.name);
+    }
+    if path.is_empty() {
+        path.push('/');
+    }
+    Err(DtbError::InvalidCells { path, property })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dtb::test_support::{StructBuilder, cells};
+
+    const STRINGS: &[u8] = b"reg\0";
+
+    fn nested(depth: usize) -> Vec<u8> {
+        let mut builder = StructBuilder::new();
+        for _ in 0..depth {
+            builder = builder.begin_node("n");
+        }
+        for _ in 0..depth {
+            builder = builder.end_node();
+        }
+        builder.end().build()
+    }
+
+    #[test]
+    fn test_parse_unlimited() {
+        let block = nested(10);
+        let root = parse_device_tree_iterative(&block, STRINGS, &ParseOptions::unlimited(), &[])
+            .expect("nested tree should parse");
+        assert_eq!(root.iter_nodes().count(), 10);
+    }
+
+    #[test]
+    fn test_parse_max_depth() {
+        let options = ParseOptions {
+            max_depth: 8,
+            ..ParseOptions::default()
+        };
+
+        let block = nested(8);
+        assert!(parse_device_tree_iterative(&block, STRINGS, &options, &[]).is_ok());
+
+        let block = nested(9);
+        assert_eq!(
+            parse_device_tree_iterative(&block, STRINGS, &options, &[]).unwrap_err(),
+            DtbError::NestingTooDeep
+        );
+    }
+
+    #[test]
+    fn test_parse_max_nodes() {
+        let options = ParseOptions {
+            max_nodes: 3,
+            ..ParseOptions::default()
+        };
+
+        let block = StructBuilder::new()
+            .begin_node("")
+            .begin_node("a")
+            .end_node()
+            .begin_node("b")
+            .end_node()
+            .begin_node("c")
+            .end_node()
+            .end_node()
+            .end()
+            .build();
+        assert_eq!(
+            parse_device_tree_iterative(&block, STRINGS, &options, &[]).unwrap_err(),
+            DtbError::TooManyNodes
+        );
+    }
+
+    #[test]
+    fn test_u64_iter_parsed() {
+        let dtb = StructBuilder::new()
+            .begin_node("")
+            .named_property(
+                "vendor,windows",
+                &[0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0x10, 0],
+            )
+            .end_node()
+            .end()
+            .build_dtb();
+        let parser = DeviceTreeParser::new(&dtb);
+        let root = parser.parse_tree().unwrap();
+
+        let words: Vec<u64> = root["vendor,windows"].value.u64_iter().collect();
+        assert_eq!(words, [0x1_0000_0000, 0x2_0000_1000]);
+    }
+
+    #[test]
+    fn test_parsed_uses_options() {
+        let dtb = StructBuilder::new()
+            .begin_node("")
+            .begin_node("a")
+            .end_node()
+            .begin_node("b")
+            .end_node()
+            .end_node()
+            .end()
+            .build_dtb();
+        let parser = DeviceTreeParser::new(&dtb);
+        assert_eq!(parser.parsed().unwrap().children.len(), 2);
+
+        // New options start from an empty cache
+        let options = ParseOptions {
+            max_nodes: 2,
+            ..ParseOptions::default()
+        };
+        let parser = parser.with_options(options);
+        assert_eq!(parser.parsed().unwrap_err(), DtbError::TooManyNodes);
+        assert_eq!(parser.find_node("/a").unwrap_err(), DtbError::TooManyNodes);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_parser_is_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<DeviceTreeParser<'_>>();
+    }
+
+    #[test]
+    fn test_parse_validate_cells() {
+        const CELL_STRINGS: &[u8] = b"#address-cells\0#size-cells\0";
+        let tree = |address_cells: u32, size_cells: u32| {
+            StructBuilder::new()
+                .begin_node("")
+                .begin_node("soc")
+                .property(0, &address_cells.to_be_bytes())
+                .property(15, &size_cells.to_be_bytes())
+                .end_node()
+                .end_node()
+                .end()
+                .build()
+        };
+        let strict = ParseOptions {
+            validate_cells: true,
+            ..ParseOptions::default()
+        };
+
+        let block = tree(2, 0);
+        assert!(parse_device_tree_iterative(&block, CELL_STRINGS, &strict, &[]).is_ok());
+
+        // Zero address cells is caught early in strict mode, and otherwise
+        // on lookup
+        let block = tree(0, 1);
+        let root = parse_device_tree_iterative(&block, CELL_STRINGS, &ParseOptions::default(), &[])
+            .unwrap();
+        assert_eq!(
+            root.children[0].address_cells(),
+            Err(DtbError::InvalidAddressCells(0))
+        );
+        assert_eq!(
+            parse_device_tree_iterative(&block, CELL_STRINGS, &strict, &[]).unwrap_err(),
+            DtbError::InvalidCells {
+                path: String::from("/soc"),
+                property: "#address-cells",
+            }
+        );
+
+        let block = tree(1, 5);
+        assert_eq!(
+            parse_device_tree_iterative(&block, CELL_STRINGS, &strict, &[]).unwrap_err(),
+            DtbError::InvalidCells {
+                path: String::from("/soc"),
+                property: "#size-cells",
+            }
+        );
+
+        // Cell counts must be a single cell
+        let block = StructBuilder::new()
+            .begin_node("")
+            .property(0, &[0, 0, 0, 1, 0, 0, 0, 1])
+            .end_node()
+            .end()
+            .build();
+        assert_eq!(
+            parse_device_tree_iterative(&block, CELL_STRINGS, &strict, &[]).unwrap_err(),
+            DtbError::InvalidCells {
+                path: String::from("/"),
+                property: "#address-cells",
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_duplicate_property() {
+        let block = StructBuilder::new()
+            .begin_node("")
+            .property(0, &1u32.to_be_bytes())
+            .property(0, &2u32.to_be_bytes())
+            .end_node()
+            .end()
+            .build();
+
+        // Non-strict parsing keeps the last definition
+        let root = parse_device_tree_iterative(&block, STRINGS, &ParseOptions::default(), &[])
+            .expect("duplicate property should parse");
+        assert_eq!(root.properties.len(), 1);
+        assert_eq!(root.prop_u32("reg"), Some(2));
+
+        let options = ParseOptions {
+            reject_duplicate_properties: true,
+            ..ParseOptions::default()
+        };
+        assert_eq!(
+            parse_device_tree_iterative(&block, STRINGS, &options, &[]).unwrap_err(),
+            DtbError::DuplicateProperty
+        );
+    }
+
+    #[test]
+    fn test_parse_max_property_len() {
+        let options = ParseOptions {
+            max_property_len: 8,
+            ..ParseOptions::default()
+        };
+
+        let block = StructBuilder::new()
+            .begin_node("")
+            .property(0, &[0u8; 8])
+            .end_node()
+            .end()
+            .build();
+        assert!(parse_device_tree_iterative(&block, STRINGS, &options, &[]).is_ok());
+
+        let block = StructBuilder::new()
+            .begin_node("")
+            .property(0, &[0u8; 12])
+            .end_node()
+            .end()
+            .build();
+        assert_eq!(
+            parse_device_tree_iterative(&block, STRINGS, &options, &[]).unwrap_err(),
+            DtbError::PropertyTooLarge
+        );
+    }
+
+    #[test]
+    fn test_parse_missing_end_node() {
+        // Root node is never closed before FDT_END
+        let block = StructBuilder::new()
+            .begin_node("")
+            .begin_node("child")
+            .end_node()
+            .end()
+            .build();
+        assert_eq!(
+            parse_device_tree_iterative(&block, STRINGS, &ParseOptions::unlimited(), &[])
+                .unwrap_err(),
+            DtbError::UnbalancedNodes
+        );
+
+        // Nested child is never closed either
+        let block = StructBuilder::new()
+            .begin_node("")
+            .begin_node("child")
+            .end()
+            .build();
+        assert_eq!(
+            parse_device_tree_iterative(&block, STRINGS, &ParseOptions::unlimited(), &[])
+                .unwrap_err(),
+            DtbError::UnbalancedNodes
+        );
+    }
+
+    #[test]
+    fn test_parse_end_node_underflow() {
+        let block = StructBuilder::new().end_node().end().build();
+        assert_eq!(
+            parse_device_tree_iterative(&block, STRINGS, &ParseOptions::unlimited(), &[])
+                .unwrap_err(),
+            DtbError::UnbalancedNodes
+        );
+    }
+
+    #[test]
+    fn test_parse_no_root_node() {
+        let block = StructBuilder::new().end().build();
+        assert_eq!(
+            parse_device_tree_iterative(&block, STRINGS, &ParseOptions::unlimited(), &[])
+                .unwrap_err(),
+            DtbError::InvalidToken
+        );
+    }
+
+    #[test]
+    fn test_chosen_bootargs_and_initrd() {
+        let dtb = StructBuilder::new()
+            .begin_node("")
+            .begin_node("chosen")
+            .named_property("bootargs", b"console=ttyS0 root=/dev/ram0\0")
+            // 1-cell start and 2-cell end
+            .named_property("linux,initrd-start", &0x8800_0000u32.to_be_bytes())
+            .named_property("linux,initrd-end", &0x1_0000_0000u64.to_be_bytes())
+            .end_node()
+            .end_node()
+            .end()
+            .build_dtb();
+        let parser = DeviceTreeParser::new(&dtb);
+        assert_eq!(
+            parser.bootargs().unwrap(),
+            Some("console=ttyS0 root=/dev/ram0")
+        );
+        assert_eq!(
+            parser.initrd_range().unwrap(),
+            Some((0x8800_0000, 0x1_0000_0000))
+        );
+
+        // No /chosen, or an incomplete initrd description
+        let dtb = StructBuilder::new()
+            .begin_node("")
+            .begin_node("chosen")
+            .named_property("linux,initrd-start", &0x8800_0000u32.to_be_bytes())
+            .end_node()
+            .end_node()
+            .end()
+            .build_dtb();
+        let parser = DeviceTreeParser::new(&dtb);
+        assert_eq!(parser.bootargs().unwrap(), None);
+        assert_eq!(parser.initrd_range().unwrap(), None);
+    }
+
+    #[test]
+    fn test_printable_reg_stays_numeric() {
+        let dtb = StructBuilder::new()
+            .begin_node("")
+            .begin_node("device@34353637")
+            .named_property("reg", b"4567")
+            .named_property("label", b"4567")
+            .end_node()
+            .end_node()
+            .end()
+            .build_dtb();
+        let tree = DeviceTreeParser::new(&dtb).parse_tree().unwrap();
+        let device = &tree.children[0];
+        assert_eq!(
+            device.find_property("reg").unwrap().value,
+            PropertyValue::U32(0x3435_3637)
+        );
+        assert_eq!(
+            device.find_property("label").unwrap().value,
+            PropertyValue::String("4567")
+        );
+    }
+
+    #[test]
+    fn test_schema_overrides_heuristic() {
+        let dtb = StructBuilder::new()
+            .begin_node("")
+            .named_property("serial-number", b"\0")
+            .begin_node("device@34353637")
+            .named_property("label", b"4567")
+            .named_property("compatible", b"vendor,widget\0")
+            .end_node()
+            .end_node()
+            .end()
+            .build_dtb();
+
+        // Without a schema the heuristic guesses
+        let parser = DeviceTreeParser::new(&dtb);
+        let tree = parser.parse_tree().unwrap();
+        let device = &tree.children[0];
+        assert_eq!(
+            tree.find_property("serial-number").unwrap().value,
+            PropertyValue::Empty
+        );
+        assert_eq!(
+            device.find_property("label").unwrap().value,
+            PropertyValue::String("4567")
+        );
+
+        let parser = DeviceTreeParser::new(&dtb).with_schema(&[
+            ("serial-number", PropKind::String),
+            ("label", PropKind::U32),
+            ("compatible", PropKind::StringList),
+        ]);
+        let tree = parser.parse_tree().unwrap();
+        let device = &tree.children[0];
+        assert_eq!(
+            tree.find_property("serial-number").unwrap().value,
+            PropertyValue::String("")
+        );
+        assert_eq!(device.prop_u32("label"), Some(0x3435_3637));
+        assert_eq!(
+            device.find_property("compatible").unwrap().value,
+            PropertyValue::StringList(vec!["vendor,widget"])
+        );
+        // Streaming lookups use the schema too
+        assert_eq!(
+            parser.root_property("serial-number").unwrap(),
+            Some(PropertyValue::String(""))
+        );
+    }
+
+    #[test]
+    fn test_usable_memory() {
+        let dtb = StructBuilder::new()
+            // Overlaps the start of the first bank
+            .reservation(0x3ff0_0000, 0x20_0000)
+            // Inside the first bank, splitting it
+            .reservation(0x4400_0000, 0x10_0000)
+            // Empty, so the second bank stays whole
+            .reservation(0x8040_0000, 0)
+            .begin_node("")
+            .named_property("#address-cells", &cells(&[1]))
+            .named_property("#size-cells", &cells(&[1]))
+            .begin_node("memory@40000000")
+            .named_property("device_type", b"memory\0")
+            .named_property(
+                "reg",
+                &cells(&[0x4000_0000, 0x0800_0000, 0x8000_0000, 0x0100_0000]),
+            )
+            .end_node()
+            .begin_node("reserved-memory")
+            .named_property("#address-cells", &cells(&[1]))
+            .named_property("#size-cells", &cells(&[1]))
+            .named_property("ranges", &[])
+            // Covers the end of the second bank
+            .begin_node("framebuffer@80f00000")
+            .named_property("reg", &cells(&[0x80f0_0000, 0x0020_0000]))
+            .named_property("no-map", &[])
+            .end_node()
+            .begin_node("empty@80800000")
+            .named_property("reg", &cells(&[0x8080_0000, 0]))
+            .end_node()
+            // Dynamically placed, so not subtracted
+            .begin_node("cma")
+            .named_property("size", &cells(&[0x0400_0000]))
+            .named_property("reusable", &[])
+            .end_node()
+            .end_node()
+            .end_node()
+            .end()
+            .build_dtb();
+        let parser = DeviceTreeParser::new(&dtb);
+
+        assert_eq!(
+            parser.usable_memory().unwrap(),
+            [
+                (0x4010_0000, 0x03f0_0000),
+                (0x4410_0000, 0x03f0_0000),
+                (0x8000_0000, 0x00f0_0000),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reserved_memory() {
+        let dtb = StructBuilder::new()
+            .begin_node("")
+            .begin_node("reserved-memory")
+            .named_property("#address-cells", &cells(&[2]))
+            .named_property("#size-cells", &cells(&[2]))
+            .named_property("ranges", &[])
+            .begin_node("secmon@80000000")
+            .named_property("reg", &cells(&[0, 0x8000_0000, 0, 0x20_0000]))
+            .named_property("no-map", &[])
+            .end_node()
+            .begin_node("linux,cma")
+            .named_property("compatible", b"shared-dma-pool\0")
+            .named_property("size", &cells(&[0x1, 0]))
+            .named_property("reusable", &[])
+            .end_node()
+            .end_node()
+            .end_node()
+            .end()
+            .build_dtb();
+        let parser = DeviceTreeParser::new(&dtb);
+
+        assert_eq!(
+            parser.reserved_memory().unwrap(),
+            [
+                ReservedRegion {
+                    name: "secmon@80000000",
+                    address: Some(0x8000_0000),
+                    size: 0x20_0000,
+                    no_map: true,
+                    reusable: false,
+                    compatible: Vec::new(),
+                },
+                ReservedRegion {
+                    name: "linux,cma",
+                    address: None,
+                    size: 0x1_0000_0000,
+                    no_map: false,
+                    reusable: true,
+                    compatible: vec!["shared-dma-pool"],
+                },
+            ]
+        );
+
+        // 0x20000000 and 0x40000000 end in bytes that look like strings
+        let dtb = StructBuilder::new()
+            .begin_node("")
+            .begin_node("reserved-memory")
+            .named_property("#address-cells", &cells(&[2]))
+            .named_property("#size-cells", &cells(&[2]))
+            .named_property("ranges", &[])
+            .begin_node("linux,cma")
+            .named_property("size", &cells(&[0, 0x2000_0000]))
+            .named_property("alignment", &cells(&[0, 0x40_0000]))
+            .end_node()
+            .begin_node("framebuffer")
+            .named_property("size", &cells(&[0, 0x4000_0000]))
+            .end_node()
+            .end_node()
+            .end_node()
+            .end()
+            .build_dtb();
+        let sizes: Vec<u64> = DeviceTreeParser::new(&dtb)
+            .reserved_memory()
+            .unwrap()
+            .iter()
+            .map(|region| region.size)
+            .collect();
+        assert_eq!(sizes, [0x2000_0000, 0x4000_0000]);
+
+        let dtb = StructBuilder::new()
+            .begin_node("")
+            .end_node()
+            .end()
+            .build_dtb();
+        assert!(
+            DeviceTreeParser::new(&dtb)
+                .reserved_memory()
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_interrupt_controllers() {
+        let dtb = StructBuilder::new()
+            .begin_node("")
+            .named_property("interrupt-parent", &1u32.to_be_bytes())
+            .begin_node("intc@8000000")
+            .named_property("compatible", b"arm,cortex-a15-gic\0")
+            .named_property("interrupt-controller", &[])
+            .named_property("#interrupt-cells", &3u32.to_be_bytes())
+            .named_property("phandle", &1u32.to_be_bytes())
+            .begin_node("v2m@8020000")
+            .named_property("msi-controller", &[])
+            .end_node()
+            .end_node()
+            .begin_node("pl011@9000000")
+            .named_property("interrupts", &[0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 4])
+            .end_node()
+            .end_node()
+            .end()
+            .build_dtb();
+        let parser = DeviceTreeParser::new(&dtb);
+
+        let controllers = parser.interrupt_controllers().unwrap();
+        assert_eq!(controllers.len(), 1);
+        assert_eq!(controllers[0].name, "intc@8000000");
+        assert_eq!(controllers[0].prop_u32("#interrupt-cells"), Some(3));
+        assert_eq!(controllers[0].phandle(), Some(1));
+    }
+
+    #[test]
+    fn test_for_each_compatible() {
+        let dtb = StructBuilder::new()
+            .begin_node("")
+            .named_property("compatible", b"linux,dummy-virt\0")
+            .begin_node("virtio_mmio@a000000")
+            .named_property("compatible", b"virtio,mmio\0")
+            .end_node()
+            .begin_node("soc")
+            .begin_node("virtio_mmio@a000200")
+            .named_property("compatible", b"vendor,virtio\0virtio,mmio\0")
+            .end_node()
+            .end_node()
+            .begin_node("pl011@9000000")
+            .named_property("compatible", b"arm,pl011\0arm,primecell\0")
+            .end_node()
+            .end_node()
+            .end()
+            .build_dtb();
+        let parser = DeviceTreeParser::new(&dtb);
+
+        let mut names = Vec::new();
+        parser
+            .for_each_compatible("virtio,mmio", |node| names.push(node.name))
+            .unwrap();
+        assert_eq!(names, ["virtio_mmio@a000000", "virtio_mmio@a000200"]);
+
+        let mut calls = 0;
+        parser
+            .for_each_compatible("arm,sp805", |_| calls += 1)
+            .unwrap();
+        assert_eq!(calls, 0);
+
+        // Parse errors are reported before any callback runs
+        let parser = DeviceTreeParser::new(&dtb[..8]);
+        assert!(
+            parser
+                .for_each_compatible("virtio,mmio", |_| calls += 1)
+                .is_err()
+        );
+        assert_eq!(calls, 0);
+    }
+
+    #[test]
+    fn test_any_node_has_property() {
+        let dtb = StructBuilder::new()
+            .begin_node("")
+            .named_property("#address-cells", &1u32.to_be_bytes())
+            .begin_node("soc")
+            .begin_node("uart@1000")
+            .named_property("interrupts-extended", &[0, 0, 0, 1, 0, 0, 0, 4])
+            .end_node()
+            .end_node()
+            .end_node()
+            .end()
+            .build_dtb();
+        let parser = DeviceTreeParser::new(&dtb);
+        assert!(parser.any_node_has_property("#address-cells").unwrap());
+        assert!(parser.any_node_has_property("interrupts-extended").unwrap());
+        assert!(!parser.any_node_has_property("interrupts").unwrap());
+
+        // The walk stops at the first match, before reaching the truncation
+        let dtb = StructBuilder::new()
+            .begin_node("")
+            .named_property("interrupts-extended", &[0, 0, 0, 1])
+            .build_dtb();
+        let parser = DeviceTreeParser::new(&dtb);
+        assert!(parser.any_node_has_property("interrupts-extended").unwrap());
+        assert!(parser.any_node_has_property("interrupts").is_err());
+    }
+
+    #[test]
+    fn test_reservations_without_terminator() {
+        let mut dtb = StructBuilder::new()
+            .begin_node("")
+            .end_node()
+            .end()
+            .build_dtb();
+        // Overwrite the terminator so the structure block follows an entry directly
+        dtb[40..48].copy_from_slice(&0x1000u64.to_be_bytes());
+        dtb[48..56].copy_from_slice(&0x2000u64.to_be_bytes());
+        let parser = DeviceTreeParser::new(&dtb);
+
+        assert_eq!(
+            parser.parse_memory_reservations().unwrap(),
+            [MemoryReservation {
+                address: 0x1000,
+                size: 0x2000,
+            }]
+        );
+        assert_eq!(
+            parser.parse_memory_reservations_strict().unwrap_err(),
+            DtbError::UnterminatedReservations
+        );
+        assert!(parser.parse_tree().is_ok());
+    }
+
+    #[test]
+    fn test_parse_reports_struct_offset() {
+        let mut block = StructBuilder::new()
+            .begin_node("")
+            .property(0, &[0, 0, 0, 1])
+            .token(DtbToken::Nop)
+            .end_node()
+            .end()
+            .build();
+        // Root node (8 bytes) + property (16 bytes) puts the NOP at offset 24
+        block[24..28].copy_from_slice(&0xdead_beef_u32.to_be_bytes());
+        let err = parse_device_tree_iterative(&block, STRINGS, &ParseOptions::unlimited(), &[])
+            .unwrap_err();
+        assert_eq!(
+            err,
+            DtbError::StructParse {
+                offset: 24,
+                reason: "unknown token",
+            }
+        );
+        assert_eq!(
+            alloc::string::ToString::to_string(&err),
+            "Structure block parse error at offset 24: unknown token"
+        );
+
+        // Property value runs past the end of the block; the error points at
+        // the FDT_PROP token
+        let block = StructBuilder::new()
+            .begin_node("")
+            .token(DtbToken::Property)
+            .build()
+            .into_iter()
+            .chain([0, 0, 1, 0, 0, 0, 0, 0])
+            .collect::<Vec<u8>>();
+        assert_eq!(
+            parse_device_tree_iterative(&block, STRINGS, &ParseOptions::unlimited(), &[])
+                .unwrap_err(),
+            DtbError::StructParse {
+                offset: 8,
+                reason: "malformed property",
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_property_padding_overflow() {
+        // A 5-byte value ends the block, leaving no room for its padding
+        let block = StructBuilder::new()
+            .begin_node("")
+            .token(DtbToken::Property)
+            .build()
+            .into_iter()
+            .chain([0, 0, 0, 5, 0, 0, 0, 0])
+            .chain(*b"okay\0")
+            .collect::<Vec<u8>>();
+        let err = parse_device_tree_iterative(&block, STRINGS, &ParseOptions::unlimited(), &[])
+            .unwrap_err();
+        assert_eq!(err, DtbError::PropertyLengthOverflow(5));
+        assert_eq!(
+            alloc::string::ToString::to_string(&err),
+            "Property length 5 plus padding runs past the end of the structure block"
+        );
+
+        // The cursor reports it the same way rather than panicking
+        let mut cursor = StructCursor::new(&block, STRINGS);
+        assert!(matches!(
+            cursor.next_token(),
+            Ok(Some(StructEvent::BeginNode("")))
+        ));
+        assert_eq!(
+            cursor.next_token().unwrap_err(),
+            DtbError::PropertyLengthOverflow(5)
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_utf8_property_name() {
+        let dtb = StructBuilder::new()
+            .begin_node("")
+            .named_property("model", b"board\0")
+            .named_property_bytes(b"bad\xffname", &1u32.to_be_bytes())
+            .end_node()
+            .end()
+            .build_dtb();
+        let parser = DeviceTreeParser::new(&dtb);
+
+        // "model\0" occupies the first 6 bytes of the strings block
+        let expected = DtbError::InvalidUtf8 { offset: 6 + 3 };
+        assert_eq!(parser.parse_tree().unwrap_err(), expected);
+        assert_eq!(parser.property_names().unwrap_err(), expected);
+    }
+
+    #[test]
+    fn test_parse_invalid_utf8_node_name() {
+        let block = StructBuilder::new()
+            .begin_node("")
+            .begin_node_bytes(b"uart\xc3@1000")
+            .end_node()
+            .end_node()
+            .end()
+            .build();
+
+        // Child name starts after the root's token and padded name (8 bytes)
+        // and the child's own token (4 bytes)
+        assert_eq!(
+            parse_device_tree_iterative(&block, STRINGS, &ParseOptions::default(), &[])
+                .unwrap_err(),
+            DtbError::InvalidUtf8 { offset: 12 + 4 }
+        );
+    }
+
+    #[test]
+    fn test_parse_lossy_strings() {
+        let dtb = StructBuilder::new()
+            .begin_node("")
+            .begin_node_bytes(b"uart\xc3@1000")
+            .named_property_bytes(b"vendor,\xffmode", &1u32.to_be_bytes())
+            .named_property("status", b"okay\0")
+            .named_property("label", b"caf\xe9\0")
+            .named_property("vendor,names", "caf\u{e9}\0port\0".as_bytes())
+            .end_node()
+            .begin_node("uart@2000")
+            .named_property_bytes(b"vendor,\xffmode", &2u32.to_be_bytes())
+            .named_property("mac", &[0x52, 0x54, 0x00, 0x12, 0x34, 0x00])
+            .end_node()
+            .end_node()
+            .end()
+            .build_dtb();
+        let parser = DeviceTreeParser::new(&dtb);
+
+        // Strict parsing rejects the bad node name
+        assert!(matches!(
+            parser.parse_tree_with_options(&ParseOptions::default()),
+            Err(DtbError::InvalidUtf8 { .. })
+        ));
+
+        let mut repaired = RepairedStrings::new();
+        let root = parser
+            .parse_tree_lossy(&ParseOptions::default(), &mut repaired)
+            .expect("lossy parsing should succeed");
+        let uart = &root.children[0];
+        assert_eq!(uart.name, "uart\u{fffd}@1000");
+        assert_eq!(uart.unit_address(), Some(0x1000));
+        assert_eq!(uart.prop_u32("vendor,\u{fffd}mode"), Some(1));
+        assert_eq!(uart.prop_string("status"), Some("okay"));
+        // Non-ASCII text values are strings, repaired where needed
+        assert_eq!(uart.prop_string("label"), Some("caf\u{fffd}"));
+        assert_eq!(
+            uart.prop_string_list("vendor,names"),
+            Some(vec!["caf\u{e9}", "port"])
+        );
+        // Binary values are left alone
+        let other = &root.children[1];
+        assert_eq!(other.prop_u32("vendor,\u{fffd}mode"), Some(2));
+        assert!(matches!(
+            other.find_property("mac").unwrap().value,
+            PropertyValue::Bytes(_)
+        ));
+
+        // The shared property name is stored once
+        drop(root);
+        assert_eq!(repaired.len(), 3);
+
+        // A clean DTB needs no repairs, and the store is reused
+        let clean = StructBuilder::new()
+            .begin_node("")
+            .begin_node("n")
+            .end_node()
+            .end_node()
+            .end()
+            .build_dtb();
+        let parser = DeviceTreeParser::new(&clean);
+        let root = parser
+            .parse_tree_lossy(&ParseOptions::default(), &mut repaired)
+            .unwrap();
+        assert_eq!(root.children[0].name, "n");
+        drop(root);
+        assert!(repaired.is_empty());
+    }
+
+    #[test]
+    fn test_parse_keep_raw() {
+        // A one-cell value that happens to be printable ASCII
+        let dtb = StructBuilder::new()
+            .begin_node("")
+            .named_property("vendor,id", b"4567")
+            .named_property("status", b"okay\0")
+            .end_node()
+            .end()
+            .build_dtb();
+        let parser = DeviceTreeParser::new(&dtb);
+
+        let root = parser.parse_tree().unwrap();
+        assert!(root.properties.iter().all(|p| p.raw.is_none()));
+
+        let options = ParseOptions {
+            keep_raw: true,
+            ..ParseOptions::default()
+        };
+        let root = parser.parse_tree_with_options(&options).unwrap();
+        let id = root.find_property("vendor,id").unwrap();
+        // The heuristic guessed a string, which re-encodes with a terminator
+        assert_eq!(id.value, PropertyValue::String("4567"));
+        assert_eq!(id.value.encode(), b"4567\0");
+        // The raw bytes are the DTB's own
+        let raw = id.raw.unwrap();
+        assert_eq!(raw, b"4567");
+        assert!(dtb.as_ptr_range().contains(&raw.as_ptr()));
+        assert_eq!(
+            root.find_property("status").unwrap().raw,
+            Some(&b"okay\0"[..])
+        );
+    }
+
+    #[test]
+    fn test_struct_and_strings_blocks() {
+        let dtb = StructBuilder::new()
+            .begin_node("")
+            .named_property("model", b"board\0")
+            .end_node()
+            .end()
+            .build_dtb();
+        let parser = DeviceTreeParser::new(&dtb);
+        let header = parser.parse_header().unwrap();
+
+        // Root node (8), model (20), end node (4), end (4)
+        let struct_block = parser.struct_block().unwrap();
+        assert_eq!(struct_block.len(), header.size_dt_struct as usize);
+        assert_eq!(struct_block.len(), 36);
+        assert_eq!(
+            struct_block[..4],
+            DtbToken::BeginNode.to_u32().to_be_bytes()
+        );
+        assert_eq!(struct_block[32..], DtbToken::End.to_u32().to_be_bytes());
+
+        let strings_block = parser.strings_block().unwrap();
+        assert_eq!(strings_block.len(), header.size_dt_strings as usize);
+        assert_eq!(strings_block, b"model\0");
+
+        // A block past the end of the buffer is rejected
+        let truncated = &dtb[..dtb.len() - 1];
+        let parser = DeviceTreeParser::new(truncated);
+        assert_eq!(parser.strings_block(), Err(DtbError::MalformedHeader));
+        assert!(parser.struct_block().is_ok());
+    }
+
+    #[test]
+    fn test_boot_cpu() {
+        let cpu = |builder: StructBuilder, name: &str, reg: u32| {
+            builder
+                .begin_node(name)
+                .named_property("device_type", b"cpu\0")
+                .named_property("reg", &reg.to_be_bytes())
+                .end_node()
+        };
+        let builder = StructBuilder::new()
+            .begin_node("")
+            .begin_node("cpus")
+            .named_property("#address-cells", &1u32.to_be_bytes())
+            .named_property("#size-cells", &0u32.to_be_bytes());
+        let builder = cpu(builder, "cpu@0", 0);
+        let builder = cpu(builder, "cpu@100", 0x100);
+        let builder = cpu(builder, "cpu@101", 0x101);
+        let mut dtb = builder
+            .begin_node("cpu-map")
+            .end_node()
+            .end_node()
+            .end_node()
+            .end()
+            .build_dtb();
+
+        // The builder's header says CPU 0 boots
+        let boot = DeviceTreeParser::new(&dtb).boot_cpu().unwrap().unwrap();
+        assert_eq!(boot.name, "cpu@0");
+
+        // boot_cpuid_phys is the eighth header field
+        dtb[0x1c..0x20].copy_from_slice(&0x101u32.to_be_bytes());
+        let parser = DeviceTreeParser::new(&dtb);
+        assert_eq!(parser.parse_header().unwrap().boot_cpuid_phys, 0x101);
+        let boot = parser.boot_cpu().unwrap().unwrap();
+        assert_eq!(boot.name, "cpu@101");
+        assert_eq!(boot.prop_u32("reg"), Some(0x101));
+
+        dtb[0x1c..0x20].copy_from_slice(&7u32.to_be_bytes());
+        assert!(DeviceTreeParser::new(&dtb).boot_cpu().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_find_node_flexible() {
+        let dtb = StructBuilder::new()
+            .begin_node("")
+            .begin_node("aliases")
+            .named_property("serial0", b"/soc/uart@1000\0")
+            .named_property("ethernet0", b"/soc/ethernet@2000\0")
+            .named_property("broken", b"soc\0")
+            .end_node()
+            .begin_node("soc")
+            .begin_node("uart@1000")
+            .end_node()
+            .begin_node("ethernet@2000")
+            .begin_node("mdio")
+            .end_node()
+            .end_node()
+            .end_node()
+            .end_node()
+            .end()
+            .build_dtb();
+        let parser = DeviceTreeParser::new(&dtb);
+        let find = |path| {
+            parser
+                .find_node_flexible(path)
+                .unwrap()
+                .map(|node| node.name)
+        };
+
+        assert_eq!(find("serial0"), Some("uart@1000"));
+        assert_eq!(find("/soc/uart@1000"), Some("uart@1000"));
+        assert_eq!(find("/soc/uart"), Some("uart@1000"));
+        assert_eq!(find("ethernet0/mdio"), Some("mdio"));
+        assert_eq!(find("ethernet0/phy"), None);
+        assert_eq!(find("serial1"), None);
+        assert_eq!(find("broken"), None);
+        assert_eq!(find("soc"), None);
+    }
+
+    /// Root (2+2 cells) with `/soc` (1+1) containing `bus@8000` (1+1) and
+    /// buses without translation or with an identity mapping
+    fn multi_level_bus_dtb() -> Vec<u8> {
+        let words =
+            |values: &[u32]| -> Vec<u8> { values.iter().flat_map(|v| v.to_be_bytes()).collect() };
+
+        // Root uses 2+2 cells, /soc uses 1+1, /soc/bus@8000 uses 1+1.
+        // /soc maps 0x0-0xffff to 0x1_4000_0000 (child 1 cell, parent 2 cells).
+        // /soc/bus@8000 maps 0x0-0xfff to 0x8000 in /soc's space.
+        StructBuilder::new()
+            .begin_node("")
+            .named_property("#address-cells", &2u32.to_be_bytes())
+            .named_property("#size-cells", &2u32.to_be_bytes())
+            .begin_node("soc")
+            .named_property("#address-cells", &1u32.to_be_bytes())
+            .named_property("#size-cells", &1u32.to_be_bytes())
+            .named_property("ranges", &words(&[0x0, 0x1, 0x4000_0000, 0x1_0000]))
+            .begin_node("bus@8000")
+            .named_property("#address-cells", &1u32.to_be_bytes())
+            .named_property("#size-cells", &1u32.to_be_bytes())
+            .named_property("reg", &words(&[0x8000, 0x1000]))
+            .named_property("ranges", &words(&[0x0, 0x8000, 0x1000]))
+            .begin_node("uart@100")
+            .named_property("reg", &words(&[0x100, 0x100]))
+            .end_node()
+            .end_node()
+            .begin_node("bridge")
+            .begin_node("gpio@0")
+            .end_node()
+            .end_node()
+            .begin_node("flat")
+            .named_property("ranges", &[])
+            .begin_node("timer@200")
+            .end_node()
+            .end_node()
+            .end_node()
+            .end_node()
+            .end()
+            .build_dtb()
+    }
+
+    #[test]
+    fn test_mmio_map() {
+        let dtb = multi_level_bus_dtb();
+        let parser = DeviceTreeParser::new(&dtb);
+
+        assert_eq!(
+            parser.mmio_map().unwrap(),
+            [
+                MmioEntry {
+                    path: String::from("/soc/bus@8000"),
+                    child_address: 0x8000,
+                    physical_address: 0x1_4000_8000,
+                    size: 0x1000,
+                },
+                // Translated through both buses
+                MmioEntry {
+                    path: String::from("/soc/bus@8000/uart@100"),
+                    child_address: 0x100,
+                    physical_address: 0x1_4000_8100,
+                    size: 0x100,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_translate_address_multi_level() {
+        let dtb = multi_level_bus_dtb();
+        let parser = DeviceTreeParser::new(&dtb);
+
+        // Through both buses
+        assert_eq!(
+            parser.translate_address("/soc/bus@8000/uart@100", 0x100),
+            Ok(0x1_4000_8100)
+        );
+        // Through /soc only
+        assert_eq!(
+            parser.translate_address("/soc/bus@8000", 0x8000),
+            Ok(0x1_4000_8000)
+        );
+        // Empty ranges is 1:1
+        assert_eq!(
+            parser.translate_address("/soc/flat/timer@200", 0x200),
+            Ok(0x1_4000_0200)
+        );
+        // Direct children of the root need no translation
+        assert_eq!(parser.translate_address("/soc", 0x1234), Ok(0x1234));
+
+        // Outside the bus window
+        assert_eq!(
+            parser.translate_address("/soc/bus@8000/uart@100", 0x2000),
+            Err(DtbError::AddressTranslationError(0x2000))
+        );
+        // A bus without ranges is not memory-mapped
+        assert_eq!(
+            parser.translate_address("/soc/bridge/gpio@0", 0x0),
+            Err(DtbError::AddressTranslationError(0x0))
+        );
+        assert_eq!(
+            parser.translate_address("/soc/missing", 0x10),
+            Err(DtbError::AddressTranslationError(0x10))
+        );
+    }
+
+    #[test]
+    fn test_device_at_address_prefers_deepest() {
+        let dtb = multi_level_bus_dtb();
+        let parser = DeviceTreeParser::new(&dtb);
+
+        // uart@100 sits inside bus@8000's own reg window
+        assert_eq!(
+            parser.device_at_address(0x1_4000_8104).unwrap(),
+            Some((String::from("/soc/bus@8000/uart@100"), 4))
+        );
+        assert_eq!(
+            parser.device_at_address(0x1_4000_8800).unwrap(),
+            Some((String::from("/soc/bus@8000"), 0x800))
+        );
+        assert_eq!(parser.device_at_address(0x1_4000_9000).unwrap(), None);
+    }
+
+    #[test]
+    fn test_parse_skips_nop_tokens() {
+        let dtb = StructBuilder::new()
+            .token(DtbToken::Nop)
+            .begin_node("")
+            .token(DtbToken::Nop)
+            .named_property("model", b"nop-board\0")
+            .token(DtbToken::Nop)
+            .token(DtbToken::Nop)
+            .begin_node("uart@1000")
+            .token(DtbToken::Nop)
+            .named_property("status", b"okay\0")
+            .end_node()
+            .token(DtbToken::Nop)
+            .end_node()
+            .token(DtbToken::Nop)
+            .end()
+            .build_dtb();
+        let parser = DeviceTreeParser::new(&dtb);
+
+        let root = parser.parse_tree().expect("NOP tokens should be skipped");
+        assert_eq!(root.prop_string("model"), Some("nop-board"));
+        assert_eq!(root.children.len(), 1);
+        assert_eq!(root.children[0].prop_string("status"), Some("okay"));
+
+        // Root property scanning also skips NOPs
+        assert_eq!(parser.model().unwrap(), Some("nop-board"));
+    }
+}
@@ -0,0 +1,163 @@
+// ABOUTME: PCI address specifier decoding for device tree bus nodes
+// ABOUTME: Splits the 3-cell PCI child address into space, location, and flag fields
+
+/// PCI address space encoded in the `ss` bits of a PCI address high cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PciSpace {
+    /// Configuration space (`ss = 00`).
+    Configuration,
+    /// I/O space (`ss = 01`).
+    Io,
+    /// 32-bit memory space (`ss = 10`).
+    Memory32,
+    /// 64-bit memory space (`ss = 11`).
+    Memory64,
+}
+
+/// Decoded 3-cell PCI address specifier.
+///
+/// PCI bus nodes use a 3-cell child address (`phys.hi`, `phys.mid`, `phys.lo`).
+/// The high cell carries the address space, bus/device/function location, and
+/// flag bits, while the two low cells hold the 64-bit address within the space.
+///
+/// # Layout
+///
+/// The high cell is laid out as defined by the PCI bus binding:
+/// ```text
+/// phys.hi  | npt000ss bbbbbbbb dddddfff rrrrrrrr
+/// phys.mid | hhhhhhhh hhhhhhhh hhhhhhhh hhhhhhhh
+/// phys.lo  | llllllll llllllll llllllll llllllll
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// # use device_tree_parser::{PciAddress, PciSpace};
+/// // Prefetchable 64-bit memory at 0x80_0000_0000
+/// let addr = PciAddress::from_cells(0x4300_0000, 0x80, 0x0);
+/// assert_eq!(addr.space, PciSpace::Memory64);
+/// assert!(addr.prefetchable);
+/// assert_eq!(addr.address, 0x80_0000_0000);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PciAddress {
+    /// Address space the address refers to.
+    pub space: PciSpace,
+    /// Bus number (8 bits).
+    pub bus: u8,
+    /// Device number (5 bits).
+    pub device: u8,
+    /// Function number (3 bits).
+    pub function: u8,
+    /// Register number for configuration space addresses (8 bits).
+    pub register: u8,
+    /// Whether the region is prefetchable (`p` bit).
+    pub prefetchable: bool,
+    /// Whether the address is non-relocatable (`n` bit).
+    pub non_relocatable: bool,
+    /// Whether the address is aliased (`t` bit).
+    pub aliased: bool,
+    /// 64-bit address within the space (`phys.mid` and `phys.lo`).
+    pub address: u64,
+}
+
+impl PciAddress {
+    /// Number of cells in a PCI child address.
+    pub const CELLS: u32 = 3;
+
+    /// Decodes a PCI address from its three cells.
+    #[must_use]
+    pub const fn from_cells(hi: u32, mid: u32, lo: u32) -> Self {
+        let space = match (hi >> 24) & 0x3 {
+            0 => PciSpace::Configuration,
+            1 => PciSpace::Io,
+            2 => PciSpace::Memory32,
+            _ => PciSpace::Memory64,
+        };
+
+        Self {
+            space,
+            bus: ((hi >> 16) & 0xff) as u8,
+            device: ((hi >> 11) & 0x1f) as u8,
+            function: ((hi >> 8) & 0x7) as u8,
+            register: (hi & 0xff) as u8,
+            prefetchable: hi & (1 << 30) != 0,
+            non_relocatable: hi & (1 << 31) != 0,
+            aliased: hi & (1 << 29) != 0,
+            address: ((mid as u64) << 32) | lo as u64,
+        }
+    }
+
+    /// Decodes a PCI address from a 96-bit value as returned by
+    /// [`parse_address_from_bytes_full`](crate::parse_address_from_bytes_full).
+    #[must_use]
+    pub const fn from_u128(value: u128) -> Self {
+        Self::from_cells((value >> 64) as u32, (value >> 32) as u32, value as u32)
+    }
+}
+
+/// Single entry from a PCI bus node's `ranges` property.
+///
+/// Maps a window of PCI address space to the parent bus address space.
+///
+/// # Examples
+///
+/// ```rust
+/// # use device_tree_parser::{DeviceTreeNode, DtbError, PciSpace};
+/// # fn example(pci_node: &DeviceTreeNode, root: &DeviceTreeNode) -> Result<(), DtbError> {
+/// for range in pci_node.pci_ranges(Some(root))? {
+///     if range.child.space == PciSpace::Memory32 {
+///         println!("MMIO window: 0x{:x} -> 0x{:x} (size: 0x{:x})",
+///             range.child.address, range.parent_address, range.size);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PciRange {
+    /// PCI child address at the start of the window.
+    pub child: PciAddress,
+    /// Start of the window in the parent address space.
+    pub parent_address: u64,
+    /// Size of the window in bytes.
+    pub size: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pci_address_from_cells() {
+        // I/O space, bus 0, device 0, function 0
+        let io = PciAddress::from_cells(0x0100_0000, 0x0, 0x0);
+        assert_eq!(io.space, PciSpace::Io);
+        assert_eq!(io.address, 0);
+        assert!(!io.prefetchable);
+
+        // Config space for bus 1, device 3, function 2, register 0x10
+        let cfg = PciAddress::from_cells(0x0001_1a10, 0x0, 0x0);
+        assert_eq!(cfg.space, PciSpace::Configuration);
+        assert_eq!(cfg.bus, 1);
+        assert_eq!(cfg.device, 3);
+        assert_eq!(cfg.function, 2);
+        assert_eq!(cfg.register, 0x10);
+
+        // Non-relocatable, prefetchable, aliased 64-bit memory
+        let mem = PciAddress::from_cells(0xe300_0000, 0x80, 0x1000);
+        assert_eq!(mem.space, PciSpace::Memory64);
+        assert!(mem.non_relocatable);
+        assert!(mem.prefetchable);
+        assert!(mem.aliased);
+        assert_eq!(mem.address, 0x80_0000_1000);
+    }
+
+    #[test]
+    fn test_pci_address_from_u128() {
+        let value = 0x0200_0000_0000_0000_1000_0000u128;
+        let addr = PciAddress::from_u128(value);
+        assert_eq!(addr, PciAddress::from_cells(0x0200_0000, 0x0, 0x1000_0000));
+        assert_eq!(addr.space, PciSpace::Memory32);
+    }
+}
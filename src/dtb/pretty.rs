@@ -0,0 +1,314 @@
+// ABOUTME: Configurable text rendering of device tree nodes
+// ABOUTME: Controls indentation, hex byte columns, and eliding long arrays
+
+use super::tree::{DeviceTreeNode, PropertyValue};
+use alloc::string::String;
+use core::fmt::{self, Write};
+
+/// Number of bytes per row when printing byte values as hex columns.
+const HEX_COLUMNS: usize = 16;
+
+/// Options for [`DeviceTreeNode::format_pretty`].
+///
+/// The defaults reproduce the node's `Display` output.
+///
+/// # Examples
+///
+/// ```rust
+/// # use device_tree_parser::{DeviceTreeParser, DtbError, PrintOptions};
+/// # fn example() -> Result<(), DtbError> {
+/// # let dtb_data = vec![0u8; 64]; // Mock data
+/// let parser = DeviceTreeParser::new(&dtb_data);
+/// let tree = parser.parse_tree()?;
+///
+/// let options = PrintOptions {
+///     indent: 4,
+///     hex_bytes: true,
+///     max_array_elements: Some(8),
+/// };
+/// println!("{}", tree.format_pretty(&options));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrintOptions {
+    /// Spaces per nesting level.
+    pub indent: usize,
+    /// Print raw byte values as rows of bare hex pairs (`de ad be ef`),
+    /// sixteen to a row, instead of a comma-separated list.
+    pub hex_bytes: bool,
+    /// Maximum number of elements to print from a string list, cell array,
+    /// or byte value. The rest are replaced by a count. `None` prints
+    /// everything.
+    pub max_array_elements: Option<usize>,
+}
+
+impl Default for PrintOptions {
+    fn default() -> Self {
+        Self {
+            indent: 2,
+            hex_bytes: false,
+            max_array_elements: None,
+        }
+    }
+}
+
+impl DeviceTreeNode<'_> {
+    /// Render the node and its subtree as text.
+    ///
+    /// Uses the same layout as `Display`, adjusted by `opts`. Eliding long
+    /// arrays and fixing the indentation keeps the output of large trees
+    /// readable and diffable.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeNode, PrintOptions};
+    /// let node = DeviceTreeNode::new("soc");
+    /// let text = node.format_pretty(&PrintOptions::default());
+    /// assert_eq!(text, "soc {\n}\n");
+    /// ```
+    #[must_use]
+    pub fn format_pretty(&self, opts: &PrintOptions) -> String {
+        let mut out = String::new();
+        // Writing to a String cannot fail
+        let _ = write_node(&mut out, self, opts, 0);
+        out
+    }
+}
+
+fn write_node(
+    out: &mut String,
+    node: &DeviceTreeNode<'_>,
+    opts: &PrintOptions,
+    depth: usize,
+) -> fmt::Result {
+    let pad = depth * opts.indent;
+    let name = if node.name.is_empty() { "/" } else { node.name };
+    writeln!(out, "{:pad$}{name} {{", "")?;
+
+    let inner = pad + opts.indent;
+    for property in &node.properties {
+        write!(out, "{:inner$}{} = ", "", property.name)?;
+        write_value(out, &property.value, opts, inner + opts.indent)?;
+        out.push('\n');
+    }
+
+    for child in &node.children {
+        write_node(out, child, opts, depth + 1)?;
+    }
+
+    writeln!(out, "{:pad$}}}", "")
+}
+
+fn write_value(
+    out: &mut String,
+    value: &PropertyValue<'_>,
+    opts: &PrintOptions,
+    row_indent: usize,
+) -> fmt::Result {
+    match value {
+        PropertyValue::StringList(list) => {
+            write_list(out, list.iter(), list.len(), opts, |out, s| {
+                write!(out, "\"{s}\"")
+            })
+        }
+        PropertyValue::U32Array(bytes) => {
+            let cells = bytes.chunks_exact(4);
+            let len = cells.len();
+            write_list(out, cells, len, opts, |out, chunk| {
+                let val = u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+                write!(out, "0x{val:x}")
+            })
+        }
+        PropertyValue::U64Array(bytes) => {
+            let cells = bytes.chunks_exact(8);
+            let len = cells.len();
+            write_list(out, cells, len, opts, |out, chunk| {
+                let val = u64::from_be_bytes([
+                    chunk[0], chunk[1], chunk[2], chunk[3], chunk[4], chunk[5], chunk[6], chunk[7],
+                ]);
+                write!(out, "0x{val:x}")
+            })
+        }
+        PropertyValue::Bytes(bytes) if opts.hex_bytes => {
+            write_hex_columns(out, bytes, opts, row_indent)
+        }
+        PropertyValue::Bytes(bytes) => {
+            write_list(out, bytes.iter(), bytes.len(), opts, |out, byte| {
+                write!(out, "0x{byte:02x}")
+            })
+        }
+        _ => write!(out, "{value}"),
+    }
+}
+
+/// Write `[a, b, ...]`, eliding past `max_array_elements`
+fn write_list<T>(
+    out: &mut String,
+    items: impl Iterator<Item = T>,
+    len: usize,
+    opts: &PrintOptions,
+    mut write_item: impl FnMut(&mut String, T) -> fmt::Result,
+) -> fmt::Result {
+    let shown = opts.max_array_elements.unwrap_or(len).min(len);
+    out.push('[');
+    for (i, item) in items.take(shown).enumerate() {
+        if i > 0 {
+            out.push_str(", ");
+        }
+        write_item(out, item)?;
+    }
+    if shown < len {
+        if shown > 0 {
+            out.push_str(", ");
+        }
+        write!(out, "... ({} more)", len - shown)?;
+    }
+    out.push(']');
+    Ok(())
+}
+
+/// Write `[de ad be ef]`, wrapping rows of [`HEX_COLUMNS`] bytes
+fn write_hex_columns(
+    out: &mut String,
+    bytes: &[u8],
+    opts: &PrintOptions,
+    row_indent: usize,
+) -> fmt::Result {
+    let shown = opts
+        .max_array_elements
+        .unwrap_or(bytes.len())
+        .min(bytes.len());
+    out.push('[');
+    for (i, row) in bytes[..shown].chunks(HEX_COLUMNS).enumerate() {
+        if i > 0 {
+            write!(out, "\n{:row_indent$}", "")?;
+        }
+        for (j, byte) in row.iter().enumerate() {
+            if j > 0 {
+                out.push(' ');
+            }
+            write!(out, "{byte:02x}")?;
+        }
+    }
+    if shown < bytes.len() {
+        if shown > 0 {
+            out.push(' ');
+        }
+        write!(out, "... ({} more)", bytes.len() - shown)?;
+    }
+    out.push(']');
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dtb::tree::Property;
+    use alloc::{string::ToString, vec};
+
+    const CELLS: &[u8] = &[0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 3];
+    const MAC: &[u8] = &[0x52, 0x54, 0x00, 0x12, 0x34, 0x56];
+
+    fn sample_tree() -> DeviceTreeNode<'static> {
+        let mut uart = DeviceTreeNode::new("uart@1000");
+        uart.add_property(Property {
+            name: "compatible",
+            value: PropertyValue::StringList(vec!["ns16550a", "uart"]),
+            raw: None,
+        });
+        uart.add_property(Property {
+            name: "reg",
+            value: PropertyValue::U32Array(CELLS),
+            raw: None,
+        });
+        uart.add_property(Property {
+            name: "local-mac-address",
+            value: PropertyValue::Bytes(MAC),
+            raw: None,
+        });
+        let mut root = DeviceTreeNode::new("");
+        root.add_property(Property {
+            name: "#address-cells",
+            value: PropertyValue::U32(1),
+            raw: None,
+        });
+        root.add_child(uart);
+        root
+    }
+
+    #[test]
+    fn test_format_pretty_default_matches_display() {
+        let tree = sample_tree();
+        assert_eq!(
+            tree.format_pretty(&PrintOptions::default()),
+            tree.to_string()
+        );
+    }
+
+    #[test]
+    fn test_format_pretty_indent_and_hex() {
+        let options = PrintOptions {
+            indent: 4,
+            hex_bytes: true,
+            max_array_elements: None,
+        };
+        assert_eq!(
+            sample_tree().format_pretty(&options),
+            "/ {\n\
+             \x20   #address-cells = 0x1\n\
+             \x20   uart@1000 {\n\
+             \x20       compatible = [\"ns16550a\", \"uart\"]\n\
+             \x20       reg = [0x1, 0x2, 0x3]\n\
+             \x20       local-mac-address = [52 54 00 12 34 56]\n\
+             \x20   }\n\
+             }\n"
+        );
+    }
+
+    #[test]
+    fn test_format_pretty_elides_arrays() {
+        let options = PrintOptions {
+            indent: 1,
+            hex_bytes: false,
+            max_array_elements: Some(1),
+        };
+        let text = sample_tree().format_pretty(&options);
+        assert!(text.contains("  compatible = [\"ns16550a\", ... (1 more)]\n"));
+        assert!(text.contains("  reg = [0x1, ... (2 more)]\n"));
+        assert!(text.contains("  local-mac-address = [0x52, ... (5 more)]\n"));
+        // Single values are never elided
+        assert!(text.contains(" #address-cells = 0x1\n"));
+
+        let options = PrintOptions {
+            max_array_elements: Some(0),
+            ..options
+        };
+        let text = sample_tree().format_pretty(&options);
+        assert!(text.contains("  reg = [... (3 more)]\n"));
+    }
+
+    #[test]
+    fn test_format_pretty_hex_rows() {
+        let bytes: [u8; 20] = core::array::from_fn(|i| i as u8);
+        let mut node = DeviceTreeNode::new("blob");
+        node.add_property(Property {
+            name: "data",
+            value: PropertyValue::Bytes(&bytes),
+            raw: None,
+        });
+        let options = PrintOptions {
+            hex_bytes: true,
+            max_array_elements: Some(18),
+            ..PrintOptions::default()
+        };
+        assert_eq!(
+            node.format_pretty(&options),
+            "blob {\n\
+             \x20 data = [00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f\n\
+             \x20   10 11 ... (2 more)]\n\
+             }\n"
+        );
+    }
+}
@@ -0,0 +1,91 @@
+// ABOUTME: Storage for strings repaired from invalid UTF-8 during lossy parsing
+// ABOUTME: Lets a lossily parsed tree borrow its replacement text instead of leaking it
+
+use alloc::{collections::BTreeMap, string::String};
+
+/// Text standing in for a repaired string until its stored copy exists.
+const REPLACEMENT: &str = "\u{fffd}";
+
+/// Storage for strings repaired by
+/// [`DeviceTreeParser::parse_tree_lossy`](super::DeviceTreeParser::parse_tree_lossy).
+///
+/// Repaired strings cannot borrow from the DTB, so they are kept here and
+/// the parsed tree borrows them. Dropping the store (after the tree) frees
+/// them. Each distinct invalid string is stored once, however many times it
+/// is referenced, so the store never outgrows the DTB.
+///
+/// # Examples
+///
+/// ```rust
+/// # use device_tree_parser::{DeviceTreeParser, DtbError, ParseOptions, RepairedStrings};
+/// # fn example() -> Result<(), DtbError> {
+/// # let dtb_data = vec![0u8; 64]; // Mock data
+/// let parser = DeviceTreeParser::new(&dtb_data);
+///
+/// let mut repaired = RepairedStrings::new();
+/// let tree = parser.parse_tree_lossy(&ParseOptions::default(), &mut repaired)?;
+/// println!("Root node has {} children", tree.children.len());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct RepairedStrings {
+    /// Repaired text keyed by the address and length of the source bytes
+    strings: BTreeMap<(usize, usize), String>,
+}
+
+impl RepairedStrings {
+    /// Create an empty store
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of distinct strings that were repaired
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Check whether no strings were repaired
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+
+    /// Forget all repaired strings
+    pub(crate) fn clear(&mut self) {
+        self.strings.clear();
+    }
+}
+
+/// How invalid UTF-8 is repaired while parsing.
+///
+/// Lossy parsing runs in two passes over the same DTB: the first collects
+/// repaired copies, the second borrows them.
+pub(crate) enum Repair<'r, 's> {
+    /// Store the repaired text, returning a placeholder for now
+    Collect(&'r mut RepairedStrings),
+    /// Borrow the text stored by an earlier `Collect` pass
+    Lookup(&'s RepairedStrings),
+}
+
+impl<'s> Repair<'_, 's> {
+    /// Replacement text for `bytes`, which are not valid UTF-8
+    pub(crate) fn repair(&mut self, bytes: &[u8]) -> &'s str {
+        let key = (bytes.as_ptr().addr(), bytes.len());
+        match self {
+            Repair::Collect(store) => {
+                store
+                    .strings
+                    .entry(key)
+                    .or_insert_with(|| String::from_utf8_lossy(bytes).into_owned());
+                REPLACEMENT
+            }
+            Repair::Lookup(store) => {
+                let store: &'s RepairedStrings = store;
+                store.strings.get(&key).map_or(REPLACEMENT, String::as_str)
+            }
+        }
+    }
+}
@@ -0,0 +1,232 @@
+// ABOUTME: User-supplied property schemas that pin how named properties are decoded
+// ABOUTME: Overrides the byte-pattern heuristic for properties whose type is known up front
+
+use super::tree::{PropertyValue, parse_property_value};
+use alloc::vec::Vec;
+
+/// Expected encoding of a property, used by
+/// [`DeviceTreeParser::with_schema`](super::DeviceTreeParser::with_schema).
+///
+/// Without a schema, property values are classified by looking at their
+/// bytes, which can misread printable numbers as strings or short strings as
+/// numbers. A schema entry names the encoding instead. If a value does not
+/// fit its declared kind (wrong length, missing null terminator, invalid
+/// UTF-8), it falls back to the heuristic.
+///
+/// # Examples
+///
+/// ```rust
+/// # use device_tree_parser::{DeviceTreeParser, DtbError, PropKind};
+/// # fn example() -> Result<(), DtbError> {
+/// # let dtb_data = vec![0u8; 64]; // Mock data
+/// let parser = DeviceTreeParser::new(&dtb_data).with_schema(&[
+///     ("reg", PropKind::RegArray),
+///     ("compatible", PropKind::StringList),
+/// ]);
+/// let tree = parser.parse_tree()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PropKind {
+    /// No value, as in boolean properties like `dma-coherent`.
+    Empty,
+    /// A single 32-bit cell.
+    U32,
+    /// A single 64-bit value (two cells).
+    U64,
+    /// Any number of 32-bit cells. A single cell decodes as
+    /// [`PropertyValue::U32`], an empty value as [`PropertyValue::Empty`].
+    U32Array,
+    /// Address/size tuples such as `reg`; decoded like [`PropKind::U32Array`].
+    RegArray,
+    /// A single null-terminated string.
+    String,
+    /// One or more null-terminated strings, always decoded as
+    /// [`PropertyValue::StringList`].
+    StringList,
+    /// Opaque bytes.
+    Bytes,
+}
+
+/// Standard properties from the Devicetree Specification and their kinds.
+///
+/// Used by
+/// [`DeviceTreeParser::with_standard_schema`](super::DeviceTreeParser::with_standard_schema).
+/// Covers the generic node properties, interrupt and `/chosen` properties,
+/// and the common `*-names` lists. Properties whose size varies, such as
+/// `clock-frequency` (one or two cells), are left to the heuristic.
+pub const STANDARD_SCHEMA: &[(&str, PropKind)] = &[
+    ("compatible", PropKind::StringList),
+    ("model", PropKind::String),
+    ("phandle", PropKind::U32),
+    ("linux,phandle", PropKind::U32),
+    ("status", PropKind::String),
+    ("#address-cells", PropKind::U32),
+    ("#size-cells", PropKind::U32),
+    ("reg", PropKind::RegArray),
+    ("virtual-reg", PropKind::U32),
+    ("ranges", PropKind::RegArray),
+    ("dma-ranges", PropKind::RegArray),
+    ("dma-coherent", PropKind::Empty),
+    ("dma-noncoherent", PropKind::Empty),
+    ("name", PropKind::String),
+    ("device_type", PropKind::String),
+    ("interrupts", PropKind::U32Array),
+    ("interrupt-parent", PropKind::U32),
+    ("interrupts-extended", PropKind::U32Array),
+    ("#interrupt-cells", PropKind::U32),
+    ("interrupt-controller", PropKind::Empty),
+    ("interrupt-map", PropKind::U32Array),
+    ("interrupt-map-mask", PropKind::U32Array),
+    ("bootargs", PropKind::String),
+    ("stdout-path", PropKind::String),
+    ("stdin-path", PropKind::String),
+    ("reg-names", PropKind::StringList),
+    ("clock-names", PropKind::StringList),
+    ("interrupt-names", PropKind::StringList),
+];
+
+impl PropKind {
+    /// Decode `data` as this kind, or `None` if it doesn't fit.
+    pub(crate) fn decode(self, data: &[u8]) -> Option<PropertyValue<'_>> {
+        match self {
+            PropKind::Empty => data.is_empty().then_some(PropertyValue::Empty),
+            PropKind::U32 => {
+                let cell: [u8; 4] = data.try_into().ok()?;
+                Some(PropertyValue::U32(u32::from_be_bytes(cell)))
+            }
+            PropKind::U64 => {
+                let cells: [u8; 8] = data.try_into().ok()?;
+                Some(PropertyValue::U64(u64::from_be_bytes(cells)))
+            }
+            PropKind::U32Array | PropKind::RegArray => match data.len() {
+                0 => Some(PropertyValue::Empty),
+                4 => PropKind::U32.decode(data),
+                len if len.is_multiple_of(4) => Some(PropertyValue::U32Array(data)),
+                _ => None,
+            },
+            PropKind::String => match decode_strings(data)?.as_slice() {
+                [s] => Some(PropertyValue::String(s)),
+                _ => None,
+            },
+            PropKind::StringList => decode_strings(data).map(PropertyValue::StringList),
+            PropKind::Bytes => Some(PropertyValue::Bytes(data)),
+        }
+    }
+}
+
+/// Split null-terminated UTF-8 strings, keeping empty entries
+fn decode_strings(data: &[u8]) -> Option<Vec<&str>> {
+    let body = data.strip_suffix(&[0])?;
+    body.split(|&b| b == 0)
+        .map(|s| core::str::from_utf8(s).ok())
+        .collect()
+}
+
+/// Decode a property value, consulting `schema` before the heuristic
+pub(crate) fn decode_property<'a>(
+    schema: &[(&str, PropKind)],
+    name: &str,
+    data: &'a [u8],
+) -> PropertyValue<'a> {
+    schema
+        .iter()
+        .find(|(entry, _)| *entry == name)
+        .and_then(|(_, kind)| kind.decode(data))
+        .unwrap_or_else(|| parse_property_value(name, data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_decode_numeric_kinds() {
+        // "4567" would be a string to the heuristic
+        assert_eq!(
+            PropKind::U32.decode(b"4567"),
+            Some(PropertyValue::U32(0x3435_3637))
+        );
+        assert_eq!(
+            PropKind::U64.decode(b"ABCDEFGH"),
+            Some(PropertyValue::U64(0x4142_4344_4546_4748))
+        );
+        assert_eq!(
+            PropKind::RegArray.decode(b"ABCDEFGH"),
+            Some(PropertyValue::U32Array(b"ABCDEFGH"))
+        );
+        assert_eq!(PropKind::U32Array.decode(&[]), Some(PropertyValue::Empty));
+        assert_eq!(PropKind::U32.decode(&[0, 0, 1]), None);
+        assert_eq!(PropKind::RegArray.decode(&[0; 6]), None);
+    }
+
+    #[test]
+    fn test_decode_string_kinds() {
+        // A lone terminator would be an empty value to the heuristic
+        assert_eq!(
+            PropKind::String.decode(b"\0"),
+            Some(PropertyValue::String(""))
+        );
+        assert_eq!(
+            PropKind::StringList.decode(b"ns16550a\0"),
+            Some(PropertyValue::StringList(vec!["ns16550a"]))
+        );
+        assert_eq!(
+            PropKind::StringList.decode(b"a\0\0b\0"),
+            Some(PropertyValue::StringList(vec!["a", "", "b"]))
+        );
+        assert_eq!(PropKind::String.decode(b"a\0b\0"), None);
+        assert_eq!(PropKind::String.decode(b"okay"), None);
+        assert_eq!(PropKind::String.decode(&[0xff, 0]), None);
+    }
+
+    #[test]
+    fn test_standard_schema_entries() {
+        for (i, &(name, kind)) in STANDARD_SCHEMA.iter().enumerate() {
+            assert!(
+                STANDARD_SCHEMA[i + 1..]
+                    .iter()
+                    .all(|(other, _)| *other != name),
+                "{name} listed twice"
+            );
+            // Printable data that the heuristic would read as a string
+            let (data, expected): (&[u8], PropertyValue<'_>) = match kind {
+                PropKind::Empty => (b"", PropertyValue::Empty),
+                PropKind::U32 => (b"4567", PropertyValue::U32(0x3435_3637)),
+                PropKind::U64 => (b"ABCDEFGH", PropertyValue::U64(0x4142_4344_4546_4748)),
+                PropKind::U32Array | PropKind::RegArray => {
+                    (b"ABCDEFGH", PropertyValue::U32Array(b"ABCDEFGH"))
+                }
+                PropKind::String => (b"okay\0", PropertyValue::String("okay")),
+                PropKind::StringList => (b"okay\0", PropertyValue::StringList(vec!["okay"])),
+                PropKind::Bytes => (b"4567", PropertyValue::Bytes(b"4567")),
+            };
+            assert_eq!(
+                decode_property(STANDARD_SCHEMA, name, data),
+                expected,
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_decode_property_fallback() {
+        let schema = [("reg", PropKind::RegArray), ("status", PropKind::String)];
+        assert_eq!(
+            decode_property(&schema, "status", b"okay\0"),
+            PropertyValue::String("okay")
+        );
+        // Values that don't fit their kind use the heuristic
+        assert_eq!(
+            decode_property(&schema, "reg", &[1, 2, 3]),
+            PropertyValue::Bytes(&[1, 2, 3])
+        );
+        // Properties missing from the schema use the heuristic
+        assert_eq!(
+            decode_property(&schema, "label", b"4567"),
+            PropertyValue::String("4567")
+        );
+    }
+}
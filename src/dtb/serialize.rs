@@ -0,0 +1,177 @@
+// ABOUTME: Optional serde serialization for parsed device tree nodes and properties
+// ABOUTME: Emits property values in a tagged form so their type survives the round trip
+
+use super::tree::{DeviceTreeNode, Property, PropertyValue};
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+/// Serializes as `{ "name": ..., "properties": [...], "children": [...] }`.
+impl Serialize for DeviceTreeNode<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("DeviceTreeNode", 3)?;
+        state.serialize_field("name", self.name)?;
+        state.serialize_field("properties", &self.properties)?;
+        state.serialize_field("children", &self.children)?;
+        state.end()
+    }
+}
+
+/// Serializes as `{ "name": ..., "value": { "type": ..., "value": ... } }`.
+impl Serialize for Property<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Property", 2)?;
+        state.serialize_field("name", self.name)?;
+        state.serialize_field("value", &self.value)?;
+        state.end()
+    }
+}
+
+/// Serializes as `{ "type": ..., "value": ... }`.
+///
+/// The `type` tag is one of `empty`, `string`, `stringlist`, `u32`,
+/// `u32array`, `u64`, `u64array`, or `bytes`. Arrays are emitted as decoded
+/// cell values rather than raw bytes, and `empty` has no `value` field.
+impl Serialize for PropertyValue<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let fields = if matches!(self, PropertyValue::Empty) {
+            1
+        } else {
+            2
+        };
+        let mut state = serializer.serialize_struct("PropertyValue", fields)?;
+        match self {
+            PropertyValue::Empty => {
+                state.serialize_field("type", "empty")?;
+            }
+            PropertyValue::String(s) => {
+                state.serialize_field("type", "string")?;
+                state.serialize_field("value", s)?;
+            }
+            PropertyValue::StringList(list) => {
+                state.serialize_field("type", "stringlist")?;
+                state.serialize_field("value", list)?;
+            }
+            PropertyValue::U32(v) => {
+                state.serialize_field("type", "u32")?;
+                state.serialize_field("value", v)?;
+            }
+            PropertyValue::U32Array(_) => {
+                state.serialize_field("type", "u32array")?;
+                state.serialize_field("value", &U32Cells(self))?;
+            }
+            PropertyValue::U64(v) => {
+                state.serialize_field("type", "u64")?;
+                state.serialize_field("value", v)?;
+            }
+            PropertyValue::U64Array(_) => {
+                state.serialize_field("type", "u64array")?;
+                state.serialize_field("value", &U64Cells(self))?;
+            }
+            PropertyValue::Bytes(bytes) => {
+                state.serialize_field("type", "bytes")?;
+                state.serialize_field("value", bytes)?;
+            }
+        }
+        state.end()
+    }
+}
+
+/// Array value serialized as a sequence of 32-bit cells
+struct U32Cells<'v, 'a>(&'v PropertyValue<'a>);
+
+impl Serialize for U32Cells<'_, '_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.u32_iter())
+    }
+}
+
+/// Array value serialized as a sequence of 64-bit cells
+struct U64Cells<'v, 'a>(&'v PropertyValue<'a>);
+
+impl Serialize for U64Cells<'_, '_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.u64_iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_serialize_tree_to_json() {
+        let reg = [0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x01, 0x00];
+        let mut uart = DeviceTreeNode::new("uart@1000");
+        uart.add_property(Property {
+            name: "compatible",
+            value: PropertyValue::StringList(vec!["ns16550a", "ns16550"]),
+            raw: None,
+        });
+        uart.add_property(Property {
+            name: "reg",
+            value: PropertyValue::U32Array(&reg),
+            raw: None,
+        });
+        uart.add_property(Property {
+            name: "dma-coherent",
+            value: PropertyValue::Empty,
+            raw: None,
+        });
+
+        let mut root = DeviceTreeNode::new("");
+        root.add_property(Property {
+            name: "#address-cells",
+            value: PropertyValue::U32(1),
+            raw: None,
+        });
+        root.add_child(uart);
+
+        let json = serde_json::to_value(&root).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "name": "",
+                "properties": [
+                    { "name": "#address-cells", "value": { "type": "u32", "value": 1 } }
+                ],
+                "children": [{
+                    "name": "uart@1000",
+                    "properties": [
+                        {
+                            "name": "compatible",
+                            "value": { "type": "stringlist", "value": ["ns16550a", "ns16550"] }
+                        },
+                        {
+                            "name": "reg",
+                            "value": { "type": "u32array", "value": [0x1000, 0x100] }
+                        },
+                        { "name": "dma-coherent", "value": { "type": "empty" } }
+                    ],
+                    "children": []
+                }]
+            })
+        );
+    }
+
+    #[test]
+    fn test_serialize_tagged_values() {
+        let bytes = [0xde, 0xad, 0xbe];
+        let cases = [
+            (
+                PropertyValue::String("okay"),
+                r#"{"type":"string","value":"okay"}"#,
+            ),
+            (
+                PropertyValue::U64(0x1_0000_0000),
+                r#"{"type":"u64","value":4294967296}"#,
+            ),
+            (
+                PropertyValue::Bytes(&bytes),
+                r#"{"type":"bytes","value":[222,173,190]}"#,
+            ),
+        ];
+        for (value, expected) in cases {
+            assert_eq!(serde_json::to_string(&value).unwrap(), expected);
+        }
+    }
+}
@@ -0,0 +1,293 @@
+// ABOUTME: Generic phandle + specifier list decoding driven by provider #xxx-cells
+// ABOUTME: Backs the clocks, gpios, resets, dmas, and pwms accessors on DeviceTreeNode
+
+use super::error::DtbError;
+use super::parser::DeviceTreeParser;
+use super::tree::DeviceTreeNode;
+use alloc::vec::Vec;
+
+/// Entry of a phandle + specifier property paired with its `*-names` entry.
+///
+/// Returned by [`DeviceTreeNode::resets`], [`DeviceTreeNode::dmas`], and
+/// [`DeviceTreeNode::pwms`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use device_tree_parser::{DeviceTreeNode, DeviceTreeParser, DtbError};
+/// # fn example(parser: &DeviceTreeParser, mmc: &DeviceTreeNode) -> Result<(), DtbError> {
+/// for dma in mmc.dmas(parser)? {
+///     println!("{:?}: provider {} {:?}", dma.name, dma.phandle, dma.args);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamedSpecifier<'a> {
+    /// Matching entry of the `*-names` property (e.g., `"rx"` in `dma-names`),
+    /// if present.
+    pub name: Option<&'a str>,
+    /// Phandle of the provider node.
+    pub phandle: u32,
+    /// Specifier cells following the phandle.
+    pub args: Vec<u32>,
+}
+
+/// Split a phandle + specifier property into `(position, phandle, specifier)`
+/// entries
+///
+/// Providers are looked up by phandle below `root`, and each provider's
+/// `cells_prop` gives the number of specifier cells after its phandle. Zero
+/// phandles are placeholders that occupy a single cell and are skipped.
+/// Positions count placeholder entries too, so they line up with the
+/// property's `*-names` list.
+pub(crate) fn split_specifiers(
+    root: &DeviceTreeNode<'_>,
+    node: &DeviceTreeNode<'_>,
+    prop_name: &str,
+    cells_prop: &str,
+) -> Result<Vec<(usize, u32, Vec<u32>)>, DtbError> {
+    let Some(property) = node.find_property(prop_name) else {
+        return Ok(Vec::new());
+    };
+    let cells = property.value.as_u32_array_checked()?;
+
+    let mut entries = Vec::new();
+    let mut rest = cells.as_slice();
+    let mut next_index = 0;
+    while let Some((&phandle, tail)) = rest.split_first() {
+        rest = tail;
+        let index = next_index;
+        next_index += 1;
+        if phandle == 0 {
+            continue;
+        }
+
+        let provider = root
+            .find_node_by_phandle(phandle)
+            .ok_or(DtbError::PhandleNotFound(phandle))?;
+        let count = provider
+            .prop_u32(cells_prop)
+            .ok_or(DtbError::InvalidSpecifier)? as usize;
+        if rest.len() < count {
+            return Err(DtbError::InvalidSpecifier);
+        }
+        let (args, tail) = rest.split_at(count);
+        rest = tail;
+
+        entries.push((index, phandle, args.to_vec()));
+    }
+    Ok(entries)
+}
+
+impl<'a> DeviceTreeNode<'a> {
+    /// Decode the `clocks` property into `(provider phandle, specifier)` pairs.
+    ///
+    /// Each provider's `#clock-cells` sizes its specifier; a provider with
+    /// `#clock-cells = <0>` yields an empty specifier. Returns an empty list if
+    /// the node has no `clocks` property.
+    ///
+    /// # Errors
+    ///
+    /// See [`DeviceTreeParser::parse_specifier_list`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeNode, DeviceTreeParser, DtbError};
+    /// # fn example(parser: &DeviceTreeParser, uart: &DeviceTreeNode) -> Result<(), DtbError> {
+    /// for (provider, specifier) in uart.clocks(parser)? {
+    ///     println!("Clock from phandle {}: {:?}", provider, specifier);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn clocks(&self, parser: &DeviceTreeParser<'_>) -> Result<Vec<(u32, Vec<u32>)>, DtbError> {
+        parser.parse_specifier_list(self, "clocks", "#clock-cells")
+    }
+
+    /// Decode the `resets` property, naming entries from `reset-names`.
+    ///
+    /// Each reset controller's `#reset-cells` sizes its specifier. Returns an
+    /// empty list if the node has no `resets` property.
+    ///
+    /// # Errors
+    ///
+    /// See [`DeviceTreeParser::parse_specifier_list`].
+    pub fn resets(
+        &self,
+        parser: &DeviceTreeParser<'_>,
+    ) -> Result<Vec<NamedSpecifier<'a>>, DtbError> {
+        self.named_specifiers(parser, "resets", "#reset-cells", "reset-names")
+    }
+
+    /// Decode the `dmas` property, naming entries from `dma-names`.
+    ///
+    /// Each DMA controller's `#dma-cells` sizes its specifier. Returns an
+    /// empty list if the node has no `dmas` property.
+    ///
+    /// # Errors
+    ///
+    /// See [`DeviceTreeParser::parse_specifier_list`].
+    pub fn dmas(&self, parser: &DeviceTreeParser<'_>) -> Result<Vec<NamedSpecifier<'a>>, DtbError> {
+        self.named_specifiers(parser, "dmas", "#dma-cells", "dma-names")
+    }
+
+    /// Decode the `pwms` property, naming entries from `pwm-names`.
+    ///
+    /// Each PWM controller's `#pwm-cells` sizes its specifier. Returns an
+    /// empty list if the node has no `pwms` property.
+    ///
+    /// # Errors
+    ///
+    /// See [`DeviceTreeParser::parse_specifier_list`].
+    pub fn pwms(&self, parser: &DeviceTreeParser<'_>) -> Result<Vec<NamedSpecifier<'a>>, DtbError> {
+        self.named_specifiers(parser, "pwms", "#pwm-cells", "pwm-names")
+    }
+
+    /// Decode a specifier list and pair each entry with its `names_prop` entry
+    fn named_specifiers(
+        &self,
+        parser: &DeviceTreeParser<'_>,
+        prop_name: &str,
+        cells_prop: &str,
+        names_prop: &str,
+    ) -> Result<Vec<NamedSpecifier<'a>>, DtbError> {
+        let entries = split_specifiers(parser.parsed()?, self, prop_name, cells_prop)?;
+        Ok(entries
+            .into_iter()
+            .map(|(index, phandle, args)| NamedSpecifier {
+                name: self.prop_string_index(names_prop, index),
+                phandle,
+                args,
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dtb::test_support::{StructBuilder, cells};
+    use alloc::vec;
+
+    fn provider(
+        builder: StructBuilder,
+        name: &str,
+        cells_prop: &str,
+        count: u32,
+        phandle: u32,
+    ) -> StructBuilder {
+        builder
+            .begin_node(name)
+            .named_property(cells_prop, &cells(&[count]))
+            .named_property("phandle", &cells(&[phandle]))
+            .end_node()
+    }
+
+    fn mixed_dtb() -> Vec<u8> {
+        let builder = StructBuilder::new().begin_node("");
+        let builder = provider(builder, "osc", "#clock-cells", 0, 1);
+        let builder = provider(builder, "clock-controller@1000", "#clock-cells", 1, 2);
+        let builder = provider(builder, "pll@2000", "#clock-cells", 2, 3);
+        let builder = provider(builder, "reset@3000", "#reset-cells", 1, 4);
+        builder
+            .begin_node("uart@4000")
+            .named_property("clocks", &cells(&[2, 7, 1, 3, 0, 4, 0, 2, 8]))
+            .named_property("resets", &cells(&[4, 3]))
+            .end_node()
+            .begin_node("broken@5000")
+            .named_property("clocks", &cells(&[4, 1]))
+            .end_node()
+            .end_node()
+            .end()
+            .build_dtb()
+    }
+
+    #[test]
+    fn test_parse_specifier_list_mixed_providers() {
+        let data = mixed_dtb();
+        let parser = DeviceTreeParser::new(&data);
+        let uart = parser.find_node_ref("/uart@4000").unwrap().unwrap();
+
+        assert_eq!(
+            uart.clocks(&parser).unwrap(),
+            [(2, vec![7]), (1, vec![]), (3, vec![0, 4]), (2, vec![8]),]
+        );
+        assert_eq!(
+            uart.resets(&parser).unwrap(),
+            [NamedSpecifier {
+                name: None,
+                phandle: 4,
+                args: vec![3],
+            }]
+        );
+        assert!(
+            parser
+                .parse_specifier_list(uart, "dmas", "#dma-cells")
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_named_specifiers() {
+        let builder = StructBuilder::new().begin_node("");
+        let builder = provider(builder, "reset@1000", "#reset-cells", 1, 1);
+        let builder = provider(builder, "dma@2000", "#dma-cells", 2, 2);
+        let builder = provider(builder, "pwm@3000", "#pwm-cells", 3, 3);
+        let data = builder
+            .begin_node("mmc@4000")
+            .named_property("resets", &cells(&[1, 5]))
+            .named_property("reset-names", b"core\0")
+            // A placeholder entry still consumes a name
+            .named_property("dmas", &cells(&[0, 2, 1, 4]))
+            .named_property("dma-names", b"tx\0rx\0")
+            .named_property("pwms", &cells(&[3, 0, 5_000_000, 1]))
+            .end_node()
+            .end_node()
+            .end()
+            .build_dtb();
+        let parser = DeviceTreeParser::new(&data);
+        let mmc = parser.find_node_ref("/mmc@4000").unwrap().unwrap();
+
+        assert_eq!(
+            mmc.resets(&parser).unwrap(),
+            [NamedSpecifier {
+                name: Some("core"),
+                phandle: 1,
+                args: vec![5],
+            }]
+        );
+        assert_eq!(
+            mmc.dmas(&parser).unwrap(),
+            [NamedSpecifier {
+                name: Some("rx"),
+                phandle: 2,
+                args: vec![1, 4],
+            }]
+        );
+        // No pwm-names property
+        assert_eq!(
+            mmc.pwms(&parser).unwrap(),
+            [NamedSpecifier {
+                name: None,
+                phandle: 3,
+                args: vec![0, 5_000_000, 1],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_specifier_list_missing_cells() {
+        let data = mixed_dtb();
+        let parser = DeviceTreeParser::new(&data);
+        let broken = parser.find_node_ref("/broken@5000").unwrap().unwrap();
+
+        // The reset controller has no #clock-cells
+        assert_eq!(
+            broken.clocks(&parser).unwrap_err(),
+            DtbError::InvalidSpecifier
+        );
+    }
+}
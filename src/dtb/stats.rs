@@ -0,0 +1,137 @@
+// ABOUTME: Size statistics for a device tree computed by streaming the structure block
+// ABOUTME: Counts nodes, properties, nesting depth, and property bytes without building a tree
+
+use super::cursor::{StructCursor, StructEvent};
+use super::error::DtbError;
+
+/// Summary counts for a device tree, as returned by
+/// [`DeviceTreeParser::stats`](super::DeviceTreeParser::stats).
+///
+/// Useful for sizing buffers or node pools before parsing in `no_std`
+/// environments.
+///
+/// # Examples
+///
+/// ```rust
+/// # use device_tree_parser::{DeviceTreeParser, DtbError};
+/// # fn example() -> Result<(), DtbError> {
+/// # let dtb_data = vec![0u8; 64]; // Mock data
+/// let parser = DeviceTreeParser::new(&dtb_data);
+///
+/// let stats = parser.stats()?;
+/// println!(
+///     "{} nodes, {} properties ({} bytes), depth {}",
+///     stats.node_count, stats.property_count, stats.property_bytes, stats.max_depth
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DtbStats {
+    /// Number of nodes, including the root.
+    pub node_count: usize,
+    /// Number of properties across all nodes.
+    pub property_count: usize,
+    /// Deepest nesting level; the root alone has depth 1.
+    pub max_depth: usize,
+    /// Total length of all property values in bytes, excluding headers and
+    /// padding.
+    pub property_bytes: usize,
+}
+
+impl DtbStats {
+    /// Collects statistics by walking `cursor` to the end of the structure block.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError::UnbalancedNodes`] if begin and end node tokens do
+    /// not match, or any error from [`StructCursor::next_token`].
+    pub fn from_cursor(mut cursor: StructCursor<'_>) -> Result<Self, DtbError> {
+        let mut stats = Self::default();
+        let mut depth = 0usize;
+        while let Some(event) = cursor.next_token()? {
+            match event {
+                StructEvent::BeginNode(_) => {
+                    depth += 1;
+                    stats.node_count += 1;
+                    stats.max_depth = stats.max_depth.max(depth);
+                }
+                StructEvent::EndNode => {
+                    depth = depth.checked_sub(1).ok_or(DtbError::UnbalancedNodes)?;
+                }
+                StructEvent::Property(_) => {
+                    stats.property_count += 1;
+                    stats.property_bytes += cursor.last_property_len();
+                }
+            }
+        }
+        if depth != 0 {
+            return Err(DtbError::UnbalancedNodes);
+        }
+        Ok(stats)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dtb::DeviceTreeParser;
+    use crate::dtb::test_support::StructBuilder;
+    use crate::dtb::tree::DeviceTreeNode;
+
+    fn depth(node: &DeviceTreeNode<'_>) -> usize {
+        1 + node.children.iter().map(depth).max().unwrap_or(0)
+    }
+
+    #[test]
+    fn test_stats_match_tree() {
+        let data = StructBuilder::new()
+            .begin_node("")
+            .named_property("model", b"stats-board\0")
+            .begin_node("soc")
+            .named_property("#address-cells", &1u32.to_be_bytes())
+            .begin_node("uart@1000")
+            .named_property("reg", &[0, 0, 0x10, 0, 0, 0, 1, 0])
+            .named_property("dma-coherent", &[])
+            .end_node()
+            .end_node()
+            .begin_node("chosen")
+            .end_node()
+            .end_node()
+            .end()
+            .build_dtb();
+        let parser = DeviceTreeParser::new(&data);
+        let stats = parser.stats().unwrap();
+        let root = parser.parse_tree().unwrap();
+
+        assert_eq!(stats.node_count, root.iter_nodes().count());
+        assert_eq!(
+            stats.property_count,
+            root.iter_nodes().map(|n| n.properties.len()).sum::<usize>()
+        );
+        assert_eq!(stats.max_depth, depth(&root));
+        assert_eq!(
+            stats,
+            DtbStats {
+                node_count: 4,
+                property_count: 4,
+                max_depth: 3,
+                property_bytes: 12 + 4 + 8,
+            }
+        );
+    }
+
+    #[test]
+    fn test_stats_unbalanced() {
+        let block = StructBuilder::new()
+            .begin_node("")
+            .begin_node("child")
+            .end_node()
+            .end()
+            .build();
+        assert_eq!(
+            DtbStats::from_cursor(StructCursor::new(&block, &[])).unwrap_err(),
+            DtbError::UnbalancedNodes
+        );
+    }
+}
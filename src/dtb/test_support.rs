@@ -0,0 +1,134 @@
+// ABOUTME: Test helpers for building structure blocks and complete DTB blobs
+// ABOUTME: Shared by unit tests that need hand-crafted device tree data
+
+use super::tokens::DtbToken;
+use alloc::vec::Vec;
+
+/// Encodes values as big-endian 32-bit cells
+pub(crate) fn cells(values: &[u32]) -> Vec<u8> {
+    values.iter().flat_map(|v| v.to_be_bytes()).collect()
+}
+
+/// Builds a structure block from tokens, node names, and properties
+pub(crate) struct StructBuilder {
+    data: Vec<u8>,
+    strings: Vec<u8>,
+    reservations: Vec<(u64, u64)>,
+}
+
+impl StructBuilder {
+    pub(crate) fn new() -> Self {
+        Self {
+            data: Vec::new(),
+            strings: Vec::new(),
+            reservations: Vec::new(),
+        }
+    }
+
+    /// Adds a memory reservation entry to the blob built by `build_dtb`
+    pub(crate) fn reservation(mut self, address: u64, size: u64) -> Self {
+        self.reservations.push((address, size));
+        self
+    }
+
+    pub(crate) fn token(mut self, token: DtbToken) -> Self {
+        token.write(&mut self.data);
+        self
+    }
+
+    pub(crate) fn begin_node(self, name: &str) -> Self {
+        self.begin_node_bytes(name.as_bytes())
+    }
+
+    /// Begins a node whose name need not be valid UTF-8
+    pub(crate) fn begin_node_bytes(mut self, name: &[u8]) -> Self {
+        self = self.token(DtbToken::BeginNode);
+        self.data.extend_from_slice(name);
+        self.data.push(0);
+        self.pad()
+    }
+
+    pub(crate) fn end_node(self) -> Self {
+        self.token(DtbToken::EndNode)
+    }
+
+    pub(crate) fn property(mut self, name_offset: u32, value: &[u8]) -> Self {
+        self = self.token(DtbToken::Property);
+        DtbToken::write_property_header(value.len() as u32, name_offset, &mut self.data);
+        self.data.extend_from_slice(value);
+        self.pad()
+    }
+
+    /// Adds a property whose name is stored in the builder's strings block
+    pub(crate) fn named_property(self, name: &str, value: &[u8]) -> Self {
+        self.named_property_bytes(name.as_bytes(), value)
+    }
+
+    /// Adds a property whose name need not be valid UTF-8
+    pub(crate) fn named_property_bytes(mut self, name: &[u8], value: &[u8]) -> Self {
+        let offset = self.intern(name);
+        self.property(offset, value)
+    }
+
+    pub(crate) fn end(self) -> Self {
+        self.token(DtbToken::End)
+    }
+
+    pub(crate) fn pad(mut self) -> Self {
+        while !self.data.len().is_multiple_of(4) {
+            self.data.push(0);
+        }
+        self
+    }
+
+    pub(crate) fn build(self) -> Vec<u8> {
+        self.data
+    }
+
+    /// Wraps the structure block and interned strings in a version 17 DTB
+    pub(crate) fn build_dtb(self) -> Vec<u8> {
+        const HEADER_SIZE: u32 = 40;
+        let rsvmap_size = (self.reservations.len() as u32 + 1) * 16;
+
+        let off_dt_struct = HEADER_SIZE + rsvmap_size;
+        let off_dt_strings = off_dt_struct + self.data.len() as u32;
+        let totalsize = off_dt_strings + self.strings.len() as u32;
+
+        let mut blob = Vec::with_capacity(totalsize as usize);
+        for field in [
+            0xd00d_feed,
+            totalsize,
+            off_dt_struct,
+            off_dt_strings,
+            HEADER_SIZE,
+            17,
+            16,
+            0,
+            self.strings.len() as u32,
+            self.data.len() as u32,
+        ] {
+            blob.extend_from_slice(&field.to_be_bytes());
+        }
+        for (address, size) in self.reservations.iter().chain([&(0, 0)]) {
+            blob.extend_from_slice(&address.to_be_bytes());
+            blob.extend_from_slice(&size.to_be_bytes());
+        }
+        blob.extend_from_slice(&self.data);
+        blob.extend_from_slice(&self.strings);
+        blob
+    }
+
+    fn intern(&mut self, name: &[u8]) -> u32 {
+        let mut offset = 0;
+        for existing in self.strings.split(|&b| b == 0) {
+            if existing == name && offset < self.strings.len() {
+                return offset as u32;
+            }
+            offset += existing.len() + 1;
+        }
+        let offset = self.strings.len() as u32;
+        self.strings.extend_from_slice(name);
+        self.strings.push(0);
+        offset
+    }
+}
@@ -1,7 +1,8 @@
 // ABOUTME: DTB structure block token definitions and parsing
-// ABOUTME: Handles the four basic DTB tokens with 4-byte alignment
+// ABOUTME: Handles the five DTB tokens (including FDT_NOP) with 4-byte alignment
 
 use super::error::DtbError;
+use alloc::vec::Vec;
 
 /// DTB token constants as defined in the device tree specification
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -12,6 +13,11 @@ pub enum DtbToken {
     EndNode,
     /// Property token (0x00000003)
     Property,
+    /// No-op token (0x00000004), ignored by parsers
+    ///
+    /// Tools that edit a DTB in place (e.g., applying an overlay or deleting a
+    /// property) may overwrite removed data with NOP tokens.
+    Nop,
     /// End of structure token (0x00000009)
     End,
 }
@@ -23,6 +29,8 @@ impl DtbToken {
     pub const FDT_END_NODE: u32 = 0x0000_0002;
     /// Property token constant
     pub const FDT_PROP: u32 = 0x0000_0003;
+    /// No-op token constant
+    pub const FDT_NOP: u32 = 0x0000_0004;
     /// End of structure token constant
     pub const FDT_END: u32 = 0x0000_0009;
 
@@ -36,6 +44,7 @@ impl DtbToken {
             Self::FDT_BEGIN_NODE => Ok(DtbToken::BeginNode),
             Self::FDT_END_NODE => Ok(DtbToken::EndNode),
             Self::FDT_PROP => Ok(DtbToken::Property),
+            Self::FDT_NOP => Ok(DtbToken::Nop),
             Self::FDT_END => Ok(DtbToken::End),
             _ => Err(DtbError::InvalidToken),
         }
@@ -48,10 +57,36 @@ impl DtbToken {
             DtbToken::BeginNode => Self::FDT_BEGIN_NODE,
             DtbToken::EndNode => Self::FDT_END_NODE,
             DtbToken::Property => Self::FDT_PROP,
+            DtbToken::Nop => Self::FDT_NOP,
             DtbToken::End => Self::FDT_END,
         }
     }
 
+    /// Append the token's 4-byte big-endian value to `out`
+    pub fn write(self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.to_u32().to_be_bytes());
+    }
+
+    /// Append a node name, its null terminator, and padding to `out`
+    ///
+    /// This is the body of an `FDT_BEGIN_NODE` token. Padding brings the
+    /// length of `out` to a multiple of 4, so `out` should start at a 4-byte
+    /// aligned position in the structure block.
+    pub fn write_node_name(name: &str, out: &mut Vec<u8>) {
+        out.extend_from_slice(name.as_bytes());
+        out.push(0);
+        out.resize(out.len() + Self::calculate_padding(out.len()), 0);
+    }
+
+    /// Append a property header (value length, then name offset) to `out`
+    ///
+    /// This follows an `FDT_PROP` token and precedes the value bytes, where
+    /// `name_offset` is the property name's offset in the strings block.
+    pub fn write_property_header(len: u32, name_offset: u32, out: &mut Vec<u8>) {
+        out.extend_from_slice(&len.to_be_bytes());
+        out.extend_from_slice(&name_offset.to_be_bytes());
+    }
+
     /// Parse a single token from input bytes with 4-byte alignment
     ///
     /// # Errors
@@ -65,10 +100,39 @@ impl DtbToken {
         }
 
         // Ensure 4-byte alignment
-        if (input.as_ptr() as usize) % 4 != 0 {
+        if !(input.as_ptr() as usize).is_multiple_of(4) {
             return Err(DtbError::AlignmentError);
         }
 
+        Self::decode(input)
+    }
+
+    /// Parse a single token at `offset` within `data`, checking alignment
+    /// relative to the start of `data` rather than the memory address.
+    ///
+    /// Use this when `data` is the DTB (or its structure block) and may itself
+    /// sit at an arbitrary address, such as a sub-slice of a larger buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DtbError::MalformedHeader` if fewer than 4 bytes remain at `offset`.
+    /// Returns `DtbError::AlignmentError` if `offset` is not a multiple of 4.
+    /// Returns `DtbError::InvalidToken` if token value is not recognized.
+    pub fn parse_at(data: &[u8], offset: usize) -> Result<(&[u8], Self), DtbError> {
+        if !offset.is_multiple_of(4) {
+            return Err(DtbError::AlignmentError);
+        }
+
+        let input = data.get(offset..).ok_or(DtbError::MalformedHeader)?;
+        if input.len() < 4 {
+            return Err(DtbError::MalformedHeader);
+        }
+
+        Self::decode(input)
+    }
+
+    /// Decode the token at the start of `input`, which must hold at least 4 bytes
+    fn decode(input: &[u8]) -> Result<(&[u8], Self), DtbError> {
         // Parse token value using array slicing
         let token_bytes: [u8; 4] = input[0..4]
             .try_into()
@@ -106,6 +170,7 @@ mod tests {
         assert_eq!(DtbToken::FDT_BEGIN_NODE, 0x00000001);
         assert_eq!(DtbToken::FDT_END_NODE, 0x00000002);
         assert_eq!(DtbToken::FDT_PROP, 0x00000003);
+        assert_eq!(DtbToken::FDT_NOP, 0x00000004);
         assert_eq!(DtbToken::FDT_END, 0x00000009);
     }
 
@@ -114,6 +179,7 @@ mod tests {
         assert_eq!(DtbToken::from_u32(0x00000001).unwrap(), DtbToken::BeginNode);
         assert_eq!(DtbToken::from_u32(0x00000002).unwrap(), DtbToken::EndNode);
         assert_eq!(DtbToken::from_u32(0x00000003).unwrap(), DtbToken::Property);
+        assert_eq!(DtbToken::from_u32(0x00000004).unwrap(), DtbToken::Nop);
         assert_eq!(DtbToken::from_u32(0x00000009).unwrap(), DtbToken::End);
 
         assert!(DtbToken::from_u32(0x12345678).is_err());
@@ -124,9 +190,46 @@ mod tests {
         assert_eq!(DtbToken::BeginNode.to_u32(), 0x00000001);
         assert_eq!(DtbToken::EndNode.to_u32(), 0x00000002);
         assert_eq!(DtbToken::Property.to_u32(), 0x00000003);
+        assert_eq!(DtbToken::Nop.to_u32(), 0x00000004);
         assert_eq!(DtbToken::End.to_u32(), 0x00000009);
     }
 
+    #[test]
+    fn test_token_write() {
+        let mut out = Vec::new();
+        DtbToken::BeginNode.write(&mut out);
+        DtbToken::End.write(&mut out);
+        assert_eq!(out, [0, 0, 0, 1, 0, 0, 0, 9]);
+
+        let (_, token) = DtbToken::parse_at(&out, 4).unwrap();
+        assert_eq!(token, DtbToken::End);
+    }
+
+    #[test]
+    fn test_write_node_name() {
+        let mut out = Vec::new();
+        DtbToken::write_node_name("cpu@0", &mut out);
+        assert_eq!(out, b"cpu@0\0\0\0");
+
+        // Exactly one terminator word for a 4-byte name
+        out.clear();
+        DtbToken::write_node_name("cpus", &mut out);
+        assert_eq!(out, b"cpus\0\0\0\0");
+
+        // The root's empty name still takes a full word
+        out.clear();
+        DtbToken::write_node_name("", &mut out);
+        assert_eq!(out, [0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_write_property_header() {
+        let mut out = Vec::new();
+        DtbToken::Property.write(&mut out);
+        DtbToken::write_property_header(8, 0x1c, &mut out);
+        assert_eq!(out, [0, 0, 0, 3, 0, 0, 0, 8, 0, 0, 0, 0x1c]);
+    }
+
     #[test]
     fn test_token_parse_begin_node() {
         let data = [0x00, 0x00, 0x00, 0x01, 0x12, 0x34, 0x56, 0x78];
@@ -161,6 +264,48 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_token_parse_at_misaligned_buffer() {
+        let tokens = [0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x09];
+
+        // Place the tokens at an address that is not 4-byte aligned
+        let mut backing = alloc::vec![0u8; tokens.len() + 4];
+        let shift = (1..4)
+            .find(|i| !(backing.as_ptr() as usize + i).is_multiple_of(4))
+            .unwrap();
+        backing[shift..shift + tokens.len()].copy_from_slice(&tokens);
+        let data = &backing[shift..shift + tokens.len()];
+
+        assert_eq!(DtbToken::parse(data).unwrap_err(), DtbError::AlignmentError);
+
+        let (remaining, token) = DtbToken::parse_at(data, 0).unwrap();
+        assert_eq!(token, DtbToken::BeginNode);
+        assert_eq!(remaining, &tokens[4..]);
+
+        let (remaining, token) = DtbToken::parse_at(data, 4).unwrap();
+        assert_eq!(token, DtbToken::End);
+        assert!(remaining.is_empty());
+    }
+
+    #[test]
+    fn test_token_parse_at_offsets() {
+        let data = [0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x03];
+
+        assert_eq!(
+            DtbToken::parse_at(&data, 2).unwrap_err(),
+            DtbError::AlignmentError
+        );
+        assert_eq!(
+            DtbToken::parse_at(&data, 8).unwrap_err(),
+            DtbError::MalformedHeader
+        );
+        assert_eq!(
+            DtbToken::parse_at(&data, 12).unwrap_err(),
+            DtbError::MalformedHeader
+        );
+        assert_eq!(DtbToken::parse_at(&data, 4).unwrap().1, DtbToken::Property);
+    }
+
     #[test]
     fn test_calculate_padding() {
         assert_eq!(DtbToken::calculate_padding(0), 0);
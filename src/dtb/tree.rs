@@ -2,11 +2,19 @@
 // ABOUTME: Provides tree building and traversal functionality
 
 use super::error::DtbError;
+use super::pci::{PciAddress, PciRange};
+use super::repair::Repair;
+use super::schema::{self, PropKind};
 use super::tokens::DtbToken;
-use alloc::{vec, vec::Vec};
+use alloc::{
+    collections::{BTreeMap, VecDeque},
+    string::String,
+    vec,
+    vec::Vec,
+};
 use core::convert::TryFrom;
-use core::fmt::{self, Display, Formatter};
-use core::ops::Index;
+use core::fmt::{self, Display, Formatter, Write};
+use core::ops::{Index, IndexMut};
 
 /// Strongly-typed property values in device trees.
 ///
@@ -69,6 +77,204 @@ pub enum PropertyValue<'a> {
     Bytes(&'a [u8]),
 }
 
+impl<'a> PropertyValue<'a> {
+    /// Returns a non-allocating iterator over the value's 32-bit cells.
+    ///
+    /// Lazily decodes big-endian words from the borrowed `U32Array` bytes.
+    /// A `U32` value yields its single cell, matching `Vec::<u32>::try_from()`.
+    /// Other variants yield nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::PropertyValue;
+    /// let bytes = [0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x20, 0x00];
+    /// let value = PropertyValue::U32Array(&bytes);
+    /// let sum: u32 = value.u32_iter().sum();
+    /// assert_eq!(sum, 0x3000);
+    /// ```
+    pub fn u32_iter(&self) -> impl Iterator<Item = u32> + 'a {
+        let (single, bytes): (Option<u32>, &'a [u8]) = match self {
+            PropertyValue::U32(val) => (Some(*val), &[]),
+            PropertyValue::U32Array(bytes) => (None, *bytes),
+            _ => (None, &[]),
+        };
+        single.into_iter().chain(
+            bytes
+                .chunks_exact(4)
+                .map(|chunk| u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]])),
+        )
+    }
+
+    /// Returns a non-allocating iterator over the value's 64-bit words.
+    ///
+    /// Lazily decodes big-endian words from the borrowed `U64Array` bytes,
+    /// or from `U32Array` bytes as pairs of cells, which is how parsed
+    /// 64-bit values are classified. A trailing unpaired cell is ignored. A
+    /// `U64` value yields its single word. Other variants yield nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::PropertyValue;
+    /// let value = PropertyValue::U32Array(&[0, 0, 0, 1, 0, 0, 0, 2]);
+    /// assert_eq!(value.u64_iter().collect::<Vec<_>>(), [0x1_0000_0002]);
+    /// ```
+    pub fn u64_iter(&self) -> impl Iterator<Item = u64> + 'a {
+        let (single, bytes): (Option<u64>, &'a [u8]) = match self {
+            PropertyValue::U64(val) => (Some(*val), &[]),
+            PropertyValue::U32Array(bytes) | PropertyValue::U64Array(bytes) => (None, *bytes),
+            _ => (None, &[]),
+        };
+        single.into_iter().chain(bytes.chunks_exact(8).map(|chunk| {
+            u64::from_be_bytes([
+                chunk[0], chunk[1], chunk[2], chunk[3], chunk[4], chunk[5], chunk[6], chunk[7],
+            ])
+        }))
+    }
+
+    /// Formats the value's encoded bytes as a hex string.
+    ///
+    /// Six-byte values are rendered in MAC address style (`00:11:22:33:44:55`);
+    /// everything else is rendered as a plain lowercase hex string
+    /// (`0011223344`). Numeric values use their big-endian encoding and strings
+    /// include their null terminators, matching the bytes stored in the DTB.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::PropertyValue;
+    /// let mac = PropertyValue::Bytes(&[0x52, 0x54, 0x00, 0x12, 0x34, 0x56]);
+    /// assert_eq!(mac.as_hex_string(), "52:54:00:12:34:56");
+    ///
+    /// let value = PropertyValue::U32(0xdeadbeef);
+    /// assert_eq!(value.as_hex_string(), "deadbeef");
+    /// ```
+    #[must_use]
+    pub fn as_hex_string(&self) -> String {
+        let bytes = self.encode();
+        let separator = if bytes.len() == 6 { ":" } else { "" };
+        let mut hex = String::with_capacity(bytes.len() * 3);
+        for (i, byte) in bytes.iter().enumerate() {
+            if i > 0 {
+                hex.push_str(separator);
+            }
+            // Writing to a String cannot fail
+            let _ = write!(hex, "{byte:02x}");
+        }
+        hex
+    }
+
+    /// Decodes the value as big-endian 32-bit cells, rejecting partial cells.
+    ///
+    /// Unlike [`u32_iter`](Self::u32_iter), which silently skips anything that
+    /// isn't a whole cell, this works on the value's encoded bytes and fails
+    /// if their length is not a multiple of 4. This catches truncated
+    /// properties, and values that happened to be classified as strings or raw
+    /// bytes are still decoded. An empty value yields no cells.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError::InvalidPropertyLength`] if the encoded length is not
+    /// a multiple of 4.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DtbError, PropertyValue};
+    /// let value = PropertyValue::U32Array(&[0, 0, 0, 1, 0, 0, 0, 2]);
+    /// assert_eq!(value.as_u32_array_checked(), Ok(vec![1, 2]));
+    ///
+    /// let truncated = PropertyValue::Bytes(&[0, 0, 0, 1, 0]);
+    /// assert_eq!(
+    ///     truncated.as_u32_array_checked(),
+    ///     Err(DtbError::InvalidPropertyLength(5))
+    /// );
+    /// ```
+    pub fn as_u32_array_checked(&self) -> Result<Vec<u32>, DtbError> {
+        let bytes = self.encode();
+        if !bytes.len().is_multiple_of(4) {
+            return Err(DtbError::InvalidPropertyLength(bytes.len()));
+        }
+        Ok(bytes
+            .chunks_exact(4)
+            .map(|chunk| u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+            .collect())
+    }
+
+    /// Length in bytes of the value as stored in the DTB.
+    pub(crate) fn encoded_len(&self) -> usize {
+        match self {
+            PropertyValue::Empty => 0,
+            PropertyValue::String(s) => s.len() + 1,
+            PropertyValue::StringList(list) => list.iter().map(|s| s.len() + 1).sum(),
+            PropertyValue::U32(_) => 4,
+            PropertyValue::U64(_) => 8,
+            PropertyValue::U32Array(bytes)
+            | PropertyValue::U64Array(bytes)
+            | PropertyValue::Bytes(bytes) => bytes.len(),
+        }
+    }
+
+    /// Serializes the value to its DTB byte form.
+    ///
+    /// Numeric values use their big-endian encoding, strings include their
+    /// null terminators, string lists are concatenated, and
+    /// [`PropertyValue::Empty`] yields no bytes.
+    ///
+    /// This re-encodes the classified value, not the bytes it was parsed
+    /// from, so it is not an exact inverse of property parsing. A value the
+    /// heuristic misclassified encodes differently from its source, and
+    /// re-parsing an encoding can classify it differently: `String("")`
+    /// encodes as a lone terminator, which parses as `Empty`, and
+    /// `U32(0x4142_4300)` encodes as `"ABC\0"`, which parses as a string.
+    /// Use [`Property::raw`] when the original bytes matter.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::PropertyValue;
+    /// assert_eq!(PropertyValue::U32(0x8000).encode(), [0, 0, 0x80, 0]);
+    /// assert_eq!(
+    ///     PropertyValue::StringList(vec!["a", "bc"]).encode(),
+    ///     b"a\0bc\0"
+    /// );
+    /// assert!(PropertyValue::Empty.encode().is_empty());
+    /// ```
+    #[must_use]
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        match self {
+            PropertyValue::Empty => {}
+            PropertyValue::String(s) => {
+                bytes.extend_from_slice(s.as_bytes());
+                bytes.push(0);
+            }
+            PropertyValue::StringList(list) => {
+                for s in list {
+                    bytes.extend_from_slice(s.as_bytes());
+                    bytes.push(0);
+                }
+            }
+            PropertyValue::U32(val) => bytes.extend_from_slice(&val.to_be_bytes()),
+            PropertyValue::U64(val) => bytes.extend_from_slice(&val.to_be_bytes()),
+            PropertyValue::U32Array(data)
+            | PropertyValue::U64Array(data)
+            | PropertyValue::Bytes(data) => bytes.extend_from_slice(data),
+        }
+        bytes
+    }
+
+    /// Returns the value as a MAC address if it holds exactly six raw bytes.
+    #[must_use]
+    pub fn mac_address(&self) -> Option<[u8; 6]> {
+        match self {
+            PropertyValue::Bytes(bytes) => (*bytes).try_into().ok(),
+            _ => None,
+        }
+    }
+}
+
 /// Device tree property with name and typed value.
 ///
 /// Properties are key-value pairs that describe characteristics of device tree
@@ -102,6 +308,29 @@ pub struct Property<'a> {
     pub name: &'a str,
     /// Strongly-typed property value.
     pub value: PropertyValue<'a>,
+    /// Value bytes exactly as stored in the DTB.
+    ///
+    /// Set only when parsing with
+    /// [`ParseOptions::keep_raw`](super::ParseOptions::keep_raw), and `None`
+    /// for properties built by hand. Unlike [`PropertyValue::encode`], this
+    /// preserves the original bytes even when the value was misclassified,
+    /// such as an unterminated string.
+    pub raw: Option<&'a [u8]>,
+}
+
+impl Property<'_> {
+    /// Value bytes as stored in the DTB, when they can be known.
+    ///
+    /// Uses [`raw`](Self::raw) when kept, otherwise re-encodes the value.
+    /// String values yield `None` without `raw`: their original bytes (a
+    /// missing terminator, embedded empty strings) cannot be recovered.
+    pub(crate) fn stored_bytes(&self) -> Option<Vec<u8>> {
+        match (self.raw, &self.value) {
+            (Some(raw), _) => Some(raw.to_vec()),
+            (None, PropertyValue::String(_) | PropertyValue::StringList(_)) => None,
+            (None, value) => Some(value.encode()),
+        }
+    }
 }
 
 /// Address specification for device tree nodes.
@@ -239,6 +468,8 @@ impl Default for AddressSpec {
 /// bus domains. Each range specifies how to translate addresses from the child address
 /// space to the parent address space.
 ///
+/// Ranges order by child address, then size, then parent address.
+///
 /// # Examples
 ///
 /// ```rust
@@ -392,6 +623,176 @@ impl AddressRange {
             .checked_add(offset)
             .ok_or(DtbError::AddressTranslationError(child_addr))
     }
+
+    /// Checks if a parent address falls within this range.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - Address in parent's address space to check
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{AddressRange, DtbError};
+    /// let range = AddressRange::new(0x1000, 0x80001000, 0x1000)?;
+    ///
+    /// assert!(range.contains_parent(0x80001000));
+    /// assert!(!range.contains_parent(0x80002000));  // End of range (exclusive)
+    /// assert!(!range.contains_parent(0x1000));      // Child address
+    /// # Ok::<(), DtbError>(())
+    /// ```
+    #[must_use]
+    pub const fn contains_parent(&self, address: u64) -> bool {
+        address >= self.parent_address && address < self.parent_end()
+    }
+
+    /// Translates a parent address back to the corresponding child address.
+    ///
+    /// Inverse of [`translate`](Self::translate).
+    ///
+    /// # Arguments
+    ///
+    /// * `parent_addr` - Address in parent's address space
+    ///
+    /// # Errors
+    ///
+    /// Returns `DtbError::AddressTranslationError` if the address is not
+    /// within this range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{AddressRange, DtbError};
+    /// let range = AddressRange::new(0x0, 0x80000000, 0x10000)?;
+    ///
+    /// assert_eq!(range.translate_reverse(0x80008000)?, 0x8000);
+    /// assert!(range.translate_reverse(0x8000).is_err());
+    /// # Ok::<(), DtbError>(())
+    /// ```
+    pub fn translate_reverse(&self, parent_addr: u64) -> Result<u64, DtbError> {
+        if !self.contains_parent(parent_addr) {
+            return Err(DtbError::AddressTranslationError(parent_addr));
+        }
+
+        Ok(self.child_address + (parent_addr - self.parent_address))
+    }
+}
+
+impl PartialOrd for AddressRange {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for AddressRange {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        (self.child_address, self.size, self.parent_address).cmp(&(
+            other.child_address,
+            other.size,
+            other.parent_address,
+        ))
+    }
+}
+
+/// How a node's `ranges` property maps child addresses to its parent.
+///
+/// Distinguishes an absent `ranges` property (the node's children are not
+/// memory-mapped into the parent) from an empty one (a 1:1 mapping), which
+/// [`DeviceTreeNode::ranges`] both report as an empty vector.
+///
+/// # Examples
+///
+/// ```rust
+/// # use device_tree_parser::{DeviceTreeNode, DtbError, RangesKind};
+/// # fn example(bus: &DeviceTreeNode, parent: Option<&DeviceTreeNode>) -> Result<(), DtbError> {
+/// match bus.ranges_kind(parent, 2)? {
+///     RangesKind::None => println!("No translation"),
+///     RangesKind::Identity => println!("1:1 mapping"),
+///     RangesKind::Explicit(ranges) => println!("{} translation windows", ranges.len()),
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RangesKind {
+    /// No `ranges` property; addresses cannot be translated to the parent.
+    None,
+    /// Empty `ranges` property; child and parent address spaces are identical.
+    Identity,
+    /// Translation windows decoded from a non-empty `ranges` property.
+    Explicit(Vec<AddressRange>),
+}
+
+impl RangesKind {
+    /// Translate a child address to the parent address space.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DtbError::AddressTranslationError` if there is no `ranges`
+    /// property, no range contains the address, or the result overflows.
+    pub fn translate(&self, child_address: u64) -> Result<u64, DtbError> {
+        match self {
+            RangesKind::None => Err(DtbError::AddressTranslationError(child_address)),
+            RangesKind::Identity => Ok(child_address),
+            RangesKind::Explicit(ranges) => ranges
+                .iter()
+                .find(|range| range.contains(child_address))
+                .ok_or(DtbError::AddressTranslationError(child_address))?
+                .translate(child_address),
+        }
+    }
+
+    /// Translate a parent address back to the child address space.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DtbError::AddressTranslationError` if there is no `ranges`
+    /// property or no range contains the address.
+    pub fn translate_reverse(&self, parent_address: u64) -> Result<u64, DtbError> {
+        match self {
+            RangesKind::None => Err(DtbError::AddressTranslationError(parent_address)),
+            RangesKind::Identity => Ok(parent_address),
+            RangesKind::Explicit(ranges) => ranges
+                .iter()
+                .find(|range| range.contains_parent(parent_address))
+                .ok_or(DtbError::AddressTranslationError(parent_address))?
+                .translate_reverse(parent_address),
+        }
+    }
+}
+
+/// Operational status of a device tree node from its `status` property.
+///
+/// Drivers must only bind to nodes whose status is [`Status::Okay`]. A node
+/// without a `status` property is treated as `Okay`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use device_tree_parser::{DeviceTreeNode, Status};
+/// # fn example(node: &DeviceTreeNode) {
+/// match node.status() {
+///     Status::Okay => println!("{} is enabled", node.name),
+///     Status::Disabled => println!("{} is disabled", node.name),
+///     Status::Unknown(s) => println!("{} has unknown status {}", node.name, s),
+///     _ => {}
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status<'a> {
+    /// Device is operational (`"okay"`, or the legacy `"ok"`).
+    Okay,
+    /// Device is not presently operational but might become so (`"disabled"`).
+    Disabled,
+    /// Device is operational but should not be used, typically because it is
+    /// controlled by other software (`"reserved"`).
+    Reserved,
+    /// Device has a serious error and is not expected to become operational
+    /// (`"fail"` or `"fail-sss"`).
+    Fail,
+    /// Unrecognized status value.
+    Unknown(&'a str),
 }
 
 /// Device tree node representing a hardware component or logical grouping.
@@ -499,6 +900,25 @@ impl<'a> DeviceTreeNode<'a> {
         self.properties.push(property);
     }
 
+    /// Set a property, replacing any existing property with the same name.
+    ///
+    /// Returns the previous property if one was replaced.
+    pub fn set_property(&mut self, property: Property<'a>) -> Option<Property<'a>> {
+        match self.properties.iter_mut().find(|p| p.name == property.name) {
+            Some(existing) => Some(core::mem::replace(existing, property)),
+            None => {
+                self.properties.push(property);
+                None
+            }
+        }
+    }
+
+    /// Remove a property by name, returning it if it was present
+    pub fn remove_property(&mut self, name: &str) -> Option<Property<'a>> {
+        let index = self.properties.iter().position(|p| p.name == name)?;
+        Some(self.properties.remove(index))
+    }
+
     /// Add a child node
     pub fn add_child(&mut self, child: DeviceTreeNode<'a>) {
         self.children.push(child);
@@ -510,6 +930,64 @@ impl<'a> DeviceTreeNode<'a> {
         self.properties.iter().find(|p| p.name == name)
     }
 
+    /// Find a property by name on this node or, failing that, its ancestors.
+    ///
+    /// `ancestors` lists the node's ancestors nearest first (parent,
+    /// grandparent, and so on up to the root), as they are searched in that
+    /// order. This is the lookup behind inherited properties such as
+    /// `interrupt-parent`, generalizing
+    /// [`address_cells_with_parent`](Self::address_cells_with_parent) to any
+    /// property and any depth.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::DeviceTreeNode;
+    /// # fn example(uart: &DeviceTreeNode, soc: &DeviceTreeNode, root: &DeviceTreeNode) {
+    /// if let Some(parent) = uart.find_property_inherited("interrupt-parent", &[soc, root]) {
+    ///     println!("Interrupt parent: {}", parent.value);
+    /// }
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn find_property_inherited<'s>(
+        &'s self,
+        name: &str,
+        ancestors: &[&'s DeviceTreeNode<'a>],
+    ) -> Option<&'s Property<'a>> {
+        self.find_property(name)
+            .or_else(|| ancestors.iter().find_map(|node| node.find_property(name)))
+    }
+
+    /// Get a property by name without panicking.
+    ///
+    /// Non-panicking equivalent of `node["name"]`, returning `None` when the
+    /// property is missing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::DeviceTreeNode;
+    /// # fn example(node: &DeviceTreeNode) {
+    /// if let Some(reg) = node.get("reg") {
+    ///     println!("Register: {}", reg.value);
+    /// }
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&Property<'a>> {
+        self.find_property(name)
+    }
+
+    /// Get a child node by index without panicking.
+    ///
+    /// Non-panicking equivalent of `node[index]`, returning `None` when the
+    /// index is out of bounds.
+    #[must_use]
+    pub fn get_child(&self, index: usize) -> Option<&DeviceTreeNode<'a>> {
+        self.children.get(index)
+    }
+
     /// Find a child node by name
     #[must_use]
     pub fn find_child(&self, name: &str) -> Option<&DeviceTreeNode<'a>> {
@@ -535,27 +1013,94 @@ impl<'a> DeviceTreeNode<'a> {
             return Some(self);
         }
 
-        let current_part = parts[0];
-        let remaining_parts = &parts[1..];
+        self.find_path_component(parts[0])?
+            .find_node_by_parts(&parts[1..])
+    }
 
-        // Look for exact match first
-        if let Some(child) = self.find_child(current_part) {
-            return child.find_node_by_parts(remaining_parts);
-        }
+    /// Find the child matching one path component
+    fn find_path_component(&self, part: &str) -> Option<&DeviceTreeNode<'a>> {
+        // Look for exact match first, then an address-based match (e.g., "cpu@0")
+        self.find_child(part).or_else(|| {
+            self.children
+                .iter()
+                .find(|child| child.name.contains('@') && child.node_base_name() == part)
+        })
+    }
 
-        // Look for address-based match (e.g., "cpu@0")
-        for child in &self.children {
-            if child.name.starts_with(current_part)
-                && let Some(at_pos) = child.name.find('@')
-            {
-                let base_name = &child.name[..at_pos];
-                if base_name == current_part {
-                    return child.find_node_by_parts(remaining_parts);
-                }
-            }
+    /// Find a node by path, returning it together with its ancestors.
+    ///
+    /// The returned chain starts with `self` and ends with the matched node.
+    pub(crate) fn find_node_chain(&self, path: &str) -> Option<Vec<&DeviceTreeNode<'a>>> {
+        let mut chain = vec![self];
+        for part in path.split('/').filter(|part| !part.is_empty()) {
+            let next = chain[chain.len() - 1].find_path_component(part)?;
+            chain.push(next);
         }
+        Some(chain)
+    }
+
+    /// Get the node's phandle.
+    ///
+    /// Reads `phandle`, falling back to the legacy `linux,phandle` property.
+    #[must_use]
+    pub fn phandle(&self) -> Option<u32> {
+        self.prop_u32("phandle")
+            .or_else(|| self.prop_u32("linux,phandle"))
+    }
+
+    /// Find the node in this subtree whose phandle matches.
+    ///
+    /// Phandles are how properties such as `interrupt-parent`, `clocks`, and
+    /// `gpios` refer to other nodes. Call this on the root node to search the
+    /// whole tree.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::DeviceTreeNode;
+    /// # fn example(root: &DeviceTreeNode) {
+    /// if let Some(controller) = root.find_node_by_phandle(1) {
+    ///     println!("Phandle 1 is {}", controller.name);
+    /// }
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn find_node_by_phandle(&self, phandle: u32) -> Option<&DeviceTreeNode<'a>> {
+        self.iter_nodes()
+            .find(|node| node.phandle() == Some(phandle))
+    }
+
+    /// Get the node name without its unit address.
+    ///
+    /// Returns the part of the name before `@` (e.g., `uart` for
+    /// `uart@9000000`), or the whole name if it has no unit address.
+    #[must_use]
+    pub fn node_base_name(&self) -> &'a str {
+        self.name
+            .split_once('@')
+            .map_or(self.name, |(base, _)| base)
+    }
 
-        None
+    /// Get the unit address parsed as a hexadecimal number.
+    ///
+    /// Returns `None` when the name has no `@`, or when the unit address is not
+    /// a plain hex number (such as the `1,0` form used by PCI devices).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::DeviceTreeNode;
+    /// let node = DeviceTreeNode::new("uart@9000000");
+    /// assert_eq!(node.unit_address(), Some(0x900_0000));
+    /// assert_eq!(node.node_base_name(), "uart");
+    /// ```
+    #[must_use]
+    pub fn unit_address(&self) -> Option<u64> {
+        let (_, address) = self.name.split_once('@')?;
+        if address.is_empty() || !address.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        u64::from_str_radix(address, 16).ok()
     }
 
     /// Get property value as u32
@@ -580,6 +1125,30 @@ impl<'a> DeviceTreeNode<'a> {
         })
     }
 
+    /// Get a specific entry of a string list property
+    ///
+    /// Useful for properties like `clock-names` and `reg-names` whose entries
+    /// correspond by position to other properties. A single-string property is
+    /// treated as a list of one.
+    #[must_use]
+    pub fn prop_string_index(&self, name: &str, index: usize) -> Option<&'a str> {
+        self.find_property(name).and_then(|p| match &p.value {
+            PropertyValue::String(s) if index == 0 => Some(*s),
+            PropertyValue::StringList(list) => list.get(index).copied(),
+            _ => None,
+        })
+    }
+
+    /// Get property value as a list of strings
+    #[must_use]
+    pub fn prop_string_list(&self, name: &str) -> Option<Vec<&'a str>> {
+        self.find_property(name).and_then(|p| match &p.value {
+            PropertyValue::String(s) => Some(vec![*s]),
+            PropertyValue::StringList(list) => Some(list.clone()),
+            _ => None,
+        })
+    }
+
     /// Get property value as u32 array
     #[must_use]
     pub fn prop_u32_array(&self, name: &str) -> Option<Vec<u32>> {
@@ -596,19 +1165,140 @@ impl<'a> DeviceTreeNode<'a> {
         })
     }
 
-    /// Get property value as u64
-    #[must_use]
-    pub fn prop_u64(&self, name: &str) -> Option<u64> {
-        self.find_property(name).and_then(|p| match &p.value {
-            PropertyValue::U64(val) => Some(*val),
-            PropertyValue::U64Array(bytes) if bytes.len() >= 8 => Some(u64::from_be_bytes([
-                bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
-            ])),
-            _ => None,
-        })
-    }
-
-    /// Get property value as bytes
+    /// Get property value as a u32 array, rejecting truncated data
+    ///
+    /// Strict counterpart to [`prop_u32_array`](Self::prop_u32_array); see
+    /// [`PropertyValue::as_u32_array_checked`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError::PropertyNotFound`] if the property is missing, or
+    /// [`DtbError::InvalidPropertyLength`] if its length is not a multiple
+    /// of 4.
+    pub fn prop_u32_array_checked(&self, name: &str) -> Result<Vec<u32>, DtbError> {
+        self.find_property(name)
+            .ok_or(DtbError::PropertyNotFound)?
+            .value
+            .as_u32_array_checked()
+    }
+
+    /// Get a property value converted to `T`.
+    ///
+    /// Generic counterpart to the `prop_*` accessors, built on the
+    /// `TryFrom<&PropertyValue>` conversions, so any type with one (`u32`,
+    /// `u64`, `&str`, `Vec<u32>`, `[u32; N]`, `bool`, `&[u8]`, ...) can be
+    /// requested by name.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DtbError::PropertyNotFound`] if the property is missing, or
+    /// the conversion's error if the value has the wrong type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeNode, DtbError};
+    /// # fn example(node: &DeviceTreeNode) -> Result<(), DtbError> {
+    /// let phandle = node.value::<u32>("phandle")?;
+    /// let status: &str = node.value("status")?;
+    /// let reg = node.value::<Vec<u32>>("reg")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn value<'s, T>(&'s self, name: &str) -> Result<T, DtbError>
+    where
+        T: TryFrom<&'s PropertyValue<'a>, Error = DtbError>,
+    {
+        let property = self.find_property(name).ok_or(DtbError::PropertyNotFound)?;
+        T::try_from(&property.value)
+    }
+
+    /// Get property value as u64
+    #[must_use]
+    pub fn prop_u64(&self, name: &str) -> Option<u64> {
+        self.find_property(name).and_then(|p| match &p.value {
+            PropertyValue::U64(val) => Some(*val),
+            PropertyValue::U64Array(bytes) if bytes.len() >= 8 => Some(u64::from_be_bytes([
+                bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
+            ])),
+            _ => None,
+        })
+    }
+
+    /// Decode `cells` 32-bit cells starting at cell `cell_offset` as a u64.
+    ///
+    /// Unlike [`prop_u64`](Self::prop_u64), this decodes the property's
+    /// stored bytes rather than its classified value. A value that happens
+    /// to look like a string (e.g., `<0x41424344 0x45464748>`) reads
+    /// correctly only when its [`raw`](Property::raw) bytes were kept;
+    /// otherwise string values yield `None`, since their original bytes
+    /// cannot be recovered.
+    ///
+    /// `cells` follows [`parse_address_from_bytes`]: 1 or 2 cells are read
+    /// in full, and 3 or 4 cells keep the low 64 bits. Returns `None` if the
+    /// property is missing, `cells` is out of range, or the requested cells
+    /// run past the end of the value, or the value is a string without
+    /// `raw` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::DeviceTreeNode;
+    /// # fn example(memory: &DeviceTreeNode) {
+    /// // reg = <0x0 0x40000000 0x0 0x08000000> with 2 address and 2 size cells
+    /// let base = memory.prop_u64_at("reg", 0, 2);
+    /// let size = memory.prop_u64_at("reg", 2, 2);
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn prop_u64_at(&self, name: &str, cell_offset: usize, cells: u32) -> Option<u64> {
+        let bytes = self.find_property(name)?.stored_bytes()?;
+        let start = cell_offset.checked_mul(4)?;
+        let end = start.checked_add(cells as usize * 4)?;
+        parse_address_from_bytes(bytes.get(start..end)?, cells).ok()
+    }
+
+    /// Decode a property as a list of `cells`-wide big-endian integers.
+    ///
+    /// Reads the property's stored bytes (its [`raw`](Property::raw) bytes
+    /// when kept, otherwise the re-encoded non-string value) and groups them like
+    /// [`prop_u64_at`](Self::prop_u64_at): 1 or 2 cells per value are read
+    /// in full, and 3 or 4 cells keep the low 64 bits. This decodes
+    /// vendor-specific multi-cell properties without going through `reg`.
+    ///
+    /// Returns `None` if the property is missing, `cells` is not 1-4, the
+    /// value is not a whole number of groups, or the value is a string
+    /// without `raw` bytes. An empty value yields an empty
+    /// list.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::DeviceTreeNode;
+    /// # fn example(node: &DeviceTreeNode) {
+    /// // vendor,windows = <0x0 0x1000 0x0 0x2000> as two 64-bit values
+    /// if let Some(windows) = node.prop_cells("vendor,windows", 2) {
+    ///     println!("{} windows", windows.len());
+    /// }
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn prop_cells(&self, name: &str, cells: u32) -> Option<Vec<u64>> {
+        if cells == 0 || cells > AddressSpec::MAX_ADDRESS_CELLS {
+            return None;
+        }
+        let bytes = self.find_property(name)?.stored_bytes()?;
+        let group = cells as usize * 4;
+        if !bytes.len().is_multiple_of(group) {
+            return None;
+        }
+        bytes
+            .chunks_exact(group)
+            .map(|chunk| parse_address_from_bytes(chunk, cells).ok())
+            .collect()
+    }
+
+    /// Get property value as bytes
     #[must_use]
     pub fn prop_bytes(&self, name: &str) -> Option<&[u8]> {
         self.find_property(name).and_then(|p| match &p.value {
@@ -617,12 +1307,85 @@ impl<'a> DeviceTreeNode<'a> {
         })
     }
 
+    /// Get property value as a MAC address (e.g., `local-mac-address`)
+    #[must_use]
+    pub fn prop_mac(&self, name: &str) -> Option<[u8; 6]> {
+        self.find_property(name).and_then(|p| p.value.mac_address())
+    }
+
     /// Check if property exists
     #[must_use]
     pub fn has_property(&self, name: &str) -> bool {
         self.find_property(name).is_some()
     }
 
+    /// Get a boolean property following the device tree presence convention
+    ///
+    /// Boolean properties such as `interrupt-controller` and `dma-coherent` are
+    /// true when present (normally with an empty value) and false when absent.
+    #[must_use]
+    pub fn prop_bool(&self, name: &str) -> bool {
+        self.has_property(name)
+    }
+
+    /// Get property value as u32, or `default` if absent or not a u32
+    #[must_use]
+    pub fn prop_u32_or(&self, name: &str, default: u32) -> u32 {
+        self.prop_u32(name).unwrap_or(default)
+    }
+
+    /// Get property value as u64, or `default` if absent or not a u64
+    #[must_use]
+    pub fn prop_u64_or(&self, name: &str, default: u64) -> u64 {
+        self.prop_u64(name).unwrap_or(default)
+    }
+
+    /// Get property value as string, or `default` if absent or not a string
+    #[must_use]
+    pub fn prop_string_or<'s>(&'s self, name: &str, default: &'s str) -> &'s str {
+        self.prop_string(name).unwrap_or(default)
+    }
+
+    /// Get a boolean property, or `default` if absent
+    ///
+    /// A present property is true unless it holds a single zero cell, so both
+    /// the empty presence convention and `<0>`/`<1>` flags are understood.
+    #[must_use]
+    pub fn prop_bool_or(&self, name: &str, default: bool) -> bool {
+        match self.find_property(name) {
+            None => default,
+            Some(property) => property.value != PropertyValue::U32(0),
+        }
+    }
+
+    /// Get the `clock-frequency` property in Hz.
+    ///
+    /// Accepts both the 1-cell (32-bit) and 2-cell (64-bit) encodings.
+    #[must_use]
+    pub fn clock_frequency(&self) -> Option<u64> {
+        self.prop_one_or_two_cells("clock-frequency")
+    }
+
+    /// Get the `timebase-frequency` property in Hz.
+    ///
+    /// Accepts both the 1-cell (32-bit) and 2-cell (64-bit) encodings.
+    #[must_use]
+    pub fn timebase_frequency(&self) -> Option<u64> {
+        self.prop_one_or_two_cells("timebase-frequency")
+    }
+
+    /// Get an integer property encoded as one or two cells
+    pub(crate) fn prop_one_or_two_cells(&self, name: &str) -> Option<u64> {
+        self.find_property(name).and_then(|p| match &p.value {
+            PropertyValue::U32(val) => Some(u64::from(*val)),
+            PropertyValue::U32Array(bytes) if bytes.len() == 8 => Some(u64::from_be_bytes([
+                bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
+            ])),
+            PropertyValue::U64(val) => Some(*val),
+            _ => None,
+        })
+    }
+
     /// Get the number of address cells for this node.
     ///
     /// Returns the value of the `#address-cells` property, which specifies how many
@@ -729,13 +1492,13 @@ impl<'a> DeviceTreeNode<'a> {
         }
 
         // Then check parent node
-        if let Some(parent_node) = parent {
-            if let Some(cells) = parent_node.prop_u32("#address-cells") {
-                if cells == 0 || cells > AddressSpec::MAX_ADDRESS_CELLS {
-                    return Err(DtbError::InvalidAddressCells(cells));
-                }
-                return Ok(cells);
+        if let Some(parent_node) = parent
+            && let Some(cells) = parent_node.prop_u32("#address-cells")
+        {
+            if cells == 0 || cells > AddressSpec::MAX_ADDRESS_CELLS {
+                return Err(DtbError::InvalidAddressCells(cells));
             }
+            return Ok(cells);
         }
 
         // Default fallback
@@ -780,13 +1543,13 @@ impl<'a> DeviceTreeNode<'a> {
         }
 
         // Then check parent node
-        if let Some(parent_node) = parent {
-            if let Some(cells) = parent_node.prop_u32("#size-cells") {
-                if cells > AddressSpec::MAX_SIZE_CELLS {
-                    return Err(DtbError::InvalidSizeCells(cells));
-                }
-                return Ok(cells);
+        if let Some(parent_node) = parent
+            && let Some(cells) = parent_node.prop_u32("#size-cells")
+        {
+            if cells > AddressSpec::MAX_SIZE_CELLS {
+                return Err(DtbError::InvalidSizeCells(cells));
             }
+            return Ok(cells);
         }
 
         // Default fallback
@@ -842,7 +1605,8 @@ impl<'a> DeviceTreeNode<'a> {
     ///
     /// # Errors
     ///
-    /// Returns `DtbError::InvalidRangesFormat` if the ranges data is malformed.
+    /// Returns `DtbError::InvalidRangesFormat` if the ranges data is malformed
+    /// or `child_address_cells` is zero or above four.
     /// Returns cell validation errors if address/size cell values are invalid.
     ///
     /// # Examples
@@ -869,65 +1633,209 @@ impl<'a> DeviceTreeNode<'a> {
         parent: Option<&DeviceTreeNode<'a>>,
         child_address_cells: u32,
     ) -> Result<Vec<AddressRange>, DtbError> {
+        self.parse_ranges_property("ranges", parent, child_address_cells)
+    }
+
+    /// Classify the `ranges` property as missing, identity, or explicit.
+    ///
+    /// Unlike [`ranges`](Self::ranges), which returns an empty vector both when
+    /// the property is absent and when it is empty, this reports the two cases
+    /// separately.
+    ///
+    /// # Arguments
+    ///
+    /// * `parent` - Optional parent node for cell inheritance
+    /// * `child_address_cells` - Number of cells for child addresses (from this node)
+    ///
+    /// # Errors
+    ///
+    /// Returns `DtbError::InvalidRangesFormat` if the ranges data is malformed.
+    /// Returns cell validation errors if address/size cell values are invalid.
+    pub fn ranges_kind(
+        &self,
+        parent: Option<&DeviceTreeNode<'a>>,
+        child_address_cells: u32,
+    ) -> Result<RangesKind, DtbError> {
+        self.ranges_kind_of("ranges", parent, child_address_cells)
+    }
+
+    /// Parse the `dma-ranges` property to extract DMA address range mappings.
+    ///
+    /// `dma-ranges` has the same layout as `ranges` but describes how bus
+    /// master (DMA) addresses seen by child devices map to the parent address
+    /// space. An empty `dma-ranges` property indicates a 1:1 mapping.
+    ///
+    /// # Arguments
+    ///
+    /// * `parent` - Optional parent node for cell inheritance
+    /// * `child_address_cells` - Number of cells for child addresses (from this node)
+    ///
+    /// # Errors
+    ///
+    /// Returns `DtbError::InvalidRangesFormat` if the dma-ranges data is malformed.
+    /// Returns cell validation errors if address/size cell values are invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeNode, DtbError};
+    /// # fn example(bus: &DeviceTreeNode, parent: Option<&DeviceTreeNode>) -> Result<(), DtbError> {
+    /// for range in bus.dma_ranges(parent, 2)? {
+    ///     println!("DMA window: bus=0x{:x} -> cpu=0x{:x}, size=0x{:x}",
+    ///         range.child_address(), range.parent_address(), range.size());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn dma_ranges(
+        &self,
+        parent: Option<&DeviceTreeNode<'a>>,
+        child_address_cells: u32,
+    ) -> Result<Vec<AddressRange>, DtbError> {
+        self.parse_ranges_property("dma-ranges", parent, child_address_cells)
+    }
+
+    /// Parse a `ranges`-style property by name
+    fn parse_ranges_property(
+        &self,
+        name: &str,
+        parent: Option<&DeviceTreeNode<'a>>,
+        child_address_cells: u32,
+    ) -> Result<Vec<AddressRange>, DtbError> {
+        match self.ranges_kind_of(name, parent, child_address_cells)? {
+            RangesKind::None | RangesKind::Identity => Ok(Vec::new()),
+            RangesKind::Explicit(ranges) => Ok(ranges),
+        }
+    }
+
+    /// Classify a `ranges`-style property by name
+    fn ranges_kind_of(
+        &self,
+        name: &str,
+        parent: Option<&DeviceTreeNode<'a>>,
+        child_address_cells: u32,
+    ) -> Result<RangesKind, DtbError> {
+        self.ranges_kind_with(name, || {
+            // Get address and size cells for parent (for parent address field)
+            Ok((
+                child_address_cells,
+                self.address_cells_with_parent(parent)?,
+                self.size_cells_with_parent(parent)?,
+            ))
+        })
+    }
+
+    /// Classify a `ranges`-style property, decoding explicit entries with the
+    /// `(child address, parent address, size)` cell counts returned by `cells`
+    fn ranges_kind_with(
+        &self,
+        name: &str,
+        cells: impl FnOnce() -> Result<(u32, u32, u32), DtbError>,
+    ) -> Result<RangesKind, DtbError> {
         // Get the raw ranges property data
+        let ranges_data = match self.find_property(name) {
+            Some(prop) => match &prop.value {
+                PropertyValue::Bytes(data) | PropertyValue::U32Array(data) => *data,
+                // Empty ranges property means 1:1 mapping
+                PropertyValue::Empty => return Ok(RangesKind::Identity),
+                _ => return Err(DtbError::InvalidRangesFormat),
+            },
+            // No ranges property means this node doesn't provide address translation
+            None => return Ok(RangesKind::None),
+        };
+
+        let (child_address_cells, parent_address_cells, size_cells) = cells()?;
+        decode_ranges(
+            ranges_data,
+            child_address_cells,
+            parent_address_cells,
+            size_cells,
+        )
+        .map(RangesKind::Explicit)
+    }
+
+    /// Parse the `ranges` property of a PCI bus node.
+    ///
+    /// PCI bus nodes use a 3-cell child address whose high cell encodes the
+    /// address space, bus/device/function, and prefetch flags. Each entry is
+    /// decoded into a [`PciRange`] with the child address split into its fields.
+    ///
+    /// The size field uses this node's `#size-cells`, and the parent address
+    /// uses `parent`'s `#address-cells` (2 when there is no parent).
+    ///
+    /// # Arguments
+    ///
+    /// * `parent` - Parent node, whose `#address-cells` sizes the parent address
+    ///
+    /// # Errors
+    ///
+    /// Returns `DtbError::InvalidRangesFormat` if the ranges data is malformed,
+    /// this node's `#address-cells` is not 3, or `#size-cells` is zero.
+    /// Returns cell validation errors if the address or size cells are invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeNode, DtbError, PciSpace};
+    /// # fn example(pci_node: &DeviceTreeNode, root: &DeviceTreeNode) -> Result<(), DtbError> {
+    /// for range in pci_node.pci_ranges(Some(root))? {
+    ///     println!("{:?} window: pci=0x{:x} -> cpu=0x{:x}, size=0x{:x}",
+    ///         range.child.space, range.child.address, range.parent_address, range.size);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn pci_ranges(
+        &self,
+        parent: Option<&DeviceTreeNode<'a>>,
+    ) -> Result<Vec<PciRange>, DtbError> {
         let ranges_data = match self.find_property("ranges") {
             Some(prop) => match &prop.value {
                 PropertyValue::Bytes(data) | PropertyValue::U32Array(data) => *data,
-                PropertyValue::Empty => {
-                    // Empty ranges property means 1:1 mapping
-                    return Ok(Vec::new());
-                }
+                PropertyValue::Empty => return Ok(Vec::new()),
                 _ => return Err(DtbError::InvalidRangesFormat),
             },
-            None => {
-                // No ranges property means this node doesn't provide address translation
-                return Ok(Vec::new());
-            }
+            None => return Ok(Vec::new()),
         };
 
-        // Get address and size cells for parent (for parent address field)
-        let parent_address_cells = self.address_cells_with_parent(parent)?;
-        let parent_size_cells = self.size_cells_with_parent(parent)?;
+        if self.address_cells()? != PciAddress::CELLS {
+            return Err(DtbError::InvalidRangesFormat);
+        }
+        let parent_address_cells = match parent {
+            Some(parent) => parent.address_cells()?,
+            None => AddressSpec::DEFAULT_ADDRESS_CELLS,
+        };
+        let size_cells = self.size_cells()?;
+        if size_cells == 0 {
+            return Err(DtbError::InvalidRangesFormat);
+        }
 
-        // Calculate the size of each range entry in bytes
-        let child_addr_bytes = (child_address_cells * 4) as usize;
+        let child_addr_bytes = (PciAddress::CELLS * 4) as usize;
         let parent_addr_bytes = (parent_address_cells * 4) as usize;
-        let size_bytes = (parent_size_cells * 4) as usize;
+        let size_bytes = (size_cells * 4) as usize;
         let entry_size = child_addr_bytes + parent_addr_bytes + size_bytes;
 
-        // Validate that the data size is a multiple of entry size
         if ranges_data.len() % entry_size != 0 {
             return Err(DtbError::InvalidRangesFormat);
         }
 
         let mut ranges = Vec::new();
-        let mut offset = 0;
-
-        while offset + entry_size <= ranges_data.len() {
-            // Parse child address
-            let child_address = parse_address_from_bytes(
-                &ranges_data[offset..offset + child_addr_bytes],
-                child_address_cells,
-            )?;
-            offset += child_addr_bytes;
-
-            // Parse parent address
-            let parent_address = parse_address_from_bytes(
-                &ranges_data[offset..offset + parent_addr_bytes],
-                parent_address_cells,
-            )?;
-            offset += parent_addr_bytes;
-
-            // Parse size
-            let size = parse_address_from_bytes(
-                &ranges_data[offset..offset + size_bytes],
-                parent_size_cells,
-            )?;
-            offset += size_bytes;
-
-            // Create and validate the address range
-            let range = AddressRange::new(child_address, parent_address, size)?;
-            ranges.push(range);
+        for entry in ranges_data.chunks_exact(entry_size) {
+            let (child_bytes, rest) = entry.split_at(child_addr_bytes);
+            let (parent_bytes, size_bytes) = rest.split_at(parent_addr_bytes);
+
+            let child = PciAddress::from_u128(parse_address_from_bytes_full(
+                child_bytes,
+                PciAddress::CELLS,
+            )?);
+            let parent_address = parse_address_from_bytes(parent_bytes, parent_address_cells)?;
+            let size = parse_address_from_bytes(size_bytes, size_cells)?;
+
+            ranges.push(PciRange {
+                child,
+                parent_address,
+                size,
+            });
         }
 
         Ok(ranges)
@@ -973,97 +1881,185 @@ impl<'a> DeviceTreeNode<'a> {
         parent: Option<&DeviceTreeNode<'a>>,
         child_address_cells: u32,
     ) -> Result<u64, DtbError> {
-        // Get the ranges for this node
-        let ranges = self.ranges(parent, child_address_cells)?;
-
-        // If ranges is empty, this could mean:
-        // 1. Empty ranges property (1:1 mapping) - translate directly
-        // 2. No ranges property - no translation capability
-        if ranges.is_empty() {
-            // Check if ranges property exists but is empty (1:1 mapping)
-            if self.has_property("ranges") {
-                // Empty ranges property means 1:1 address mapping
-                return Ok(child_address);
-            }
-            // No ranges property means this node doesn't provide translation
-            return Err(DtbError::AddressTranslationError(child_address));
-        }
-
-        // Find the range that contains the child address
-        for range in &ranges {
-            if range.contains(child_address) {
-                return range.translate(child_address);
-            }
-        }
-
-        // No matching range found
-        Err(DtbError::AddressTranslationError(child_address))
+        self.translate_with_property("ranges", child_address, parent, child_address_cells)
     }
 
-    /// Translate an address through multiple levels of the device tree hierarchy.
+    /// Translate a parent address back to this node's child address space.
     ///
-    /// This method performs recursive address translation by walking up the device tree
-    /// from the current node to the root, applying address translations at each level.
-    /// It includes cycle detection and depth limits to prevent infinite recursion.
+    /// Inverse of [`translate_address`](Self::translate_address): searches this
+    /// node's `ranges` for an entry whose parent window contains the address.
+    /// An empty `ranges` property maps 1:1.
     ///
     /// # Arguments
     ///
-    /// * `child_address` - Address in this node's address space to translate
+    /// * `parent_address` - Address in the parent's address space
+    /// * `parent` - Optional parent node for cell inheritance
     /// * `child_address_cells` - Number of cells for child addresses
-    /// * `max_depth` - Maximum recursion depth (typically 10)
     ///
     /// # Errors
     ///
-    /// Returns `DtbError::TranslationCycle` if a circular reference is detected.
-    /// Returns `DtbError::MaxTranslationDepthExceeded` if recursion exceeds max_depth.
-    /// Returns other translation errors for individual translation failures.
+    /// Returns `DtbError::AddressTranslationError` if the node has no `ranges`
+    /// property or no range contains the address.
+    ///
+    /// Returns other errors for cell validation or ranges parsing failures.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use device_tree_parser::{DeviceTreeNode, DtbError};
-    /// # fn example(device_node: &DeviceTreeNode) -> Result<(), DtbError> {
-    /// // Translate address through complete bus hierarchy to CPU address space
-    /// let cpu_addr = device_node.translate_address_recursive(0x1000, 2, 10)?;
-    /// println!("Device address 0x1000 maps to CPU address 0x{:x}", cpu_addr);
+    /// # fn example(bus_node: &DeviceTreeNode, parent: Option<&DeviceTreeNode>) -> Result<(), DtbError> {
+    /// let child_addr = bus_node.untranslate_address(0x8000_1000, parent, 2)?;
+    /// println!("CPU address 0x80001000 is bus address 0x{:x}", child_addr);
     /// # Ok(())
     /// # }
     /// ```
-    pub fn translate_address_recursive(
+    pub fn untranslate_address(
         &self,
-        child_address: u64,
+        parent_address: u64,
+        parent: Option<&DeviceTreeNode<'a>>,
         child_address_cells: u32,
-        max_depth: u32,
     ) -> Result<u64, DtbError> {
-        self.translate_address_recursive_internal(
-            child_address,
-            child_address_cells,
-            max_depth,
-            &mut Vec::new(),
-            0,
-        )
+        self.ranges_kind(parent, child_address_cells)?
+            .translate_reverse(parent_address)
     }
 
-    /// Internal implementation of recursive address translation with cycle detection.
+    /// Translate a child DMA address to the parent address space.
     ///
-    /// This method maintains a visited nodes list to detect cycles and tracks
-    /// recursion depth to prevent stack overflow.
-    fn translate_address_recursive_internal(
-        &self,
-        mut current_address: u64,
-        child_address_cells: u32,
-        max_depth: u32,
-        visited_nodes: &mut Vec<*const DeviceTreeNode<'a>>,
-        current_depth: u32,
-    ) -> Result<u64, DtbError> {
-        // Check recursion depth limit
-        if current_depth >= max_depth {
-            return Err(DtbError::MaxTranslationDepthExceeded);
-        }
-
-        // Check for cycles using pointer comparison
-        let self_ptr = self as *const DeviceTreeNode<'a>;
-        if visited_nodes.contains(&self_ptr) {
+    /// Single-level translation through this node's `dma-ranges` property,
+    /// following the same rules as [`translate_address`](Self::translate_address):
+    /// an empty `dma-ranges` maps 1:1, and a missing one provides no translation.
+    ///
+    /// # Arguments
+    ///
+    /// * `child_address` - DMA address in this node's address space to translate
+    /// * `parent` - Optional parent node for cell inheritance
+    /// * `child_address_cells` - Number of cells for child addresses
+    ///
+    /// # Errors
+    ///
+    /// Returns `DtbError::AddressTranslationError` if the node has no
+    /// `dma-ranges` property or no range contains the address.
+    ///
+    /// Returns other errors for cell validation or dma-ranges parsing failures.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeNode, DtbError};
+    /// # fn example(bus_node: &DeviceTreeNode, parent: Option<&DeviceTreeNode>) -> Result<(), DtbError> {
+    /// let cpu_addr = bus_node.translate_dma_address(0x8000_0000, parent, 2)?;
+    /// println!("Bus DMA address 0x80000000 maps to 0x{:x}", cpu_addr);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn translate_dma_address(
+        &self,
+        child_address: u64,
+        parent: Option<&DeviceTreeNode<'a>>,
+        child_address_cells: u32,
+    ) -> Result<u64, DtbError> {
+        self.translate_with_property("dma-ranges", child_address, parent, child_address_cells)
+    }
+
+    /// Translate an address through a `ranges`-style property by name
+    fn translate_with_property(
+        &self,
+        name: &str,
+        child_address: u64,
+        parent: Option<&DeviceTreeNode<'a>>,
+        child_address_cells: u32,
+    ) -> Result<u64, DtbError> {
+        self.ranges_kind_of(name, parent, child_address_cells)?
+            .translate(child_address)
+    }
+
+    /// Translate an address from this bus node's child address space to the
+    /// address space of `parent`.
+    ///
+    /// Uses the cell counts the specification prescribes for `ranges`: this
+    /// node's `#address-cells` and `#size-cells` for the child address and
+    /// length, and `parent`'s `#address-cells` for the parent address.
+    pub(crate) fn translate_to_parent(
+        &self,
+        address: u64,
+        parent: &DeviceTreeNode<'a>,
+    ) -> Result<u64, DtbError> {
+        self.ranges_kind_with("ranges", || {
+            Ok((
+                self.address_cells()?,
+                parent.address_cells()?,
+                self.size_cells()?,
+            ))
+        })?
+        .translate(address)
+    }
+
+    /// Translate an address through this node's `ranges` property.
+    ///
+    /// Nodes do not hold parent references, so this applies only this node's
+    /// own `ranges`, returning the address unchanged if there is none. It
+    /// still enforces `max_depth` and detects cycles. To translate through
+    /// every ancestor bus up to the root, use
+    /// [`DeviceTreeParser::translate_address`](crate::DeviceTreeParser::translate_address).
+    ///
+    /// # Arguments
+    ///
+    /// * `child_address` - Address in this node's address space to translate
+    /// * `child_address_cells` - Number of cells for child addresses
+    /// * `max_depth` - Maximum recursion depth (typically 10)
+    ///
+    /// # Errors
+    ///
+    /// Returns `DtbError::TranslationCycle` if a circular reference is detected.
+    /// Returns `DtbError::MaxTranslationDepthExceeded` if recursion exceeds max_depth.
+    /// Returns other translation errors for individual translation failures.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeNode, DtbError};
+    /// # fn example(device_node: &DeviceTreeNode) -> Result<(), DtbError> {
+    /// // Translate address through this bus node's ranges
+    /// let parent_addr = device_node.translate_address_recursive(0x1000, 2, 10)?;
+    /// println!("Device address 0x1000 maps to parent address 0x{:x}", parent_addr);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn translate_address_recursive(
+        &self,
+        child_address: u64,
+        child_address_cells: u32,
+        max_depth: u32,
+    ) -> Result<u64, DtbError> {
+        self.translate_address_recursive_internal(
+            child_address,
+            child_address_cells,
+            max_depth,
+            &mut Vec::new(),
+            0,
+        )
+    }
+
+    /// Internal implementation of recursive address translation with cycle detection.
+    ///
+    /// This method maintains a visited nodes list to detect cycles and tracks
+    /// recursion depth to prevent stack overflow.
+    fn translate_address_recursive_internal(
+        &self,
+        mut current_address: u64,
+        child_address_cells: u32,
+        max_depth: u32,
+        visited_nodes: &mut Vec<*const DeviceTreeNode<'a>>,
+        current_depth: u32,
+    ) -> Result<u64, DtbError> {
+        // Check recursion depth limit
+        if current_depth >= max_depth {
+            return Err(DtbError::MaxTranslationDepthExceeded);
+        }
+
+        // Check for cycles using pointer comparison
+        let self_ptr = self as *const DeviceTreeNode<'a>;
+        if visited_nodes.contains(&self_ptr) {
             return Err(DtbError::TranslationCycle);
         }
         visited_nodes.push(self_ptr);
@@ -1096,14 +2092,13 @@ impl<'a> DeviceTreeNode<'a> {
             }
             Err(DtbError::AddressTranslationError(_)) => {
                 // If translation fails and we have empty ranges (1:1 mapping)
-                if self.has_property("ranges") {
-                    if let Some(ranges_prop) = self.find_property("ranges") {
-                        if matches!(ranges_prop.value, PropertyValue::Empty) {
-                            // Empty ranges means 1:1 mapping, continue to parent
-                            visited_nodes.pop();
-                            return Ok(current_address);
-                        }
-                    }
+                if self.has_property("ranges")
+                    && let Some(ranges_prop) = self.find_property("ranges")
+                    && matches!(ranges_prop.value, PropertyValue::Empty)
+                {
+                    // Empty ranges means 1:1 mapping, continue to parent
+                    visited_nodes.pop();
+                    return Ok(current_address);
                 }
                 visited_nodes.pop();
                 Err(DtbError::AddressTranslationError(current_address))
@@ -1180,6 +2175,129 @@ impl<'a> DeviceTreeNode<'a> {
         Ok(addresses)
     }
 
+    /// Get a single `(address, size)` entry from the `reg` property.
+    ///
+    /// Decodes only the requested entry, using the parent's `#address-cells`
+    /// and `#size-cells` (or the specification defaults of 2 and 1 when
+    /// `parent` is `None`). Addresses wider than 64 bits keep their low 64
+    /// bits. The address is returned untranslated.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Zero-based entry index
+    /// * `parent` - Parent node defining the cell counts for this node's `reg`
+    ///
+    /// # Errors
+    ///
+    /// Returns `DtbError::InvalidRegFormat` if `reg` is not cell data, or cell
+    /// validation errors if the parent's cell counts are invalid.
+    ///
+    /// # Returns
+    ///
+    /// Returns `None` if the node has no `reg` property or `index` is past the
+    /// last complete entry.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeNode, DtbError};
+    /// # fn example(device: &DeviceTreeNode, bus: &DeviceTreeNode) -> Result<(), DtbError> {
+    /// if let Some((base, size)) = device.reg_index(0, Some(bus))? {
+    ///     println!("First region: 0x{:x} (size: 0x{:x})", base, size);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn reg_index(
+        &self,
+        index: usize,
+        parent: Option<&DeviceTreeNode<'a>>,
+    ) -> Result<Option<(u64, u64)>, DtbError> {
+        let Some(reg) = self.find_property("reg") else {
+            return Ok(None);
+        };
+        let single;
+        let data: &[u8] = match &reg.value {
+            PropertyValue::U32(val) => {
+                single = val.to_be_bytes();
+                &single
+            }
+            PropertyValue::U32Array(bytes) => bytes,
+            _ => return Err(DtbError::InvalidRegFormat),
+        };
+
+        let (address_cells, size_cells) = match parent {
+            Some(parent) => (parent.address_cells()?, parent.size_cells()?),
+            None => (
+                AddressSpec::DEFAULT_ADDRESS_CELLS,
+                AddressSpec::DEFAULT_SIZE_CELLS,
+            ),
+        };
+        let address_bytes = address_cells as usize * 4;
+        let entry_size = address_bytes + size_cells as usize * 4;
+
+        let Some(entry) = index
+            .checked_mul(entry_size)
+            .and_then(|start| data.get(start..start.checked_add(entry_size)?))
+        else {
+            return Ok(None);
+        };
+
+        let read_cells = |bytes: &[u8]| {
+            bytes.chunks_exact(4).fold(0u64, |value, chunk| {
+                (value << 32)
+                    | u64::from(u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+            })
+        };
+        let (address, size) = entry.split_at(address_bytes);
+        Ok(Some((read_cells(address), read_cells(size))))
+    }
+
+    /// Check that the `reg` property is a whole number of entries.
+    ///
+    /// Each entry is the parent's `#address-cells` plus `#size-cells` cells
+    /// (the specification defaults of 2 and 1 when `parent` is `None`), so the
+    /// value's length must be a multiple of that many 4-byte cells. A node
+    /// without `reg` passes. The value's stored bytes are checked, so a `reg`
+    /// the heuristic misread as a string is measured correctly.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DtbError::InvalidRegFormat` if the length is not a whole
+    /// number of entries, or cell validation errors if the parent's cell
+    /// counts are invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeNode, DtbError};
+    /// # fn example(device: &DeviceTreeNode, bus: &DeviceTreeNode) -> Result<(), DtbError> {
+    /// device.validate_reg(Some(bus))?;
+    /// let regions = device.translate_reg_addresses(Some(bus))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn validate_reg(&self, parent: Option<&DeviceTreeNode<'a>>) -> Result<(), DtbError> {
+        let Some(reg) = self.find_property("reg") else {
+            return Ok(());
+        };
+        let (address_cells, size_cells) = match parent {
+            Some(parent) => (parent.address_cells()?, parent.size_cells()?),
+            None => (
+                AddressSpec::DEFAULT_ADDRESS_CELLS,
+                AddressSpec::DEFAULT_SIZE_CELLS,
+            ),
+        };
+        let entry_size = (address_cells + size_cells) as usize * 4;
+        let len = reg
+            .raw
+            .map_or_else(|| reg.value.encode().len(), <[u8]>::len);
+        if !len.is_multiple_of(entry_size) {
+            return Err(DtbError::InvalidRegFormat);
+        }
+        Ok(())
+    }
+
     /// Get memory-mapped I/O regions for this device with address translation.
     ///
     /// Convenience method that combines register address parsing and translation
@@ -1231,6 +2349,115 @@ impl<'a> DeviceTreeNode<'a> {
         }
     }
 
+    /// Iterate over every occurrence of a property in the tree, together with
+    /// the node that owns it (depth-first order).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::DeviceTreeNode;
+    /// # fn example(root: &DeviceTreeNode) {
+    /// for (node, prop) in root.iter_property("interrupts") {
+    ///     println!("{}: {}", node.name, prop.value);
+    /// }
+    /// # }
+    /// ```
+    pub fn iter_property<'b>(
+        &'b self,
+        name: &str,
+    ) -> impl Iterator<Item = (&'b DeviceTreeNode<'a>, &'b Property<'a>)> {
+        self.iter_nodes()
+            .filter_map(move |node| node.find_property(name).map(|prop| (node, prop)))
+    }
+
+    /// Get the operational status from the `status` property.
+    ///
+    /// Defaults to [`Status::Okay`] when the property is absent.
+    #[must_use]
+    pub fn status(&self) -> Status<'a> {
+        match self.find_property("status").map(|p| &p.value) {
+            None => Status::Okay,
+            Some(PropertyValue::String(s)) => match *s {
+                "okay" | "ok" => Status::Okay,
+                "disabled" => Status::Disabled,
+                "reserved" => Status::Reserved,
+                s if s == "fail" || s.starts_with("fail-") => Status::Fail,
+                s => Status::Unknown(s),
+            },
+            Some(_) => Status::Unknown(""),
+        }
+    }
+
+    /// Check if the node is enabled (status is `okay` or absent)
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        self.status() == Status::Okay
+    }
+
+    /// Get all entries of the `compatible` property.
+    ///
+    /// Returns every compatible string, in most-specific to least-specific
+    /// order, whether the property holds a single string or a string list.
+    /// Returns an empty vector if the node has no `compatible` property.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::DeviceTreeNode;
+    /// # fn example(node: &DeviceTreeNode) {
+    /// for compatible in node.compatible() {
+    ///     println!("Compatible: {}", compatible);
+    /// }
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn compatible(&self) -> Vec<&'a str> {
+        match self.find_property("compatible").map(|p| &p.value) {
+            Some(PropertyValue::String(s)) => vec![*s],
+            Some(PropertyValue::StringList(list)) => list.clone(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Check if any entry of the `compatible` property matches `compatible`
+    #[must_use]
+    pub fn is_compatible(&self, compatible: &str) -> bool {
+        match self.find_property("compatible").map(|p| &p.value) {
+            Some(PropertyValue::String(s)) => *s == compatible,
+            Some(PropertyValue::StringList(list)) => list.contains(&compatible),
+            _ => false,
+        }
+    }
+
+    /// Pick the most specific `compatible` entry supported by a driver.
+    ///
+    /// Compatible entries are listed from most to least specific, so the
+    /// earliest entry that appears in `candidates` wins, regardless of the
+    /// order of `candidates`. This mirrors how Linux matches drivers. Returns
+    /// the entry's position in the `compatible` list together with the entry.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeNode, Property, PropertyValue};
+    /// let mut uart = DeviceTreeNode::new("serial@1000");
+    /// uart.add_property(Property {
+    ///     name: "compatible",
+    ///     value: PropertyValue::StringList(vec!["vendor,soc-uart", "ns16550a"]),
+    ///     raw: None,
+    /// });
+    ///
+    /// let supported = ["ns16550a", "vendor,soc-uart"];
+    /// assert_eq!(uart.best_compatible(&supported), Some((0, "vendor,soc-uart")));
+    /// ```
+    #[must_use]
+    pub fn best_compatible(&self, candidates: &[&str]) -> Option<(usize, &'a str)> {
+        self.compatible()
+            .into_iter()
+            .enumerate()
+            .find(|(_, entry)| candidates.contains(entry))
+    }
+
     /// Get all nodes with a specific compatible string
     #[must_use]
     pub fn find_compatible_nodes(&self, compatible: &str) -> Vec<&DeviceTreeNode<'a>> {
@@ -1244,13 +2471,50 @@ impl<'a> DeviceTreeNode<'a> {
         &'b self,
         compatible: &str,
         nodes: &mut Vec<&'b DeviceTreeNode<'a>>,
+    ) {
+        if self.is_compatible(compatible) {
+            nodes.push(self);
+        }
+
+        for child in &self.children {
+            child.collect_compatible_nodes(compatible, nodes);
+        }
+    }
+
+    /// Get all nodes with a compatible string starting with `prefix`.
+    ///
+    /// Matches any entry of the `compatible` property, so a node compatible with
+    /// `["arm,pl011", "arm,primecell"]` is found by the prefix `"arm,"`. Useful for
+    /// finding all peripherals from a given vendor.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::DeviceTreeNode;
+    /// # fn example(root: &DeviceTreeNode) {
+    /// let arm_devices = root.find_compatible_prefix("arm,");
+    /// println!("Found {} ARM peripherals", arm_devices.len());
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn find_compatible_prefix(&self, prefix: &str) -> Vec<&DeviceTreeNode<'a>> {
+        let mut nodes = Vec::new();
+        self.collect_compatible_prefix_nodes(prefix, &mut nodes);
+        nodes
+    }
+
+    /// Recursively collect nodes with a compatible string starting with a prefix
+    fn collect_compatible_prefix_nodes<'b>(
+        &'b self,
+        prefix: &str,
+        nodes: &mut Vec<&'b DeviceTreeNode<'a>>,
     ) {
         if let Some(compat_prop) = self.find_property("compatible") {
             match &compat_prop.value {
-                PropertyValue::String(s) if *s == compatible => {
+                PropertyValue::String(s) if s.starts_with(prefix) => {
                     nodes.push(self);
                 }
-                PropertyValue::StringList(list) if list.contains(&compatible) => {
+                PropertyValue::StringList(list) if list.iter().any(|s| s.starts_with(prefix)) => {
                     nodes.push(self);
                 }
                 _ => {}
@@ -1258,7 +2522,7 @@ impl<'a> DeviceTreeNode<'a> {
         }
 
         for child in &self.children {
-            child.collect_compatible_nodes(compatible, nodes);
+            child.collect_compatible_prefix_nodes(prefix, nodes);
         }
     }
 
@@ -1268,37 +2532,190 @@ impl<'a> DeviceTreeNode<'a> {
         NodeIterator::new(self)
     }
 
-    /// Get iterator over all properties
-    pub fn iter_properties(&self) -> core::slice::Iter<'_, Property<'a>> {
-        self.properties.iter()
-    }
-
-    /// Get iterator over child nodes
-    pub fn iter_children(&self) -> core::slice::Iter<'_, DeviceTreeNode<'a>> {
-        self.children.iter()
+    /// Get iterator over all nodes (breadth-first traversal)
+    ///
+    /// Yields this node, then its children, then its grandchildren, and so
+    /// on, with siblings in tree order.
+    pub fn iter_nodes_bfs(&self) -> impl Iterator<Item = &DeviceTreeNode<'a>> {
+        BfsNodeIterator {
+            queue: VecDeque::from([self]),
+        }
     }
-}
-
-// Trait implementations for better UX
 
-/// Index trait for property access by name
-impl<'a> Index<&str> for DeviceTreeNode<'a> {
-    type Output = Property<'a>;
-
-    fn index(&self, property_name: &str) -> &Self::Output {
-        self.find_property(property_name)
-            .unwrap_or_else(|| panic!("Property '{property_name}' not found"))
+    /// Get iterator over the bus bridge nodes in this subtree (depth-first)
+    ///
+    /// Yields every node, this one included, that has a `ranges` property,
+    /// empty or not. These are the nodes that map their children's addresses
+    /// into the parent address space, the steps of a translation map.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::DeviceTreeNode;
+    /// # fn example(root: &DeviceTreeNode) {
+    /// for bus in root.iter_bus_nodes() {
+    ///     println!("{} bridges {} children", bus.name, bus.children.len());
+    /// }
+    /// # }
+    /// ```
+    pub fn iter_bus_nodes(&self) -> impl Iterator<Item = &DeviceTreeNode<'a>> {
+        self.iter_nodes().filter(|node| node.has_property("ranges"))
     }
-}
 
-/// Index trait for child access by index
-impl<'a> Index<usize> for DeviceTreeNode<'a> {
-    type Output = DeviceTreeNode<'a>;
-
-    fn index(&self, index: usize) -> &Self::Output {
-        &self.children[index]
+    /// Get iterator over all nodes with their full paths (depth-first)
+    ///
+    /// Yields `(path, node)` pairs in the same order as
+    /// [`iter_nodes`](Self::iter_nodes). This node is treated as the root and
+    /// yields `/`; descendants yield paths such as `/soc/uart@1000`. Paths are
+    /// built incrementally from a stack of name segments in a single pass.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::DeviceTreeNode;
+    /// # fn example(root: &DeviceTreeNode) {
+    /// for (path, node) in root.iter_paths() {
+    ///     println!("{}: {} properties", path, node.properties.len());
+    /// }
+    /// # }
+    /// ```
+    pub fn iter_paths(&self) -> impl Iterator<Item = (String, &DeviceTreeNode<'a>)> {
+        PathIterator {
+            stack: vec![(self, 0)],
+            segments: Vec::new(),
+        }
     }
-}
+
+    /// Get iterator over all nodes with their parents (depth-first)
+    ///
+    /// Yields `(parent, node)` pairs in the same order as
+    /// [`iter_nodes`](Self::iter_nodes). This node is treated as the root
+    /// and yields `None` as its parent. The parent is what
+    /// [`address_cells_with_parent`](Self::address_cells_with_parent) and
+    /// [`size_cells_with_parent`](Self::size_cells_with_parent) expect.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::{DeviceTreeNode, DtbError};
+    /// # fn example(root: &DeviceTreeNode) -> Result<(), DtbError> {
+    /// for (parent, node) in root.iter_with_parent() {
+    ///     let address_cells = node.address_cells_with_parent(parent)?;
+    ///     println!("{}: {} address cells", node.name, address_cells);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter_with_parent(
+        &self,
+    ) -> impl Iterator<Item = (Option<&DeviceTreeNode<'a>>, &DeviceTreeNode<'a>)> {
+        ParentIterator {
+            stack: vec![(None, self)],
+        }
+    }
+
+    /// Build an index from full path to node for repeated lookups
+    ///
+    /// Each lookup in the returned map is O(log n), compared with a walk down
+    /// the tree for every [`find_node`](Self::find_node) call. Keys are the
+    /// paths produced by [`iter_paths`](Self::iter_paths), so they must be
+    /// exact: unit addresses are not optional (`/cpus/cpu@0`, not
+    /// `/cpus/cpu`) and there is no trailing `/`.
+    ///
+    /// The index borrows the tree, so the tree cannot be modified or dropped
+    /// while the index is alive. Rebuild the index after changing the tree.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::DeviceTreeNode;
+    /// # fn example(root: &DeviceTreeNode) {
+    /// let index = root.build_path_index();
+    /// if let Some(uart) = index.get("/soc/uart@1000") {
+    ///     println!("Found {}", uart.name);
+    /// }
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn build_path_index(&self) -> BTreeMap<String, &DeviceTreeNode<'a>> {
+        self.iter_paths().collect()
+    }
+
+    /// Get iterator over all properties
+    pub fn iter_properties(&self) -> core::slice::Iter<'_, Property<'a>> {
+        self.properties.iter()
+    }
+
+    /// Get iterator over child nodes
+    pub fn iter_children(&self) -> core::slice::Iter<'_, DeviceTreeNode<'a>> {
+        self.children.iter()
+    }
+
+    /// Get direct children for which `pred` returns true
+    ///
+    /// Only immediate children are tested; use
+    /// [`iter_nodes`](Self::iter_nodes) to search the whole subtree.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use device_tree_parser::DeviceTreeNode;
+    /// # fn example(cpus: &DeviceTreeNode) {
+    /// for cpu in cpus.children_where(|n| n.prop_string("device_type") == Some("cpu")) {
+    ///     println!("CPU node {}", cpu.name);
+    /// }
+    /// # }
+    /// ```
+    pub fn children_where<F>(&self, pred: F) -> impl Iterator<Item = &DeviceTreeNode<'a>>
+    where
+        F: Fn(&DeviceTreeNode<'a>) -> bool,
+    {
+        self.children.iter().filter(move |child| pred(child))
+    }
+
+    /// Get child nodes ordered by unit address.
+    ///
+    /// Children without a parseable unit address come first, ordered by name;
+    /// the rest follow in ascending address order, with ties broken by name.
+    /// Useful for producing stable output regardless of DTB node order.
+    #[must_use]
+    pub fn children_sorted_by_address(&self) -> Vec<&DeviceTreeNode<'a>> {
+        let mut children: Vec<&DeviceTreeNode<'a>> = self.children.iter().collect();
+        children.sort_by_key(|child| (child.unit_address(), child.name));
+        children
+    }
+}
+
+// Trait implementations for better UX
+
+/// Index trait for property access by name
+impl<'a> Index<&str> for DeviceTreeNode<'a> {
+    type Output = Property<'a>;
+
+    fn index(&self, property_name: &str) -> &Self::Output {
+        self.find_property(property_name)
+            .unwrap_or_else(|| panic!("Property '{property_name}' not found"))
+    }
+}
+
+/// `IndexMut` trait for mutable property access by name
+impl IndexMut<&str> for DeviceTreeNode<'_> {
+    fn index_mut(&mut self, property_name: &str) -> &mut Self::Output {
+        self.properties
+            .iter_mut()
+            .find(|p| p.name == property_name)
+            .unwrap_or_else(|| panic!("Property '{property_name}' not found"))
+    }
+}
+
+/// Index trait for child access by index
+impl<'a> Index<usize> for DeviceTreeNode<'a> {
+    type Output = DeviceTreeNode<'a>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.children[index]
+    }
+}
 
 /// `IntoIterator` trait for iterating over child nodes
 impl<'a> IntoIterator for &'a DeviceTreeNode<'a> {
@@ -1488,6 +2905,72 @@ impl<'a> TryFrom<&PropertyValue<'a>> for Vec<u32> {
     }
 }
 
+/// `TryFrom` trait for converting `PropertyValue` to a fixed-size `[u32; N]`
+///
+/// Succeeds only when the value holds exactly `N` cells.
+impl<'a, const N: usize> TryFrom<&PropertyValue<'a>> for [u32; N] {
+    type Error = DtbError;
+
+    fn try_from(value: &PropertyValue<'a>) -> Result<Self, Self::Error> {
+        match value {
+            PropertyValue::U32Array(bytes) if bytes.len() == N * 4 => {
+                let mut values = [0u32; N];
+                for (value, chunk) in values.iter_mut().zip(bytes.chunks_exact(4)) {
+                    *value = u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+                }
+                Ok(values)
+            }
+            PropertyValue::U32(val) if N == 1 => Ok([*val; N]),
+            _ => Err(DtbError::InvalidToken),
+        }
+    }
+}
+
+/// `TryFrom` trait for converting `PropertyValue` to `Vec<u64>`
+impl<'a> TryFrom<&PropertyValue<'a>> for Vec<u64> {
+    type Error = DtbError;
+
+    fn try_from(value: &PropertyValue<'a>) -> Result<Self, Self::Error> {
+        match value {
+            PropertyValue::U64Array(bytes) => {
+                let mut values = Vec::new();
+                for chunk in bytes.chunks_exact(8) {
+                    values.push(u64::from_be_bytes([
+                        chunk[0], chunk[1], chunk[2], chunk[3], chunk[4], chunk[5], chunk[6],
+                        chunk[7],
+                    ]));
+                }
+                Ok(values)
+            }
+            PropertyValue::U64(val) => Ok(vec![*val]),
+            PropertyValue::U32Array(bytes) => {
+                let mut values = Vec::new();
+                for chunk in bytes.chunks_exact(4) {
+                    let val = u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+                    values.push(u64::from(val));
+                }
+                Ok(values)
+            }
+            PropertyValue::U32(val) => Ok(vec![u64::from(*val)]),
+            _ => Err(DtbError::InvalidToken),
+        }
+    }
+}
+
+/// `TryFrom` trait for converting `PropertyValue` to bool
+///
+/// Boolean properties carry no value, so only `Empty` converts (to `true`).
+impl<'a> TryFrom<&PropertyValue<'a>> for bool {
+    type Error = DtbError;
+
+    fn try_from(value: &PropertyValue<'a>) -> Result<Self, Self::Error> {
+        match value {
+            PropertyValue::Empty => Ok(true),
+            _ => Err(DtbError::InvalidToken),
+        }
+    }
+}
+
 /// `TryFrom` trait for converting `PropertyValue` to &[u8]
 impl<'a> TryFrom<&PropertyValue<'a>> for &'a [u8] {
     type Error = DtbError;
@@ -1529,6 +3012,68 @@ impl<'a, 'b> Iterator for NodeIterator<'a, 'b> {
     }
 }
 
+/// Iterator for breadth-first traversal of device tree nodes
+struct BfsNodeIterator<'a, 'b> {
+    queue: VecDeque<&'b DeviceTreeNode<'a>>,
+}
+
+impl<'a, 'b> Iterator for BfsNodeIterator<'a, 'b> {
+    type Item = &'b DeviceTreeNode<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.queue.pop_front()?;
+        self.queue.extend(&node.children);
+        Some(node)
+    }
+}
+
+/// Iterator for depth-first traversal yielding each node's path
+struct PathIterator<'a, 'b> {
+    /// Nodes still to visit, with their depth below the starting node
+    stack: Vec<(&'b DeviceTreeNode<'a>, usize)>,
+    /// Names of the ancestors of the next node to visit
+    segments: Vec<&'a str>,
+}
+
+impl<'a, 'b> Iterator for PathIterator<'a, 'b> {
+    type Item = (String, &'b DeviceTreeNode<'a>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (node, depth) = self.stack.pop()?;
+        self.stack
+            .extend(node.children.iter().rev().map(|child| (child, depth + 1)));
+
+        if depth == 0 {
+            return Some((String::from("/"), node));
+        }
+        self.segments.truncate(depth - 1);
+        self.segments.push(node.name);
+
+        let mut path = String::new();
+        for segment in &self.segments {
+            path.push('/');
+            path.push_str(segment);
+        }
+        Some((path, node))
+    }
+}
+
+/// Iterator for depth-first traversal yielding each node's parent
+struct ParentIterator<'a, 'b> {
+    stack: Vec<(Option<&'b DeviceTreeNode<'a>>, &'b DeviceTreeNode<'a>)>,
+}
+
+impl<'a, 'b> Iterator for ParentIterator<'a, 'b> {
+    type Item = (Option<&'b DeviceTreeNode<'a>>, &'b DeviceTreeNode<'a>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (parent, node) = self.stack.pop()?;
+        self.stack
+            .extend(node.children.iter().rev().map(|child| (Some(node), child)));
+        Some((parent, node))
+    }
+}
+
 /// Parse a multi-cell address value from big-endian bytes.
 ///
 /// Device tree addresses can be 1-4 cells (4-16 bytes). This function
@@ -1592,20 +3137,154 @@ pub fn parse_address_from_bytes(bytes: &[u8], cells: u32) -> Result<u64, DtbErro
     }
 }
 
+/// Parse a multi-cell address value from big-endian bytes, keeping every cell.
+///
+/// Unlike [`parse_address_from_bytes`], which truncates 3-cell and 4-cell
+/// addresses to their lower 64 bits, this function retains all cells in a
+/// `u128`. This matters for PCI address specifiers, where the high cell
+/// carries the address space and flag bits.
+///
+/// # Arguments
+///
+/// * `bytes` - Raw bytes containing the address (must be 4*cells bytes)
+/// * `cells` - Number of 32-bit cells (1-4)
+///
+/// # Errors
+///
+/// Returns `DtbError::InvalidAddressCells` if cells is not in range 1-4.
+/// Returns `DtbError::MalformedHeader` if bytes length doesn't match cells.
+///
+/// # Examples
+///
+/// ```rust
+/// # use device_tree_parser::DtbError;
+/// # fn example() -> Result<(), DtbError> {
+/// # use device_tree_parser::parse_address_from_bytes_full;
+/// // Parse 3-cell PCI address (12 bytes), high cell is preserved
+/// let bytes = [
+///     0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00,
+/// ];
+/// let addr = parse_address_from_bytes_full(&bytes, 3)?;
+/// assert_eq!(addr, 0x0200_0000_0000_0000_4000_0000);
+/// # Ok(())
+/// # }
+/// ```
+pub fn parse_address_from_bytes_full(bytes: &[u8], cells: u32) -> Result<u128, DtbError> {
+    if cells == 0 || cells > AddressSpec::MAX_ADDRESS_CELLS {
+        return Err(DtbError::InvalidAddressCells(cells));
+    }
+
+    let expected_len = (cells * 4) as usize;
+    if bytes.len() != expected_len {
+        return Err(DtbError::MalformedHeader);
+    }
+
+    let mut address = 0u128;
+    for chunk in bytes.chunks_exact(4) {
+        let cell = u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        address = (address << 32) | u128::from(cell);
+    }
+
+    Ok(address)
+}
+
+/// Decode `ranges`-style data into address ranges
+///
+/// Each entry is a child address, a parent address, and a size, using the
+/// given cell widths. Addresses wider than 64 bits keep their low 64 bits.
+/// Address widths of zero are rejected, since every entry would decode to
+/// the same empty address.
+fn decode_ranges(
+    ranges_data: &[u8],
+    child_address_cells: u32,
+    parent_address_cells: u32,
+    size_cells: u32,
+) -> Result<Vec<AddressRange>, DtbError> {
+    // Zero address cells leave no address to map, and out-of-range counts
+    // would overflow the entry size
+    let address_cells = 1..=AddressSpec::MAX_ADDRESS_CELLS;
+    if !address_cells.contains(&child_address_cells)
+        || !address_cells.contains(&parent_address_cells)
+        || size_cells > AddressSpec::MAX_SIZE_CELLS
+    {
+        return Err(DtbError::InvalidRangesFormat);
+    }
+
+    // Calculate the size of each range entry in bytes
+    let child_addr_bytes = (child_address_cells * 4) as usize;
+    let parent_addr_bytes = (parent_address_cells * 4) as usize;
+    let size_bytes = (size_cells * 4) as usize;
+    let entry_size = child_addr_bytes + parent_addr_bytes + size_bytes;
+
+    // Validate that the data size is a multiple of entry size
+    if !ranges_data.len().is_multiple_of(entry_size) {
+        return Err(DtbError::InvalidRangesFormat);
+    }
+
+    let mut ranges = Vec::new();
+    let mut offset = 0;
+
+    while offset + entry_size <= ranges_data.len() {
+        // Parse child address
+        let child_address = parse_address_from_bytes(
+            &ranges_data[offset..offset + child_addr_bytes],
+            child_address_cells,
+        )?;
+        offset += child_addr_bytes;
+
+        // Parse parent address
+        let parent_address = parse_address_from_bytes(
+            &ranges_data[offset..offset + parent_addr_bytes],
+            parent_address_cells,
+        )?;
+        offset += parent_addr_bytes;
+
+        // Parse size
+        let size = parse_address_from_bytes(&ranges_data[offset..offset + size_bytes], size_cells)?;
+        offset += size_bytes;
+
+        // Create and validate the address range
+        let range = AddressRange::new(child_address, parent_address, size)?;
+        ranges.push(range);
+    }
+
+    Ok(ranges)
+}
+
 /// Parse a null-terminated string from bytes
 ///
 /// # Errors
 ///
-/// Returns `DtbError::MalformedHeader` if no null terminator is found
-/// or if the string contains invalid UTF-8.
+/// Returns `DtbError::MalformedHeader` if no null terminator is found, or
+/// `DtbError::InvalidUtf8` with an offset relative to `input` if the string
+/// contains invalid UTF-8.
 pub fn parse_null_terminated_string(input: &[u8]) -> Result<(&[u8], &str), DtbError> {
+    parse_null_terminated_string_with(input, None)
+}
+
+/// Parse a null-terminated string, optionally repairing invalid UTF-8
+///
+/// With `repair`, invalid strings are replaced by their repaired text
+/// instead of failing.
+fn parse_null_terminated_string_with<'a>(
+    input: &'a [u8],
+    repair: Option<&mut Repair<'_, 'a>>,
+) -> Result<(&'a [u8], &'a str), DtbError> {
     let null_pos = input
         .iter()
         .position(|&b| b == 0)
         .ok_or(DtbError::MalformedHeader)?;
 
     let string_bytes = &input[..null_pos];
-    let string = core::str::from_utf8(string_bytes).map_err(|_| DtbError::MalformedHeader)?;
+    let string = match (core::str::from_utf8(string_bytes), repair) {
+        (Ok(string), _) => string,
+        (Err(_), Some(repair)) => repair.repair(string_bytes),
+        (Err(e), None) => {
+            return Err(DtbError::InvalidUtf8 {
+                offset: e.valid_up_to(),
+            });
+        }
+    };
 
     Ok((&input[null_pos + 1..], string))
 }
@@ -1614,9 +3293,19 @@ pub fn parse_null_terminated_string(input: &[u8]) -> Result<(&[u8], &str), DtbEr
 ///
 /// # Errors
 ///
-/// Returns `DtbError::MalformedHeader` if the node name is malformed.
+/// Returns `DtbError::MalformedHeader` if the node name is malformed, or
+/// `DtbError::InvalidUtf8` with an offset relative to `input` if it is not
+/// valid UTF-8.
 pub fn parse_node_name(input: &[u8]) -> Result<(&[u8], &str), DtbError> {
-    let (remaining, name) = parse_null_terminated_string(input)?;
+    parse_node_name_with(input, None)
+}
+
+/// Parse node name, replacing invalid UTF-8 through `repair` if given
+pub(crate) fn parse_node_name_with<'a>(
+    input: &'a [u8],
+    repair: Option<&mut Repair<'_, 'a>>,
+) -> Result<(&'a [u8], &'a str), DtbError> {
+    let (remaining, name) = parse_null_terminated_string_with(input, repair)?;
 
     // Skip padding to 4-byte alignment
     let name_len = input.len() - remaining.len();
@@ -1633,10 +3322,28 @@ pub fn parse_node_name(input: &[u8]) -> Result<(&[u8], &str), DtbError> {
 ///
 /// # Errors
 ///
-/// Returns `DtbError::MalformedHeader` if input is too short or data is corrupted.
+/// Returns `DtbError::MalformedHeader` if input is too short or data is corrupted,
+/// or `DtbError::InvalidUtf8` with an offset relative to `strings_block` if the
+/// property name is not valid UTF-8.
 pub fn parse_property_data<'a>(
     input: &'a [u8],
     strings_block: &'a [u8],
+) -> Result<(&'a [u8], Property<'a>), DtbError> {
+    parse_property_data_with(input, strings_block, None, false, &[])
+}
+
+/// Parse property data, decoding values listed in `schema` by their declared
+/// kind
+///
+/// With `repair`, invalid UTF-8 in the name is repaired instead of failing,
+/// and non-ASCII text values are decoded as repaired strings rather than
+/// bytes.
+pub(crate) fn parse_property_data_with<'a>(
+    input: &'a [u8],
+    strings_block: &'a [u8],
+    mut repair: Option<&mut Repair<'_, 'a>>,
+    keep_raw: bool,
+    schema: &[(&str, PropKind)],
 ) -> Result<(&'a [u8], Property<'a>), DtbError> {
     if input.len() < 8 {
         return Err(DtbError::MalformedHeader);
@@ -1658,44 +3365,76 @@ pub fn parse_property_data<'a>(
     // Extract property data
     let prop_data = &remaining[..prop_len];
 
-    // Calculate padding for 4-byte alignment
+    // Calculate padding for 4-byte alignment, which must also fit
     let padding = DtbToken::calculate_padding(prop_len);
-    let next_input = &remaining[prop_len + padding..];
+    let Some(next_input) = remaining.get(prop_len + padding..) else {
+        return Err(DtbError::PropertyLengthOverflow(prop_len));
+    };
 
     // Resolve property name from strings block
-    let name = resolve_property_name(strings_block, name_offset)?;
+    let name = resolve_property_name(strings_block, name_offset, repair.as_deref_mut())?;
 
-    // Parse property value based on data
-    let value = parse_property_value(prop_data);
+    // Parse property value based on schema, name, and data
+    let mut value = schema::decode_property(schema, name, prop_data);
+    if let Some(repair) = repair
+        && let PropertyValue::Bytes(data) = value
+        && let Some(text) = parse_lossy_strings(name, data, repair)
+    {
+        value = text;
+    }
 
-    let property = Property { name, value };
+    let raw = keep_raw.then_some(prop_data);
+    let property = Property { name, value, raw };
     Ok((next_input, property))
 }
 
 /// Resolve property name from strings block using offset
-fn resolve_property_name(strings_block: &[u8], offset: usize) -> Result<&str, DtbError> {
+fn resolve_property_name<'a>(
+    strings_block: &'a [u8],
+    offset: usize,
+    repair: Option<&mut Repair<'_, 'a>>,
+) -> Result<&'a str, DtbError> {
     if offset >= strings_block.len() {
         return Err(DtbError::MalformedHeader);
     }
 
     let string_data = &strings_block[offset..];
-    let (_remaining, name) = parse_null_terminated_string(string_data)?;
+    let (_remaining, name) = parse_null_terminated_string_with(string_data, repair)
+        .map_err(|e| rebase_utf8_error(e, offset))?;
     Ok(name)
 }
 
+/// Shift an `InvalidUtf8` offset from a sub-slice to its enclosing block
+pub(crate) fn rebase_utf8_error(error: DtbError, base: usize) -> DtbError {
+    match error {
+        DtbError::InvalidUtf8 { offset } => DtbError::InvalidUtf8 {
+            offset: base + offset,
+        },
+        other => other,
+    }
+}
+
 /// Parse property value from raw bytes
-fn parse_property_value(data: &[u8]) -> PropertyValue<'_> {
+///
+/// The property name is used to skip string detection for properties that
+/// are always numeric, whose cells may happen to look like printable text.
+pub(crate) fn parse_property_value<'a>(name: &str, data: &'a [u8]) -> PropertyValue<'a> {
     if data.is_empty() {
         return PropertyValue::Empty;
     }
 
-    // Try to parse as string(s) first
-    if let Ok(string_value) = parse_as_strings(data) {
+    // Try to parse as string(s) first. Whole zero cells are left to the
+    // numeric rules (e.g. `reg = <0>` or `#size-cells = <0>`), but a lone
+    // terminator such as `compatible = ""` is still an empty string.
+    if !is_numeric_property(name)
+        && !is_zero_cells(data)
+        && let Ok(string_value) = parse_as_strings(data)
+    {
         return string_value;
     }
 
     // Try to parse as u32 array
-    if data.len() % 4 == 0 && !data.is_empty() {
+    if data.len().is_multiple_of(4) && !data.is_empty() {
         // For single u32 value, parse it directly
         if data.len() == 4 {
             let value = u32::from_be_bytes([data[0], data[1], data[2], data[3]]);
@@ -1706,7 +3445,7 @@ fn parse_property_value(data: &[u8]) -> PropertyValue<'_> {
     }
 
     // Try to parse as u64 array
-    if data.len() % 8 == 0 && !data.is_empty() {
+    if data.len().is_multiple_of(8) && !data.is_empty() {
         // For single u64 value, parse it directly
         if data.len() == 8 {
             let value = u64::from_be_bytes([
@@ -1722,6 +3461,46 @@ fn parse_property_value(data: &[u8]) -> PropertyValue<'_> {
     PropertyValue::Bytes(data)
 }
 
+/// Whether data is one or more 32-bit cells, all zero
+fn is_zero_cells(data: &[u8]) -> bool {
+    !data.is_empty() && data.len().is_multiple_of(4) && data.iter().all(|&b| b == 0)
+}
+
+/// Whether a property is always encoded as cells rather than strings
+fn is_numeric_property(name: &str) -> bool {
+    matches!(
+        name,
+        "reg" | "ranges" | "interrupts" | "phandle" | "size" | "alignment"
+    ) || (name.starts_with('#') && name.ends_with("-cells"))
+}
+
+/// Decode null-terminated non-ASCII text as strings, repairing invalid UTF-8
+///
+/// Only used when parsing lossily. The heuristic accepts printable ASCII
+/// only, so text in other encodings would otherwise stay raw bytes.
+fn parse_lossy_strings<'a>(
+    name: &str,
+    data: &'a [u8],
+    repair: &mut Repair<'_, 'a>,
+) -> Option<PropertyValue<'a>> {
+    let body = data.strip_suffix(&[0])?;
+    let is_control = |b: u8| (b < 0x20 && !matches!(b, 0 | b'\t' | b'\n' | b'\r')) || b == 0x7f;
+    if is_numeric_property(name) || body.is_ascii() || body.iter().any(|&b| is_control(b)) {
+        return None;
+    }
+
+    let strings: Vec<&str> = body
+        .split(|&b| b == 0)
+        .filter(|piece| !piece.is_empty())
+        .map(|piece| core::str::from_utf8(piece).unwrap_or_else(|_| repair.repair(piece)))
+        .collect();
+    match strings.as_slice() {
+        [] => None,
+        [s] => Some(PropertyValue::String(s)),
+        _ => Some(PropertyValue::StringList(strings)),
+    }
+}
+
 /// Try to parse data as string or string list
 fn parse_as_strings(data: &[u8]) -> Result<PropertyValue<'_>, ()> {
     // Check if all bytes are valid UTF-8 or null
@@ -1860,14 +3639,14 @@ mod tests {
     #[test]
     fn test_parse_property_value_u32() {
         let data = [0x12, 0x34, 0x56, 0x78];
-        let value = parse_property_value(&data);
+        let value = parse_property_value("value", &data);
         assert_eq!(value, PropertyValue::U32(0x12345678));
     }
 
     #[test]
     fn test_parse_property_value_string() {
         let data = b"hello\0";
-        let value = parse_property_value(data);
+        let value = parse_property_value("value", data);
         match value {
             PropertyValue::String(s) => assert_eq!(s, "hello"),
             _ => panic!("Expected String value"),
@@ -1877,10 +3656,325 @@ mod tests {
     #[test]
     fn test_parse_property_value_empty() {
         let data = [];
-        let value = parse_property_value(&data);
+        let value = parse_property_value("value", &data);
         assert_eq!(value, PropertyValue::Empty);
     }
 
+    #[test]
+    fn test_parse_property_value_zero() {
+        // Zero-valued cells are numbers, not empty strings
+        let value = parse_property_value("value", &[0u8; 4]);
+        assert_eq!(value, PropertyValue::U32(0));
+
+        let data = [0u8; 8];
+        let value = parse_property_value("value", &data);
+        assert_eq!(value, PropertyValue::U32Array(&data));
+
+        // Unaligned zeros are terminators, not cells
+        assert_eq!(parse_property_value("value", b"\0"), PropertyValue::Empty);
+        assert_eq!(parse_property_value("value", b"\0\0"), PropertyValue::Empty);
+        assert_eq!(
+            parse_property_value("value", &[0, 0, 0, 0, 0]),
+            PropertyValue::Empty
+        );
+    }
+
+    #[test]
+    fn test_parse_property_value_numeric_names() {
+        // "4567" is printable ASCII, but reg is always cells
+        let data = *b"4567";
+        assert_eq!(
+            parse_property_value("reg", &data),
+            PropertyValue::U32(0x3435_3637)
+        );
+        assert_eq!(
+            parse_property_value("#address-cells", &data),
+            PropertyValue::U32(0x3435_3637)
+        );
+        let data = *b"ABCDEFGH";
+        assert_eq!(
+            parse_property_value("ranges", &data),
+            PropertyValue::U32Array(&data)
+        );
+        // Other names keep the string heuristic
+        assert_eq!(
+            parse_property_value("label", &data),
+            PropertyValue::String("ABCDEFGH")
+        );
+    }
+
+    #[test]
+    fn test_property_value_encode_round_trip() {
+        let cells = [0, 0, 0, 1, 0, 0, 0, 2];
+        let bytes = [0xde, 0xad, 0xbe];
+        let cases = [
+            (PropertyValue::Empty, &[][..]),
+            (PropertyValue::String("okay"), b"okay\0"),
+            (
+                PropertyValue::StringList(vec!["ns16550a", "uart"]),
+                b"ns16550a\0uart\0",
+            ),
+            (PropertyValue::U32(0x1234_5678), &[0x12, 0x34, 0x56, 0x78]),
+            (PropertyValue::U32Array(&cells), &cells),
+            (PropertyValue::Bytes(&bytes), &bytes),
+        ];
+        for (value, encoded) in cases {
+            assert_eq!(value.encode(), encoded, "{value:?}");
+            assert_eq!(value.encode().len(), value.encoded_len());
+            assert_eq!(parse_property_value("value", &value.encode()), value);
+        }
+
+        // 64-bit values are never produced by the heuristic, which reads
+        // whole cells first, but their bytes still round-trip
+        let value = PropertyValue::U64(0x1_0000_0002);
+        assert_eq!(value.encode(), cells);
+        assert_eq!(
+            crate::dtb::PropKind::U64.decode(&value.encode()),
+            Some(value)
+        );
+        let value = PropertyValue::U64Array(&cells);
+        assert_eq!(value.encode(), cells);
+        assert_eq!(
+            parse_property_value("value", &value.encode()).encode(),
+            cells
+        );
+    }
+
+    #[test]
+    fn test_property_value_encode_reclassifies() {
+        // An empty string is a lone terminator, which parses as Empty
+        let value = PropertyValue::String("");
+        assert_eq!(value.encode(), b"\0");
+        assert_eq!(
+            parse_property_value("value", &value.encode()),
+            PropertyValue::Empty
+        );
+
+        // A cell whose bytes are printable parses as a string
+        let value = PropertyValue::U32(0x4142_4300);
+        assert_eq!(value.encode(), b"ABC\0");
+        assert_eq!(
+            parse_property_value("value", &value.encode()),
+            PropertyValue::String("ABC")
+        );
+    }
+
+    #[test]
+    fn test_find_property_inherited() {
+        let mut root = DeviceTreeNode::new("");
+        root.add_property(Property {
+            name: "interrupt-parent",
+            value: PropertyValue::U32(1),
+            raw: None,
+        });
+        let mut soc = DeviceTreeNode::new("soc");
+        soc.add_property(Property {
+            name: "#address-cells",
+            value: PropertyValue::U32(2),
+            raw: None,
+        });
+        let mut uart = DeviceTreeNode::new("uart@1000");
+        uart.add_property(Property {
+            name: "#address-cells",
+            value: PropertyValue::U32(1),
+            raw: None,
+        });
+
+        // interrupt-parent comes from the grandparent
+        let found = uart
+            .find_property_inherited("interrupt-parent", &[&soc, &root])
+            .unwrap();
+        assert_eq!(found.value, PropertyValue::U32(1));
+
+        // The nearest definition wins
+        let found = uart
+            .find_property_inherited("#address-cells", &[&soc, &root])
+            .unwrap();
+        assert_eq!(found.value, PropertyValue::U32(1));
+
+        // A closer ancestor shadows a farther one
+        soc.add_property(Property {
+            name: "interrupt-parent",
+            value: PropertyValue::U32(5),
+            raw: None,
+        });
+        let found = uart
+            .find_property_inherited("interrupt-parent", &[&soc, &root])
+            .unwrap();
+        assert_eq!(found.value, PropertyValue::U32(5));
+
+        assert!(
+            uart.find_property_inherited("interrupt-parent", &[])
+                .is_none()
+        );
+        assert!(
+            uart.find_property_inherited("status", &[&soc, &root])
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_prop_cells() {
+        const DATA: &[u8] = &[
+            0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 4, 0, 0, 0, 5, 0, 0, 0, 6,
+        ];
+        let mut node = DeviceTreeNode::new("widget");
+        node.add_property(Property {
+            name: "vendor,cells",
+            value: PropertyValue::U32Array(DATA),
+            raw: None,
+        });
+        node.add_property(Property {
+            name: "vendor,none",
+            value: PropertyValue::Empty,
+            raw: None,
+        });
+
+        assert_eq!(
+            node.prop_cells("vendor,cells", 1),
+            Some(vec![1, 2, 3, 4, 5, 6])
+        );
+        assert_eq!(
+            node.prop_cells("vendor,cells", 2),
+            Some(vec![0x1_0000_0002, 0x3_0000_0004, 0x5_0000_0006])
+        );
+        // Three cells keep the low 64 bits of each group
+        assert_eq!(
+            node.prop_cells("vendor,cells", 3),
+            Some(vec![0x2_0000_0003, 0x5_0000_0006])
+        );
+
+        assert_eq!(node.prop_cells("vendor,cells", 4), None);
+        assert_eq!(node.prop_cells("vendor,cells", 0), None);
+        assert_eq!(node.prop_cells("vendor,none", 2), Some(vec![]));
+        assert_eq!(node.prop_cells("missing", 1), None);
+    }
+
+    #[test]
+    fn test_prop_cells_uses_raw() {
+        // Misclassified as a string; the raw bytes have no terminator
+        let mut node = DeviceTreeNode::new("widget");
+        node.add_property(Property {
+            name: "vendor,id",
+            value: PropertyValue::String("ABCDEFGH"),
+            raw: Some(b"ABCDEFGH"),
+        });
+        assert_eq!(
+            node.prop_cells("vendor,id", 1),
+            Some(vec![0x4142_4344, 0x4546_4748])
+        );
+
+        // Without raw bytes a string's original encoding is unknown
+        node.add_property(Property {
+            name: "vendor,name",
+            value: PropertyValue::String("ABC"),
+            raw: None,
+        });
+        assert_eq!(node.prop_cells("vendor,name", 1), None);
+    }
+
+    #[test]
+    fn test_node_value() {
+        let cells = [0, 0, 0, 1, 0, 0, 0, 2];
+        let mut node = DeviceTreeNode::new("uart@1000");
+        node.add_property(Property {
+            name: "phandle",
+            value: PropertyValue::U32(5),
+            raw: None,
+        });
+        node.add_property(Property {
+            name: "status",
+            value: PropertyValue::String("okay"),
+            raw: None,
+        });
+        node.add_property(Property {
+            name: "reg",
+            value: PropertyValue::U32Array(&cells),
+            raw: None,
+        });
+
+        assert_eq!(node.value::<u32>("phandle"), Ok(5));
+        let status: &str = node.value("status").unwrap();
+        assert_eq!(status, "okay");
+        assert_eq!(node.value::<Vec<u32>>("reg"), Ok(vec![1, 2]));
+        // A single cell converts to a one-element array
+        assert_eq!(node.value::<Vec<u32>>("phandle"), Ok(vec![5]));
+
+        assert_eq!(
+            node.value::<u32>("missing"),
+            Err(DtbError::PropertyNotFound)
+        );
+        assert_eq!(
+            node.value::<u32>("status"),
+            u32::try_from(&PropertyValue::String("okay"))
+        );
+        assert!(node.value::<&str>("reg").is_err());
+    }
+
+    #[test]
+    fn test_iter_bus_nodes() {
+        let mut root = DeviceTreeNode::new("");
+        let mut soc = DeviceTreeNode::new("soc");
+        soc.add_property(Property {
+            name: "ranges",
+            value: PropertyValue::Empty,
+            raw: None,
+        });
+        let mut bridge = DeviceTreeNode::new("bridge@1000");
+        bridge.add_property(Property {
+            name: "ranges",
+            value: PropertyValue::U32Array(&[0, 0, 0, 0, 0, 0, 0x10, 0, 0, 0, 0x10, 0]),
+            raw: None,
+        });
+        bridge.add_child(DeviceTreeNode::new("uart@0"));
+        soc.add_child(bridge);
+        soc.add_child(DeviceTreeNode::new("gpio@2000"));
+        root.add_child(soc);
+        root.add_child(DeviceTreeNode::new("memory@0"));
+
+        let names: Vec<&str> = root.iter_bus_nodes().map(|n| n.name).collect();
+        assert_eq!(names, ["soc", "bridge@1000"]);
+        assert_eq!(root.children[1].iter_bus_nodes().count(), 0);
+    }
+
+    #[test]
+    fn test_iter_with_parent() {
+        let mut soc = DeviceTreeNode::new("soc");
+        soc.add_property(Property {
+            name: "#address-cells",
+            value: PropertyValue::U32(1),
+            raw: None,
+        });
+        soc.add_child(DeviceTreeNode::new("uart@1000"));
+        soc.add_child(DeviceTreeNode::new("gpio@2000"));
+        let mut root = DeviceTreeNode::new("");
+        root.add_child(soc);
+        root.add_child(DeviceTreeNode::new("memory@0"));
+
+        let pairs: Vec<(Option<&str>, &str)> = root
+            .iter_with_parent()
+            .map(|(parent, node)| (parent.map(|p| p.name), node.name))
+            .collect();
+        assert_eq!(
+            pairs,
+            [
+                (None, ""),
+                (Some(""), "soc"),
+                (Some("soc"), "uart@1000"),
+                (Some("soc"), "gpio@2000"),
+                (Some(""), "memory@0"),
+            ]
+        );
+
+        // The parent is the node in the tree, not a copy
+        let (parent, uart) = root
+            .iter_with_parent()
+            .find(|(_, node)| node.name == "uart@1000")
+            .unwrap();
+        assert!(core::ptr::eq(parent.unwrap(), &root.children[0]));
+        assert_eq!(uart.address_cells_with_parent(parent), Ok(1));
+    }
+
     #[test]
     fn test_node_property_accessors() {
         let name1 = "test-u32";
@@ -1892,12 +3986,14 @@ mod tests {
         node.add_property(Property {
             name: name1,
             value: PropertyValue::U32(42),
+            raw: None,
         });
 
         // Add string property
         node.add_property(Property {
             name: name2,
             value: PropertyValue::String(value_str),
+            raw: None,
         });
 
         assert_eq!(node.prop_u32("test-u32"), Some(42));
@@ -1913,54 +4009,427 @@ mod tests {
         let mut cpus = DeviceTreeNode::new("cpus");
         let mut cpu0 = DeviceTreeNode::new("cpu@0");
 
-        cpu0.add_property(Property {
-            name: device_type,
-            value: PropertyValue::String(cpu_str),
-        });
+        cpu0.add_property(Property {
+            name: device_type,
+            value: PropertyValue::String(cpu_str),
+            raw: None,
+        });
+
+        cpus.add_child(cpu0);
+        root.add_child(cpus);
+
+        // Test root lookup
+        assert!(root.find_node("/").is_some());
+        assert!(root.find_node("").is_some());
+
+        // Test path lookup
+        assert!(root.find_node("/cpus").is_some());
+        assert!(root.find_node("/cpus/cpu@0").is_some());
+        assert!(root.find_node("/cpus/cpu").is_some()); // Should match cpu@0
+
+        // Test non-existent path
+        assert!(root.find_node("/nonexistent").is_none());
+    }
+
+    #[test]
+    fn test_compatible_node_search() {
+        let compatible = "compatible";
+        let ns16550a = "ns16550a";
+        let ns16550 = "ns16550";
+        let mut root = DeviceTreeNode::new("");
+        let mut uart1 = DeviceTreeNode::new("uart@1000");
+        let mut uart2 = DeviceTreeNode::new("uart@2000");
+
+        uart1.add_property(Property {
+            name: compatible,
+            value: PropertyValue::String(ns16550a),
+            raw: None,
+        });
+
+        uart2.add_property(Property {
+            name: compatible,
+            value: PropertyValue::StringList(vec![ns16550a, ns16550]),
+            raw: None,
+        });
+
+        root.add_child(uart1);
+        root.add_child(uart2);
+
+        let ns16550a_nodes = root.find_compatible_nodes("ns16550a");
+        assert_eq!(ns16550a_nodes.len(), 2);
+
+        let ns16550_nodes = root.find_compatible_nodes("ns16550");
+        assert_eq!(ns16550_nodes.len(), 1);
+    }
+
+    #[test]
+    fn test_compatible_list() {
+        let mut single = DeviceTreeNode::new("pl031@9010000");
+        single.add_property(Property {
+            name: "compatible",
+            value: PropertyValue::String("arm,pl031"),
+            raw: None,
+        });
+        assert_eq!(single.compatible(), vec!["arm,pl031"]);
+        assert!(single.is_compatible("arm,pl031"));
+        assert!(!single.is_compatible("arm,primecell"));
+
+        let mut multi = DeviceTreeNode::new("pl011@9000000");
+        multi.add_property(Property {
+            name: "compatible",
+            value: PropertyValue::StringList(vec!["arm,pl011", "arm,primecell"]),
+            raw: None,
+        });
+        assert_eq!(multi.compatible(), vec!["arm,pl011", "arm,primecell"]);
+        assert!(multi.is_compatible("arm,pl011"));
+        assert!(multi.is_compatible("arm,primecell"));
+        assert!(!multi.is_compatible("arm,pl031"));
+
+        let none = DeviceTreeNode::new("chosen");
+        assert!(none.compatible().is_empty());
+        assert!(!none.is_compatible("arm,pl011"));
+    }
+
+    #[test]
+    fn test_best_compatible() {
+        let mut uart = DeviceTreeNode::new("serial@1000");
+        uart.add_property(Property {
+            name: "compatible",
+            value: PropertyValue::StringList(vec!["vendor,soc-uart", "ns16550a"]),
+            raw: None,
+        });
+
+        // The more specific entry wins even when listed later by the driver
+        assert_eq!(
+            uart.best_compatible(&["ns16550a", "vendor,soc-uart"]),
+            Some((0, "vendor,soc-uart"))
+        );
+        // Falls back to the generic entry
+        assert_eq!(
+            uart.best_compatible(&["snps,dw-apb-uart", "ns16550a"]),
+            Some((1, "ns16550a"))
+        );
+        assert_eq!(uart.best_compatible(&["arm,pl011"]), None);
+        assert_eq!(uart.best_compatible(&[]), None);
+        assert_eq!(
+            DeviceTreeNode::new("chosen").best_compatible(&["ns16550a"]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_frequency_cell_widths() {
+        let one_cell = [0x00, 0x1c, 0x20, 0x00];
+        let two_cells = [0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00];
+
+        let mut node = DeviceTreeNode::new("clock");
+        node.add_property(Property {
+            name: "clock-frequency",
+            value: parse_property_value("clock-frequency", &one_cell),
+            raw: None,
+        });
+        node.add_property(Property {
+            name: "timebase-frequency",
+            value: parse_property_value("timebase-frequency", &two_cells),
+            raw: None,
+        });
+        assert_eq!(node.clock_frequency(), Some(1_843_200));
+        assert_eq!(node.timebase_frequency(), Some(0x1_0000_0000));
+
+        // Three cells is not a frequency
+        node.set_property(Property {
+            name: "clock-frequency",
+            value: PropertyValue::U32Array(&[0u8; 12]),
+            raw: None,
+        });
+        assert_eq!(node.clock_frequency(), None);
+        assert_eq!(DeviceTreeNode::new("empty").timebase_frequency(), None);
+    }
+
+    #[test]
+    fn test_unit_address() {
+        let cpu = DeviceTreeNode::new("cpu@0");
+        assert_eq!(cpu.unit_address(), Some(0));
+        assert_eq!(cpu.node_base_name(), "cpu");
+
+        let memory = DeviceTreeNode::new("memory@40000000");
+        assert_eq!(memory.unit_address(), Some(0x4000_0000));
+        assert_eq!(memory.node_base_name(), "memory");
+
+        let root = DeviceTreeNode::new("");
+        assert_eq!(root.unit_address(), None);
+        assert_eq!(root.node_base_name(), "");
+
+        let chosen = DeviceTreeNode::new("chosen");
+        assert_eq!(chosen.unit_address(), None);
+        assert_eq!(chosen.node_base_name(), "chosen");
+
+        for name in ["pci@1,0", "node@", "node@+10", "node@xyz"] {
+            assert_eq!(DeviceTreeNode::new(name).unit_address(), None, "{name}");
+        }
+        assert_eq!(DeviceTreeNode::new("pci@1,0").node_base_name(), "pci");
+    }
+
+    #[test]
+    fn test_children_sorted_by_address() {
+        let mut soc = DeviceTreeNode::new("soc");
+        for name in [
+            "uart@9000000",
+            "gpio@2000",
+            "clocks",
+            "rtc@9010000",
+            "serial@2000",
+            "aliases",
+        ] {
+            soc.add_child(DeviceTreeNode::new(name));
+        }
+
+        let names: Vec<&str> = soc
+            .children_sorted_by_address()
+            .iter()
+            .map(|c| c.name)
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "aliases",
+                "clocks",
+                "gpio@2000",
+                "serial@2000",
+                "uart@9000000",
+                "rtc@9010000",
+            ]
+        );
+        // Original order is untouched
+        assert_eq!(soc.children[0].name, "uart@9000000");
+    }
+
+    #[test]
+    fn test_validate_reg() {
+        let mut bus = DeviceTreeNode::new("soc");
+        bus.add_property(Property {
+            name: "#address-cells",
+            value: PropertyValue::U32(2),
+            raw: None,
+        });
+        bus.add_property(Property {
+            name: "#size-cells",
+            value: PropertyValue::U32(2),
+            raw: None,
+        });
+
+        let cells: Vec<u8> = (1..=8u32).flat_map(u32::to_be_bytes).collect();
+        let mut device = DeviceTreeNode::new("device@1");
+        assert_eq!(device.validate_reg(Some(&bus)), Ok(()));
+
+        // Two 2+2 cell entries
+        device.add_property(Property {
+            name: "reg",
+            value: parse_property_value("reg", &cells),
+            raw: None,
+        });
+        assert_eq!(device.validate_reg(Some(&bus)), Ok(()));
+        // Eight cells are not a whole number of default 2+1 cell entries
+        assert_eq!(device.validate_reg(None), Err(DtbError::InvalidRegFormat));
+
+        // One and a half entries
+        device.set_property(Property {
+            name: "reg",
+            value: parse_property_value("reg", &cells[..24]),
+            raw: None,
+        });
+        assert_eq!(
+            device.validate_reg(Some(&bus)),
+            Err(DtbError::InvalidRegFormat)
+        );
+        assert_eq!(device.validate_reg(None), Ok(()));
+
+        // A partial cell is never valid
+        device.set_property(Property {
+            name: "reg",
+            value: parse_property_value("reg", &cells[..14]),
+            raw: None,
+        });
+        assert_eq!(
+            device.validate_reg(Some(&bus)),
+            Err(DtbError::InvalidRegFormat)
+        );
+
+        // Raw bytes are measured when kept
+        device.set_property(Property {
+            name: "reg",
+            value: PropertyValue::Empty,
+            raw: Some(&cells[..12]),
+        });
+        assert_eq!(device.validate_reg(None), Ok(()));
+        assert_eq!(
+            device.validate_reg(Some(&bus)),
+            Err(DtbError::InvalidRegFormat)
+        );
+
+        // Invalid parent cell counts are reported as such
+        bus.set_property(Property {
+            name: "#size-cells",
+            value: PropertyValue::U32(5),
+            raw: None,
+        });
+        assert_eq!(
+            device.validate_reg(Some(&bus)),
+            Err(DtbError::InvalidSizeCells(5))
+        );
+    }
+
+    #[test]
+    fn test_reg_index() {
+        let mut bus = DeviceTreeNode::new("soc");
+        bus.add_property(Property {
+            name: "#address-cells",
+            value: PropertyValue::U32(2),
+            raw: None,
+        });
+        bus.add_property(Property {
+            name: "#size-cells",
+            value: PropertyValue::U32(1),
+            raw: None,
+        });
+
+        // Three entries: <0x0 0x1000 0x100>, <0x1 0x2000 0x200>, <0x0 0x3000 0x300>
+        let reg = [
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x01, 0x00, //
+            0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x02, 0x00, //
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x00, 0x00, 0x00, 0x03, 0x00,
+        ];
+        let mut device = DeviceTreeNode::new("device@1000");
+        device.add_property(Property {
+            name: "reg",
+            value: parse_property_value("reg", &reg),
+            raw: None,
+        });
+
+        assert_eq!(device.reg_index(0, Some(&bus)), Ok(Some((0x1000, 0x100))));
+        assert_eq!(
+            device.reg_index(1, Some(&bus)),
+            Ok(Some((0x1_0000_2000, 0x200)))
+        );
+        assert_eq!(device.reg_index(2, Some(&bus)), Ok(Some((0x3000, 0x300))));
+        assert_eq!(device.reg_index(3, Some(&bus)), Ok(None));
+        assert_eq!(device.reg_index(usize::MAX, Some(&bus)), Ok(None));
+
+        // Default 2+1 cells without a parent give the same layout
+        assert_eq!(device.reg_index(1, None), Ok(Some((0x1_0000_2000, 0x200))));
+
+        assert_eq!(DeviceTreeNode::new("chosen").reg_index(0, None), Ok(None));
+        device.set_property(Property {
+            name: "reg",
+            value: PropertyValue::String("bogus"),
+            raw: None,
+        });
+        assert_eq!(
+            device.reg_index(0, Some(&bus)),
+            Err(DtbError::InvalidRegFormat)
+        );
+    }
+
+    #[test]
+    fn test_iter_property() {
+        let reg = |name| {
+            let mut node = DeviceTreeNode::new(name);
+            node.add_property(Property {
+                name: "reg",
+                value: PropertyValue::U32(0x1000),
+                raw: None,
+            });
+            node
+        };
+
+        let mut soc = DeviceTreeNode::new("soc");
+        soc.add_child(reg("uart@1000"));
+        soc.add_child(DeviceTreeNode::new("clocks"));
+        soc.add_child(reg("gpio@2000"));
+        let mut root = reg("");
+        root.add_child(soc);
+
+        let owners: Vec<&str> = root
+            .iter_property("reg")
+            .map(|(node, prop)| {
+                assert_eq!(prop.name, "reg");
+                node.name
+            })
+            .collect();
+        assert_eq!(owners, ["", "uart@1000", "gpio@2000"]);
+        assert_eq!(root.iter_property("missing").count(), 0);
+    }
+
+    #[test]
+    fn test_node_status() {
+        let with_status = |value: &'static str| {
+            let mut node = DeviceTreeNode::new("test");
+            node.add_property(Property {
+                name: "status",
+                value: PropertyValue::String(value),
+                raw: None,
+            });
+            node
+        };
+
+        // Missing status defaults to okay
+        let node = DeviceTreeNode::new("test");
+        assert_eq!(node.status(), Status::Okay);
+        assert!(node.is_enabled());
 
-        cpus.add_child(cpu0);
-        root.add_child(cpus);
+        assert_eq!(with_status("okay").status(), Status::Okay);
+        assert_eq!(with_status("ok").status(), Status::Okay);
+        assert!(with_status("okay").is_enabled());
 
-        // Test root lookup
-        assert!(root.find_node("/").is_some());
-        assert!(root.find_node("").is_some());
+        assert_eq!(with_status("disabled").status(), Status::Disabled);
+        assert!(!with_status("disabled").is_enabled());
 
-        // Test path lookup
-        assert!(root.find_node("/cpus").is_some());
-        assert!(root.find_node("/cpus/cpu@0").is_some());
-        assert!(root.find_node("/cpus/cpu").is_some()); // Should match cpu@0
+        assert_eq!(with_status("reserved").status(), Status::Reserved);
+        assert!(!with_status("reserved").is_enabled());
 
-        // Test non-existent path
-        assert!(root.find_node("/nonexistent").is_none());
+        assert_eq!(with_status("fail").status(), Status::Fail);
+        assert_eq!(with_status("fail-clock").status(), Status::Fail);
+        assert!(!with_status("fail").is_enabled());
+
+        assert_eq!(with_status("broken").status(), Status::Unknown("broken"));
+        assert!(!with_status("broken").is_enabled());
     }
 
     #[test]
-    fn test_compatible_node_search() {
-        let compatible = "compatible";
-        let ns16550a = "ns16550a";
-        let ns16550 = "ns16550";
+    fn test_compatible_prefix_search() {
         let mut root = DeviceTreeNode::new("");
-        let mut uart1 = DeviceTreeNode::new("uart@1000");
-        let mut uart2 = DeviceTreeNode::new("uart@2000");
-
-        uart1.add_property(Property {
-            name: compatible,
-            value: PropertyValue::String(ns16550a),
+        let mut uart = DeviceTreeNode::new("pl011@9000000");
+        let mut rtc = DeviceTreeNode::new("pl031@9010000");
+        let mut virtio = DeviceTreeNode::new("virtio_mmio@a000000");
+
+        uart.add_property(Property {
+            name: "compatible",
+            value: PropertyValue::StringList(vec!["arm,pl011", "arm,primecell"]),
+            raw: None,
         });
-
-        uart2.add_property(Property {
-            name: compatible,
-            value: PropertyValue::StringList(vec![ns16550a, ns16550]),
+        rtc.add_property(Property {
+            name: "compatible",
+            value: PropertyValue::String("arm,pl031"),
+            raw: None,
+        });
+        virtio.add_property(Property {
+            name: "compatible",
+            value: PropertyValue::String("virtio,mmio"),
+            raw: None,
         });
 
-        root.add_child(uart1);
-        root.add_child(uart2);
+        root.add_child(uart);
+        root.add_child(rtc);
+        root.add_child(virtio);
 
-        let ns16550a_nodes = root.find_compatible_nodes("ns16550a");
-        assert_eq!(ns16550a_nodes.len(), 2);
+        let arm_nodes = root.find_compatible_prefix("arm,");
+        assert_eq!(arm_nodes.len(), 2);
+        assert_eq!(arm_nodes[0].name, "pl011@9000000");
+        assert_eq!(arm_nodes[1].name, "pl031@9010000");
 
-        let ns16550_nodes = root.find_compatible_nodes("ns16550");
-        assert_eq!(ns16550_nodes.len(), 1);
+        // Prefix matches later entries of the list too
+        assert_eq!(root.find_compatible_prefix("arm,prime").len(), 1);
+        assert!(root.find_compatible_prefix("ns16550").is_empty());
     }
 
     #[test]
@@ -1984,6 +4453,197 @@ mod tests {
         assert_eq!(nodes[3].name, "child2");
     }
 
+    #[test]
+    fn test_iter_paths() {
+        let mut uart = DeviceTreeNode::new("uart@1000");
+        uart.add_child(DeviceTreeNode::new("port"));
+        let mut soc = DeviceTreeNode::new("soc");
+        soc.add_child(uart);
+        soc.add_child(DeviceTreeNode::new("gpio@2000"));
+        let mut root = DeviceTreeNode::new("");
+        root.add_child(soc);
+        root.add_child(DeviceTreeNode::new("chosen"));
+
+        let paths: Vec<(String, &str)> = root
+            .iter_paths()
+            .map(|(path, node)| (path, node.name))
+            .collect();
+        assert_eq!(
+            paths,
+            [
+                (String::from("/"), ""),
+                (String::from("/soc"), "soc"),
+                (String::from("/soc/uart@1000"), "uart@1000"),
+                (String::from("/soc/uart@1000/port"), "port"),
+                (String::from("/soc/gpio@2000"), "gpio@2000"),
+                (String::from("/chosen"), "chosen"),
+            ]
+        );
+
+        // Every path resolves back to its node
+        for (path, node) in root.iter_paths() {
+            assert!(core::ptr::eq(root.find_node(&path).unwrap(), node));
+        }
+    }
+
+    #[test]
+    fn test_build_path_index() {
+        let mut cpus = DeviceTreeNode::new("cpus");
+        cpus.add_child(DeviceTreeNode::new("cpu@0"));
+        cpus.add_child(DeviceTreeNode::new("cpu@1"));
+        let mut root = DeviceTreeNode::new("");
+        root.add_child(cpus);
+        root.add_child(DeviceTreeNode::new("memory@40000000"));
+
+        let index = root.build_path_index();
+        assert_eq!(index.len(), root.iter_nodes().count());
+        for (path, node) in &index {
+            assert!(core::ptr::eq(root.find_node(path).unwrap(), *node));
+        }
+        assert_eq!(index["/cpus/cpu@1"].name, "cpu@1");
+        assert!(core::ptr::eq(index["/"], &root));
+        // Unlike find_node, the index needs exact paths
+        assert!(root.find_node("/cpus/cpu").is_some());
+        assert!(!index.contains_key("/cpus/cpu"));
+    }
+
+    #[test]
+    fn test_prop_u64_at() {
+        let mut node = DeviceTreeNode::new("test");
+        // "ABCDEFGH" is all printable, so the heuristic decodes it as a string
+        let printable = parse_property_value("value", b"ABCDEFGH");
+        assert_eq!(printable, PropertyValue::String("ABCDEFGH"));
+        node.add_property(Property {
+            name: "value",
+            value: printable,
+            raw: None,
+        });
+        assert_eq!(node.prop_u64("value"), None);
+        // Without raw bytes the original encoding is unknown
+        assert_eq!(node.prop_u64_at("value", 0, 2), None);
+
+        // <0x41 0x42434400> decodes as the string "ABCD"
+        const CELLS: &[u8] = &[0, 0, 0, 0x41, 0x42, 0x43, 0x44, 0];
+        let value = parse_property_value("vendor,id", CELLS);
+        assert_eq!(value, PropertyValue::String("ABCD"));
+        node.add_property(Property {
+            name: "vendor,id",
+            value,
+            raw: Some(CELLS),
+        });
+        assert_eq!(node.prop_u64_at("vendor,id", 0, 1), Some(0x41));
+        assert_eq!(node.prop_u64_at("vendor,id", 1, 1), Some(0x4243_4400));
+        assert_eq!(node.prop_u64_at("vendor,id", 0, 2), Some(0x41_4243_4400));
+
+        let reg = [0, 0, 0, 0, 0x40, 0, 0, 0, 0, 0, 0, 0, 0x08, 0, 0, 0];
+        node.add_property(Property {
+            name: "reg",
+            value: parse_property_value("reg", &reg),
+            raw: None,
+        });
+        assert_eq!(node.prop_u64_at("reg", 0, 2), Some(0x4000_0000));
+        assert_eq!(node.prop_u64_at("reg", 2, 2), Some(0x0800_0000));
+        assert_eq!(node.prop_u64_at("reg", 3, 2), None);
+        assert_eq!(node.prop_u64_at("reg", 0, 5), None);
+        assert_eq!(node.prop_u64_at("missing", 0, 1), None);
+    }
+
+    #[test]
+    fn test_address_range_ordering() {
+        let low = AddressRange::new(0x1000, 0x9000_0000, 0x100).unwrap();
+        let low_larger = AddressRange::new(0x1000, 0x8000_0000, 0x200).unwrap();
+        let high = AddressRange::new(0x2000, 0x0, 0x100).unwrap();
+        let mut ranges = vec![high, low_larger, low];
+        ranges.sort();
+        assert_eq!(ranges, [low, low_larger, high]);
+
+        // Parent address only breaks ties
+        let other_parent = AddressRange::new(0x1000, 0xa000_0000, 0x100).unwrap();
+        assert!(low < other_parent);
+    }
+
+    #[test]
+    fn test_prop_or_defaults() {
+        let mut node = DeviceTreeNode::new("uart@1000");
+        for (name, value) in [
+            ("current-speed", PropertyValue::U32(115_200)),
+            ("timebase", PropertyValue::U64(0x1_0000_0000)),
+            ("status", PropertyValue::String("okay")),
+            ("dma-coherent", PropertyValue::Empty),
+            ("wakeup-source", PropertyValue::U32(0)),
+        ] {
+            node.add_property(Property {
+                name,
+                value,
+                raw: None,
+            });
+        }
+
+        assert_eq!(node.prop_u32_or("current-speed", 9600), 115_200);
+        assert_eq!(node.prop_u32_or("reg-shift", 2), 2);
+        assert_eq!(node.prop_u64_or("timebase", 0), 0x1_0000_0000);
+        assert_eq!(node.prop_u64_or("missing", 7), 7);
+        assert_eq!(node.prop_string_or("status", "disabled"), "okay");
+        assert_eq!(node.prop_string_or("label", "uart"), "uart");
+        // Wrong type falls back to the default too
+        assert_eq!(node.prop_string_or("current-speed", "none"), "none");
+
+        assert!(node.prop_bool_or("dma-coherent", false));
+        assert!(!node.prop_bool_or("wakeup-source", true));
+        assert!(node.prop_bool_or("missing", true));
+        assert!(!node.prop_bool_or("missing", false));
+    }
+
+    #[test]
+    fn test_children_where() {
+        let device_type = |name, value| {
+            let mut node = DeviceTreeNode::new(name);
+            node.add_property(Property {
+                name: "device_type",
+                value: PropertyValue::String(value),
+                raw: None,
+            });
+            node
+        };
+        let mut cpu0 = device_type("cpu@0", "cpu");
+        cpu0.add_child(device_type("cpu@0-nested", "cpu"));
+        let mut cpus = DeviceTreeNode::new("cpus");
+        cpus.add_child(cpu0);
+        cpus.add_child(device_type("cpu-map", "map"));
+        cpus.add_child(DeviceTreeNode::new("idle-states"));
+        cpus.add_child(device_type("cpu@1", "cpu"));
+
+        let names: Vec<&str> = cpus
+            .children_where(|n| n.prop_string("device_type") == Some("cpu"))
+            .map(|n| n.name)
+            .collect();
+        // Grandchildren are not visited
+        assert_eq!(names, ["cpu@0", "cpu@1"]);
+        assert_eq!(cpus.children_where(|_| false).count(), 0);
+    }
+
+    #[test]
+    fn test_node_iterator_bfs() {
+        let mut root = DeviceTreeNode::new("");
+        let mut child1 = DeviceTreeNode::new("child1");
+        let mut child2 = DeviceTreeNode::new("child2");
+        let mut grandchild1 = DeviceTreeNode::new("grandchild1");
+
+        grandchild1.add_child(DeviceTreeNode::new("leaf"));
+        child1.add_child(grandchild1);
+        child2.add_child(DeviceTreeNode::new("grandchild2"));
+        root.add_child(child1);
+        root.add_child(child2);
+
+        // Check breadth-first order
+        let names: Vec<&str> = root.iter_nodes_bfs().map(|n| n.name).collect();
+        assert_eq!(
+            names,
+            ["", "child1", "child2", "grandchild1", "grandchild2", "leaf"]
+        );
+        assert_eq!(root.iter_nodes_bfs().count(), root.iter_nodes().count());
+    }
+
     #[test]
     fn test_property_types() {
         let u32_prop = "u32-prop";
@@ -1997,21 +4657,25 @@ mod tests {
         node.add_property(Property {
             name: u32_prop,
             value: PropertyValue::U32(42),
+            raw: None,
         });
 
         node.add_property(Property {
             name: u64_prop,
             value: PropertyValue::U64(0x123456789),
+            raw: None,
         });
 
         node.add_property(Property {
             name: bytes_prop,
             value: PropertyValue::Bytes(bytes_data),
+            raw: None,
         });
 
         node.add_property(Property {
             name: empty_prop,
             value: PropertyValue::Empty,
+            raw: None,
         });
 
         assert_eq!(node.prop_u32("u32-prop"), Some(42));
@@ -2021,6 +4685,240 @@ mod tests {
         assert!(!node.has_property("nonexistent"));
     }
 
+    #[test]
+    fn test_string_list_accessors() {
+        let mut node = DeviceTreeNode::new("pl011@9000000");
+        node.add_property(Property {
+            name: "clock-names",
+            value: PropertyValue::StringList(vec!["uartclk", "apb_pclk"]),
+            raw: None,
+        });
+        node.add_property(Property {
+            name: "status",
+            value: PropertyValue::String("okay"),
+            raw: None,
+        });
+        node.add_property(Property {
+            name: "clocks",
+            value: PropertyValue::U32(0x8000),
+            raw: None,
+        });
+
+        assert_eq!(node.prop_string_index("clock-names", 0), Some("uartclk"));
+        assert_eq!(node.prop_string_index("clock-names", 1), Some("apb_pclk"));
+        assert_eq!(node.prop_string_index("clock-names", 2), None);
+        assert_eq!(
+            node.prop_string_list("clock-names"),
+            Some(vec!["uartclk", "apb_pclk"])
+        );
+
+        // Single strings behave as a one-element list
+        assert_eq!(node.prop_string_index("status", 0), Some("okay"));
+        assert_eq!(node.prop_string_index("status", 1), None);
+        assert_eq!(node.prop_string_list("status"), Some(vec!["okay"]));
+
+        // Non-string and missing properties
+        assert_eq!(node.prop_string_index("clocks", 0), None);
+        assert_eq!(node.prop_string_list("clocks"), None);
+        assert_eq!(node.prop_string_list("missing"), None);
+    }
+
+    #[test]
+    fn test_try_from_vec_u64() {
+        use core::convert::TryFrom;
+
+        let bytes = [
+            0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, // 0x1_0000_0002
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, // u64::MAX
+        ];
+
+        let wide = PropertyValue::U64Array(&bytes);
+        assert_eq!(
+            Vec::<u64>::try_from(&wide).unwrap(),
+            vec![0x1_0000_0002, u64::MAX]
+        );
+
+        let single = PropertyValue::U64(0x1234_5678_9abc);
+        assert_eq!(
+            Vec::<u64>::try_from(&single).unwrap(),
+            vec![0x1234_5678_9abc]
+        );
+
+        // 32-bit cells are promoted one cell per value
+        let narrow = PropertyValue::U32Array(&bytes[..8]);
+        assert_eq!(Vec::<u64>::try_from(&narrow).unwrap(), vec![1, 2]);
+        assert_eq!(
+            Vec::<u64>::try_from(&PropertyValue::U32(42)).unwrap(),
+            vec![42]
+        );
+
+        // Non-numeric values are rejected like the Vec<u32> conversion
+        assert!(matches!(
+            Vec::<u64>::try_from(&PropertyValue::String("x")),
+            Err(DtbError::InvalidToken)
+        ));
+        assert!(Vec::<u64>::try_from(&PropertyValue::Empty).is_err());
+    }
+
+    #[test]
+    fn test_try_from_fixed_array() {
+        use core::convert::TryFrom;
+
+        let reg = [0x09, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00];
+        let value = PropertyValue::U32Array(&reg);
+
+        let [address, size] = <[u32; 2]>::try_from(&value).unwrap();
+        assert_eq!(address, 0x0900_0000);
+        assert_eq!(size, 0x1000);
+
+        // Length must match exactly
+        assert!(matches!(
+            <[u32; 3]>::try_from(&value),
+            Err(DtbError::InvalidToken)
+        ));
+        assert!(<[u32; 1]>::try_from(&value).is_err());
+
+        // A single cell converts to a one-element array only
+        let single = PropertyValue::U32(42);
+        assert_eq!(<[u32; 1]>::try_from(&single).unwrap(), [42]);
+        assert!(<[u32; 2]>::try_from(&single).is_err());
+        assert!(<[u32; 2]>::try_from(&PropertyValue::String("x")).is_err());
+    }
+
+    #[test]
+    fn test_boolean_properties() {
+        use core::convert::TryFrom;
+
+        let mut node = DeviceTreeNode::new("intc@8000000");
+        node.add_property(Property {
+            name: "interrupt-controller",
+            value: PropertyValue::Empty,
+            raw: None,
+        });
+        node.add_property(Property {
+            name: "#interrupt-cells",
+            value: PropertyValue::U32(3),
+            raw: None,
+        });
+
+        assert!(node.prop_bool("interrupt-controller"));
+        assert!(!node.prop_bool("dma-coherent"));
+
+        assert!(bool::try_from(&node["interrupt-controller"].value).unwrap());
+        assert!(matches!(
+            bool::try_from(&node["#interrupt-cells"].value),
+            Err(DtbError::InvalidToken)
+        ));
+    }
+
+    #[test]
+    fn test_cell_iterators() {
+        use core::convert::TryFrom;
+
+        let bytes = [
+            0x00, 0x00, 0x00, 0x01, 0x12, 0x34, 0x56, 0x78, 0xff, 0xff, 0xff, 0xff,
+        ];
+        let array = PropertyValue::U32Array(&bytes);
+        let collected: Vec<u32> = array.u32_iter().collect();
+        assert_eq!(collected, Vec::<u32>::try_from(&array).unwrap());
+        assert_eq!(collected, vec![1, 0x12345678, 0xffffffff]);
+
+        let single = PropertyValue::U32(42);
+        let collected: Vec<u32> = single.u32_iter().collect();
+        assert_eq!(collected, Vec::<u32>::try_from(&single).unwrap());
+
+        let wide = PropertyValue::U64Array(&bytes[..8]);
+        assert_eq!(wide.u64_iter().collect::<Vec<_>>(), vec![0x1_1234_5678]);
+        assert_eq!(
+            PropertyValue::U64(7).u64_iter().collect::<Vec<_>>(),
+            vec![7]
+        );
+
+        // Non-array variants yield nothing
+        assert_eq!(PropertyValue::String("x").u32_iter().count(), 0);
+        assert_eq!(PropertyValue::Empty.u32_iter().count(), 0);
+        assert_eq!(PropertyValue::U32(1).u64_iter().count(), 0);
+
+        // Cells pair up into words; the unpaired last cell is dropped
+        assert_eq!(array.u64_iter().collect::<Vec<_>>(), vec![0x1_1234_5678]);
+    }
+
+    #[test]
+    fn test_u32_array_checked() {
+        let mut node = DeviceTreeNode::new("widget");
+        node.add_property(Property {
+            name: "cells",
+            value: parse_property_value("cells", &[0, 0, 0, 1, 0, 0, 0, 2]),
+            raw: None,
+        });
+        // Five bytes: one whole cell plus a truncated one
+        node.add_property(Property {
+            name: "truncated",
+            value: parse_property_value("truncated", &[0, 0, 0, 1, 2]),
+            raw: None,
+        });
+        // Encoded "AB\0\0" looks like a string but is still a whole cell
+        node.add_property(Property {
+            name: "stringy",
+            value: PropertyValue::StringList(vec!["AB", ""]),
+            raw: None,
+        });
+
+        assert_eq!(node.prop_u32_array_checked("cells"), Ok(vec![1, 2]));
+        assert_eq!(
+            node.prop_u32_array_checked("truncated"),
+            Err(DtbError::InvalidPropertyLength(5))
+        );
+        assert_eq!(
+            node.prop_u32_array_checked("stringy"),
+            Ok(vec![0x4142_0000])
+        );
+        assert_eq!(
+            node.prop_u32_array_checked("missing"),
+            Err(DtbError::PropertyNotFound)
+        );
+
+        // The lenient accessor hides the truncated property entirely
+        assert_eq!(node.prop_u32_array("truncated"), None);
+        assert_eq!(PropertyValue::Empty.as_u32_array_checked(), Ok(vec![]));
+        assert_eq!(
+            PropertyValue::U64(0x1_0000_0002).as_u32_array_checked(),
+            Ok(vec![1, 2])
+        );
+    }
+
+    #[test]
+    fn test_mac_address_formatting() {
+        let mac_bytes = [0x52, 0x54, 0x00, 0x12, 0x34, 0x56];
+        let mut node = DeviceTreeNode::new("ethernet@10000");
+        node.add_property(Property {
+            name: "local-mac-address",
+            value: PropertyValue::Bytes(&mac_bytes),
+            raw: None,
+        });
+        node.add_property(Property {
+            name: "serial",
+            value: PropertyValue::Bytes(&[0xde, 0xad, 0xbe]),
+            raw: None,
+        });
+
+        let value = &node["local-mac-address"].value;
+        assert_eq!(value.as_hex_string(), "52:54:00:12:34:56");
+        assert_eq!(value.mac_address(), Some(mac_bytes));
+        assert_eq!(node.prop_mac("local-mac-address"), Some(mac_bytes));
+
+        // Other lengths render as plain hex and are not MAC addresses
+        assert_eq!(node["serial"].value.as_hex_string(), "deadbe");
+        assert_eq!(node.prop_mac("serial"), None);
+        assert_eq!(node.prop_mac("missing"), None);
+
+        // Non-byte variants use their encoded form
+        assert_eq!(PropertyValue::U32(0x1234).as_hex_string(), "00001234");
+        assert_eq!(PropertyValue::String("ok").as_hex_string(), "6f6b00");
+        assert_eq!(PropertyValue::Empty.as_hex_string(), "");
+        assert_eq!(PropertyValue::U32(0x1234).mac_address(), None);
+    }
+
     #[test]
     fn test_ergonomic_traits() {
         use core::convert::TryFrom;
@@ -2032,16 +4930,19 @@ mod tests {
         node.add_property(Property {
             name: "test-u32",
             value: PropertyValue::U32(42),
+            raw: None,
         });
 
         node.add_property(Property {
             name: "test-string",
             value: PropertyValue::String("hello"),
+            raw: None,
         });
 
         child.add_property(Property {
             name: "child-prop",
             value: PropertyValue::U32(100),
+            raw: None,
         });
 
         node.add_child(child);
@@ -2078,6 +4979,78 @@ mod tests {
         assert_eq!(default_value, PropertyValue::Empty);
     }
 
+    #[test]
+    fn test_checked_access() {
+        let mut node = DeviceTreeNode::new("test");
+        node.add_property(Property {
+            name: "test-u32",
+            value: PropertyValue::U32(42),
+            raw: None,
+        });
+        node.add_child(DeviceTreeNode::new("child"));
+
+        // Present entries mirror the Index impls
+        assert_eq!(node.get("test-u32").unwrap().name, node["test-u32"].name);
+        assert_eq!(node.get_child(0).unwrap().name, node[0].name);
+
+        // Missing entries return None instead of panicking
+        assert!(node.get("missing").is_none());
+        assert!(node.get_child(1).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "Property 'missing' not found")]
+    fn test_index_missing_property_panics() {
+        let node = DeviceTreeNode::new("test");
+        let _ = &node["missing"];
+    }
+
+    #[test]
+    fn test_property_mutation() {
+        let mut node = DeviceTreeNode::new("test");
+        node.add_property(Property {
+            name: "status",
+            value: PropertyValue::String("disabled"),
+            raw: None,
+        });
+        node.add_property(Property {
+            name: "clock-frequency",
+            value: PropertyValue::U32(1000),
+            raw: None,
+        });
+
+        // IndexMut updates the value in place
+        node["status"].value = PropertyValue::String("okay");
+        assert_eq!(node.prop_string("status"), Some("okay"));
+
+        // set_property replaces an existing property
+        let previous = node.set_property(Property {
+            name: "clock-frequency",
+            value: PropertyValue::U32(2000),
+            raw: None,
+        });
+        assert_eq!(previous.unwrap().value, PropertyValue::U32(1000));
+        assert_eq!(node.prop_u32("clock-frequency"), Some(2000));
+        assert_eq!(node.properties.len(), 2);
+
+        // set_property appends a new property
+        assert!(
+            node.set_property(Property {
+                name: "new-prop",
+                value: PropertyValue::Empty,
+                raw: None,
+            })
+            .is_none()
+        );
+        assert_eq!(node.properties.len(), 3);
+
+        // remove_property returns the removed property
+        let removed = node.remove_property("status").unwrap();
+        assert_eq!(removed.value, PropertyValue::String("okay"));
+        assert!(!node.has_property("status"));
+        assert!(node.remove_property("status").is_none());
+    }
+
     #[test]
     fn test_address_cells_parsing() {
         // Test node with explicit #address-cells property
@@ -2085,6 +5058,7 @@ mod tests {
         node.add_property(Property {
             name: "#address-cells",
             value: PropertyValue::U32(2),
+            raw: None,
         });
 
         assert_eq!(node.address_cells().unwrap(), 2);
@@ -2094,6 +5068,7 @@ mod tests {
         invalid_node.add_property(Property {
             name: "#address-cells",
             value: PropertyValue::U32(0),
+            raw: None,
         });
 
         assert!(matches!(
@@ -2106,6 +5081,7 @@ mod tests {
         invalid_node2.add_property(Property {
             name: "#address-cells",
             value: PropertyValue::U32(5),
+            raw: None,
         });
 
         assert!(matches!(
@@ -2128,6 +5104,7 @@ mod tests {
         node.add_property(Property {
             name: "#size-cells",
             value: PropertyValue::U32(1),
+            raw: None,
         });
 
         assert_eq!(node.size_cells().unwrap(), 1);
@@ -2137,6 +5114,7 @@ mod tests {
         zero_size_node.add_property(Property {
             name: "#size-cells",
             value: PropertyValue::U32(0),
+            raw: None,
         });
 
         assert_eq!(zero_size_node.size_cells().unwrap(), 0);
@@ -2146,6 +5124,7 @@ mod tests {
         invalid_node.add_property(Property {
             name: "#size-cells",
             value: PropertyValue::U32(5),
+            raw: None,
         });
 
         assert!(matches!(
@@ -2168,6 +5147,7 @@ mod tests {
         parent.add_property(Property {
             name: "#address-cells",
             value: PropertyValue::U32(3),
+            raw: None,
         });
 
         // Create child node without #address-cells
@@ -2181,6 +5161,7 @@ mod tests {
         child_with_prop.add_property(Property {
             name: "#address-cells",
             value: PropertyValue::U32(1),
+            raw: None,
         });
 
         assert_eq!(
@@ -2201,6 +5182,7 @@ mod tests {
         invalid_parent.add_property(Property {
             name: "#address-cells",
             value: PropertyValue::U32(0),
+            raw: None,
         });
 
         assert!(matches!(
@@ -2216,6 +5198,7 @@ mod tests {
         parent.add_property(Property {
             name: "#size-cells",
             value: PropertyValue::U32(2),
+            raw: None,
         });
 
         // Create child node without #size-cells
@@ -2229,6 +5212,7 @@ mod tests {
         child_with_prop.add_property(Property {
             name: "#size-cells",
             value: PropertyValue::U32(0),
+            raw: None,
         });
 
         assert_eq!(
@@ -2252,10 +5236,12 @@ mod tests {
         node.add_property(Property {
             name: "#address-cells",
             value: PropertyValue::U32(2),
+            raw: None,
         });
         node.add_property(Property {
             name: "#size-cells",
             value: PropertyValue::U32(1),
+            raw: None,
         });
 
         let spec = node.create_address_spec(None).unwrap();
@@ -2268,10 +5254,12 @@ mod tests {
         parent.add_property(Property {
             name: "#address-cells",
             value: PropertyValue::U32(1),
+            raw: None,
         });
         parent.add_property(Property {
             name: "#size-cells",
             value: PropertyValue::U32(2),
+            raw: None,
         });
 
         let child = DeviceTreeNode::new("child");
@@ -2387,16 +5375,71 @@ mod tests {
             parse_address_from_bytes(&bytes1, 0),
             Err(DtbError::MalformedHeader)
         ));
-        // 5 cells with correct length should fail on the match
-        let bytes5 = [0u8; 20]; // 5 cells * 4 bytes
+        // 5 cells with correct length should fail on the match
+        let bytes5 = [0u8; 20]; // 5 cells * 4 bytes
+        assert!(matches!(
+            parse_address_from_bytes(&bytes5, 5),
+            Err(DtbError::InvalidAddressCells(5))
+        ));
+
+        // Test invalid byte length
+        assert!(matches!(
+            parse_address_from_bytes(&bytes1[..3], 1),
+            Err(DtbError::MalformedHeader)
+        ));
+    }
+
+    #[test]
+    fn test_parse_address_from_bytes_full() {
+        // 1-cell and 2-cell addresses match the u64 variant
+        let bytes1 = [0x12, 0x34, 0x56, 0x78];
+        assert_eq!(
+            parse_address_from_bytes_full(&bytes1, 1).unwrap(),
+            0x12345678
+        );
+
+        let bytes2 = [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0];
+        assert_eq!(
+            parse_address_from_bytes_full(&bytes2, 2).unwrap(),
+            0x123456789ABCDEF0
+        );
+
+        // 3-cell address keeps the high cell
+        let bytes3 = [
+            0x00, 0x11, 0x22, 0x33, // First cell (retained)
+            0x44, 0x55, 0x66, 0x77, // Second cell
+            0x88, 0x99, 0xAA, 0xBB, // Third cell
+        ];
+        assert_eq!(
+            parse_address_from_bytes_full(&bytes3, 3).unwrap(),
+            0x00112233_44556677_8899AABB
+        );
+
+        // 4-cell address keeps all 128 bits
+        let bytes4 = [
+            0x00, 0x11, 0x22, 0x33, // First cell (retained)
+            0x44, 0x55, 0x66, 0x77, // Second cell (retained)
+            0x88, 0x99, 0xAA, 0xBB, // Third cell
+            0xCC, 0xDD, 0xEE, 0xFF, // Fourth cell
+        ];
+        assert_eq!(
+            parse_address_from_bytes_full(&bytes4, 4).unwrap(),
+            0x00112233_44556677_8899AABB_CCDDEEFF
+        );
+
+        // Invalid cell counts are rejected before the length check
+        assert!(matches!(
+            parse_address_from_bytes_full(&[], 0),
+            Err(DtbError::InvalidAddressCells(0))
+        ));
         assert!(matches!(
-            parse_address_from_bytes(&bytes5, 5),
+            parse_address_from_bytes_full(&[0u8; 20], 5),
             Err(DtbError::InvalidAddressCells(5))
         ));
 
-        // Test invalid byte length
+        // Length mismatch is rejected
         assert!(matches!(
-            parse_address_from_bytes(&bytes1[..3], 1),
+            parse_address_from_bytes_full(&bytes4[..12], 4),
             Err(DtbError::MalformedHeader)
         ));
     }
@@ -2408,6 +5451,7 @@ mod tests {
         node.add_property(Property {
             name: "ranges",
             value: PropertyValue::Empty,
+            raw: None,
         });
 
         let ranges = node.ranges(None, 2).unwrap();
@@ -2429,10 +5473,12 @@ mod tests {
         node.add_property(Property {
             name: "#address-cells",
             value: PropertyValue::U32(2),
+            raw: None,
         });
         node.add_property(Property {
             name: "#size-cells",
             value: PropertyValue::U32(1),
+            raw: None,
         });
 
         // Create ranges data: child_addr(2 cells) + parent_addr(2 cells) + size(1 cell)
@@ -2455,6 +5501,7 @@ mod tests {
         node.add_property(Property {
             name: "ranges",
             value: PropertyValue::Bytes(&ranges_data),
+            raw: None,
         });
 
         let ranges = node.ranges(None, 2).unwrap();
@@ -2479,10 +5526,12 @@ mod tests {
         node.add_property(Property {
             name: "#address-cells",
             value: PropertyValue::U32(2),
+            raw: None,
         });
         node.add_property(Property {
             name: "#size-cells",
             value: PropertyValue::U32(1),
+            raw: None,
         });
 
         // Invalid ranges data (not multiple of entry size)
@@ -2491,6 +5540,7 @@ mod tests {
         node.add_property(Property {
             name: "ranges",
             value: PropertyValue::Bytes(&invalid_data),
+            raw: None,
         });
 
         assert!(matches!(
@@ -2506,10 +5556,12 @@ mod tests {
         parent.add_property(Property {
             name: "#address-cells",
             value: PropertyValue::U32(1),
+            raw: None,
         });
         parent.add_property(Property {
             name: "#size-cells",
             value: PropertyValue::U32(1),
+            raw: None,
         });
 
         // Create child node without cell properties (inherits from parent)
@@ -2528,6 +5580,7 @@ mod tests {
         child.add_property(Property {
             name: "ranges",
             value: PropertyValue::Bytes(&ranges_data),
+            raw: None,
         });
 
         let ranges = child.ranges(Some(&parent), 2).unwrap();
@@ -2539,6 +5592,361 @@ mod tests {
         assert_eq!(range.size(), 0x1000);
     }
 
+    #[test]
+    fn test_decode_ranges_cell_widths() {
+        // 1+1+1 cells: child=0x1000, parent=0x4000_1000, size=0x100
+        let data = [
+            0x00, 0x00, 0x10, 0x00, 0x40, 0x00, 0x10, 0x00, 0x00, 0x00, 0x01, 0x00,
+        ];
+        let ranges = decode_ranges(&data, 1, 1, 1).unwrap();
+        assert_eq!(ranges.len(), 1);
+        assert_eq!(ranges[0].child_address(), 0x1000);
+        assert_eq!(ranges[0].parent_address(), 0x4000_1000);
+        assert_eq!(ranges[0].size(), 0x100);
+
+        // 3+2+2 cells (PCI-style child): high child cell is dropped
+        let data = [
+            0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, // child
+            0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, // parent
+            0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, // size
+        ];
+        let ranges = decode_ranges(&data, 3, 2, 2).unwrap();
+        assert_eq!(ranges.len(), 1);
+        assert_eq!(ranges[0].child_address(), 0x1000_0000);
+        assert_eq!(ranges[0].parent_address(), 0x1000_0000);
+        assert_eq!(ranges[0].size(), 0x1_0000_0000);
+
+        // Two 1+2+1 entries; a trailing partial entry is rejected
+        let data = [
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x10, 0x00, //
+            0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00,
+            0x10, 0x00,
+        ];
+        let ranges = decode_ranges(&data, 1, 2, 1).unwrap();
+        assert_eq!(ranges.len(), 2);
+        assert_eq!(ranges[1].child_address(), 0x2000);
+        assert_eq!(ranges[1].parent_address(), 0x1_0000_2000);
+        assert!(matches!(
+            decode_ranges(&data[..28], 1, 2, 1),
+            Err(DtbError::InvalidRangesFormat)
+        ));
+        assert!(decode_ranges(&[], 2, 2, 1).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_decode_ranges_degenerate_cells() {
+        let data = [0u8; 8];
+        for (child, parent, size) in [
+            (1, 0, 1),
+            (0, 1, 1),
+            (0, 0, 0),
+            (5, 1, 1),
+            (1, 1, 5),
+            (u32::MAX, 1, 1),
+        ] {
+            assert_eq!(
+                decode_ranges(&data, child, parent, size),
+                Err(DtbError::InvalidRangesFormat),
+                "{child}+{parent}+{size} cells"
+            );
+            // Nothing to decode is still rejected rather than looping
+            assert_eq!(
+                decode_ranges(&[], child, parent, size),
+                Err(DtbError::InvalidRangesFormat)
+            );
+        }
+    }
+
+    #[test]
+    fn test_ranges_zero_address_cells() {
+        // Eight cells of ranges, read with a zero-width child address
+        let mut bus = DeviceTreeNode::new("bus");
+        bus.add_property(Property {
+            name: "ranges",
+            value: PropertyValue::U32Array(&[0; 32]),
+            raw: None,
+        });
+        assert_eq!(bus.ranges(None, 0), Err(DtbError::InvalidRangesFormat));
+        assert_eq!(bus.ranges_kind(None, 0), Err(DtbError::InvalidRangesFormat));
+        assert_eq!(bus.ranges(None, 5), Err(DtbError::InvalidRangesFormat));
+
+        // A zero #address-cells on the bus itself is caught by cell validation
+        bus.add_property(Property {
+            name: "#address-cells",
+            value: PropertyValue::U32(0),
+            raw: None,
+        });
+        assert_eq!(bus.ranges(None, 2), Err(DtbError::InvalidAddressCells(0)));
+    }
+
+    #[test]
+    fn test_ranges_kind() {
+        // No ranges property
+        let mut node = DeviceTreeNode::new("bus");
+        assert_eq!(node.ranges_kind(None, 1).unwrap(), RangesKind::None);
+
+        // Empty ranges property (1:1 mapping)
+        node.add_property(Property {
+            name: "ranges",
+            value: PropertyValue::Empty,
+            raw: None,
+        });
+        assert_eq!(node.ranges_kind(None, 1).unwrap(), RangesKind::Identity);
+        assert!(node.ranges(None, 1).unwrap().is_empty());
+
+        // Explicit range: child=0x0, parent=0x4000_0000, size=0x1000 (1+1+1 cells)
+        let ranges_data = [
+            0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00,
+        ];
+        node.add_property(Property {
+            name: "#address-cells",
+            value: PropertyValue::U32(1),
+            raw: None,
+        });
+        node.add_property(Property {
+            name: "#size-cells",
+            value: PropertyValue::U32(1),
+            raw: None,
+        });
+        node.set_property(Property {
+            name: "ranges",
+            value: PropertyValue::Bytes(&ranges_data),
+            raw: None,
+        });
+        assert_eq!(
+            node.ranges_kind(None, 1).unwrap(),
+            RangesKind::Explicit(vec![AddressRange::new(0x0, 0x4000_0000, 0x1000).unwrap()])
+        );
+
+        // Malformed data is still reported as an error
+        node.set_property(Property {
+            name: "ranges",
+            value: PropertyValue::Bytes(&ranges_data[..8]),
+            raw: None,
+        });
+        assert!(matches!(
+            node.ranges_kind(None, 1),
+            Err(DtbError::InvalidRangesFormat)
+        ));
+    }
+
+    #[test]
+    fn test_dma_ranges_parsing_empty_property() {
+        // Test node with empty dma-ranges property (1:1 mapping)
+        let mut node = DeviceTreeNode::new("test");
+        node.add_property(Property {
+            name: "dma-ranges",
+            value: PropertyValue::Empty,
+            raw: None,
+        });
+
+        let ranges = node.dma_ranges(None, 2).unwrap();
+        assert!(ranges.is_empty());
+    }
+
+    #[test]
+    fn test_dma_ranges_parsing_no_property() {
+        // Test node without dma-ranges property
+        let node = DeviceTreeNode::new("test");
+        let ranges = node.dma_ranges(None, 2).unwrap();
+        assert!(ranges.is_empty());
+    }
+
+    #[test]
+    fn test_dma_ranges_parsing_with_data() {
+        // Create a node with 2 address cells, 1 size cell
+        let mut node = DeviceTreeNode::new("test");
+        node.add_property(Property {
+            name: "#address-cells",
+            value: PropertyValue::U32(2),
+            raw: None,
+        });
+        node.add_property(Property {
+            name: "#size-cells",
+            value: PropertyValue::U32(1),
+            raw: None,
+        });
+
+        // Create dma-ranges data: child_addr(2 cells) + parent_addr(2 cells) + size(1 cell)
+        // Range 1: child=0x0, parent=0x80000000, size=0x10000
+        // Range 2: child=0x20000, parent=0x90000000, size=0x8000
+        let ranges_data = vec![
+            // Range 1: child address (0x0 as 2 cells)
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            // Range 1: parent address (0x80000000 as 2 cells)
+            0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00,
+            // Range 1: size (0x10000 as 1 cell)
+            0x00, 0x01, 0x00, 0x00, // Range 2: child address (0x20000 as 2 cells)
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00,
+            // Range 2: parent address (0x90000000 as 2 cells)
+            0x00, 0x00, 0x00, 0x00, 0x90, 0x00, 0x00, 0x00,
+            // Range 2: size (0x8000 as 1 cell)
+            0x00, 0x00, 0x80, 0x00,
+        ];
+
+        node.add_property(Property {
+            name: "dma-ranges",
+            value: PropertyValue::Bytes(&ranges_data),
+            raw: None,
+        });
+
+        let ranges = node.dma_ranges(None, 2).unwrap();
+        assert_eq!(ranges.len(), 2);
+
+        // Check first range
+        let range1 = &ranges[0];
+        assert_eq!(range1.child_address(), 0x0);
+        assert_eq!(range1.parent_address(), 0x80000000);
+        assert_eq!(range1.size(), 0x10000);
+
+        // Check second range
+        let range2 = &ranges[1];
+        assert_eq!(range2.child_address(), 0x20000);
+        assert_eq!(range2.parent_address(), 0x90000000);
+        assert_eq!(range2.size(), 0x8000);
+
+        // dma-ranges does not affect CPU address translation
+        assert!(node.ranges(None, 2).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_dma_ranges_parsing_invalid_format() {
+        let mut node = DeviceTreeNode::new("test");
+        node.add_property(Property {
+            name: "#address-cells",
+            value: PropertyValue::U32(2),
+            raw: None,
+        });
+        node.add_property(Property {
+            name: "#size-cells",
+            value: PropertyValue::U32(1),
+            raw: None,
+        });
+
+        // Invalid dma-ranges data (not multiple of entry size)
+        let invalid_data = vec![0u8; 19];
+        node.add_property(Property {
+            name: "dma-ranges",
+            value: PropertyValue::Bytes(&invalid_data),
+            raw: None,
+        });
+
+        assert!(matches!(
+            node.dma_ranges(None, 2),
+            Err(DtbError::InvalidRangesFormat)
+        ));
+    }
+
+    #[test]
+    fn test_pci_ranges_parsing() {
+        use crate::dtb::pci::PciSpace;
+
+        let mut pci = DeviceTreeNode::new("pcie@10000000");
+        pci.add_property(Property {
+            name: "#address-cells",
+            value: PropertyValue::U32(3),
+            raw: None,
+        });
+        pci.add_property(Property {
+            name: "#size-cells",
+            value: PropertyValue::U32(2),
+            raw: None,
+        });
+
+        // QEMU virt PCIe host ranges: I/O, 32-bit memory, prefetchable 64-bit memory
+        let cells: [u32; 21] = [
+            0x0100_0000,
+            0x0,
+            0x0,
+            0x0,
+            0x3eff_0000,
+            0x0,
+            0x1_0000, // I/O
+            0x0200_0000,
+            0x0,
+            0x1000_0000,
+            0x0,
+            0x1000_0000,
+            0x0,
+            0x2eff_0000, // MEM32
+            0x4300_0000,
+            0x80,
+            0x0,
+            0x80,
+            0x0,
+            0x80,
+            0x0, // prefetchable MEM64
+        ];
+        let ranges_data: Vec<u8> = cells.iter().flat_map(|c| c.to_be_bytes()).collect();
+        pci.add_property(Property {
+            name: "ranges",
+            value: PropertyValue::U32Array(&ranges_data),
+            raw: None,
+        });
+
+        let ranges = pci.pci_ranges(None).unwrap();
+        assert_eq!(ranges.len(), 3);
+
+        assert_eq!(ranges[0].child.space, PciSpace::Io);
+        assert_eq!(ranges[0].child.address, 0x0);
+        assert_eq!(ranges[0].parent_address, 0x3eff_0000);
+        assert_eq!(ranges[0].size, 0x1_0000);
+
+        assert_eq!(ranges[1].child.space, PciSpace::Memory32);
+        assert_eq!(ranges[1].child.address, 0x1000_0000);
+        assert_eq!(ranges[1].parent_address, 0x1000_0000);
+        assert_eq!(ranges[1].size, 0x2eff_0000);
+
+        assert_eq!(ranges[2].child.space, PciSpace::Memory64);
+        assert!(ranges[2].child.prefetchable);
+        assert_eq!(ranges[2].child.address, 0x80_0000_0000);
+        assert_eq!(ranges[2].parent_address, 0x80_0000_0000);
+        assert_eq!(ranges[2].size, 0x80_0000_0000);
+
+        // Truncated entry is rejected
+        pci.properties.retain(|p| p.name != "ranges");
+        pci.add_property(Property {
+            name: "ranges",
+            value: PropertyValue::U32Array(&ranges_data[..24]),
+            raw: None,
+        });
+        assert!(matches!(
+            pci.pci_ranges(None),
+            Err(DtbError::InvalidRangesFormat)
+        ));
+
+        // A 32-bit parent uses one cell for the parent address
+        let mut root = DeviceTreeNode::new("");
+        root.add_property(Property {
+            name: "#address-cells",
+            value: PropertyValue::U32(1),
+            raw: None,
+        });
+        let narrow: Vec<u8> = [0x0200_0000, 0x0, 0x1000_0000, 0x1000_0000, 0x0, 0x2eff_0000]
+            .iter()
+            .flat_map(|c: &u32| c.to_be_bytes())
+            .collect();
+        pci.properties.retain(|p| p.name != "ranges");
+        pci.add_property(Property {
+            name: "ranges",
+            value: PropertyValue::U32Array(&narrow),
+            raw: None,
+        });
+        let ranges = pci.pci_ranges(Some(&root)).unwrap();
+        assert_eq!(ranges.len(), 1);
+        assert_eq!(ranges[0].child.space, PciSpace::Memory32);
+        assert_eq!(ranges[0].parent_address, 0x1000_0000);
+        assert_eq!(ranges[0].size, 0x2eff_0000);
+
+        // Only 3-cell child addresses are PCI addresses
+        pci.properties.retain(|p| p.name != "#address-cells");
+        assert!(matches!(
+            pci.pci_ranges(Some(&root)),
+            Err(DtbError::InvalidRangesFormat)
+        ));
+    }
+
     #[test]
     fn test_translate_address_successful() {
         // Create a node with address translation ranges
@@ -2546,10 +5954,12 @@ mod tests {
         node.add_property(Property {
             name: "#address-cells",
             value: PropertyValue::U32(2),
+            raw: None,
         });
         node.add_property(Property {
             name: "#size-cells",
             value: PropertyValue::U32(1),
+            raw: None,
         });
 
         // Create ranges data: child_addr(2 cells) + parent_addr(2 cells) + size(1 cell)
@@ -2565,6 +5975,7 @@ mod tests {
         node.add_property(Property {
             name: "ranges",
             value: PropertyValue::Bytes(&ranges_data),
+            raw: None,
         });
 
         // Test successful translation
@@ -2587,10 +5998,12 @@ mod tests {
         node.add_property(Property {
             name: "#address-cells",
             value: PropertyValue::U32(2),
+            raw: None,
         });
         node.add_property(Property {
             name: "#size-cells",
             value: PropertyValue::U32(1),
+            raw: None,
         });
 
         // Create ranges data: child=0x1000, parent=0x80001000, size=0x1000
@@ -2603,6 +6016,7 @@ mod tests {
         node.add_property(Property {
             name: "ranges",
             value: PropertyValue::Bytes(&ranges_data),
+            raw: None,
         });
 
         // Test address outside range (below)
@@ -2625,6 +6039,7 @@ mod tests {
         node.add_property(Property {
             name: "ranges",
             value: PropertyValue::Empty,
+            raw: None,
         });
 
         // Test 1:1 translation
@@ -2647,6 +6062,131 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_translate_dma_address() {
+        let mut node = DeviceTreeNode::new("test");
+        node.add_property(Property {
+            name: "#address-cells",
+            value: PropertyValue::U32(2),
+            raw: None,
+        });
+        node.add_property(Property {
+            name: "#size-cells",
+            value: PropertyValue::U32(1),
+            raw: None,
+        });
+
+        // Create dma-ranges data: child=0x1000, parent=0x80001000, size=0x1000
+        let ranges_data = vec![
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, // child address
+            0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x10, 0x00, // parent address
+            0x00, 0x00, 0x10, 0x00, // size
+        ];
+
+        node.add_property(Property {
+            name: "dma-ranges",
+            value: PropertyValue::Bytes(&ranges_data),
+            raw: None,
+        });
+
+        assert_eq!(
+            node.translate_dma_address(0x1500, None, 2).unwrap(),
+            0x80001500
+        );
+        assert!(matches!(
+            node.translate_dma_address(0x3000, None, 2),
+            Err(DtbError::AddressTranslationError(0x3000))
+        ));
+
+        // Without `ranges`, CPU address translation is unavailable
+        assert!(matches!(
+            node.translate_address(0x1500, None, 2),
+            Err(DtbError::AddressTranslationError(0x1500))
+        ));
+    }
+
+    #[test]
+    fn test_translate_dma_address_empty_and_missing() {
+        let mut node = DeviceTreeNode::new("test");
+        assert!(matches!(
+            node.translate_dma_address(0x1000, None, 2),
+            Err(DtbError::AddressTranslationError(0x1000))
+        ));
+
+        // Empty dma-ranges means 1:1 mapping
+        node.add_property(Property {
+            name: "dma-ranges",
+            value: PropertyValue::Empty,
+            raw: None,
+        });
+        assert_eq!(node.translate_dma_address(0x1234, None, 2).unwrap(), 0x1234);
+    }
+
+    #[test]
+    fn test_address_range_reverse() {
+        let range = AddressRange::new(0x1000, 0x8000_1000, 0x1000).unwrap();
+
+        assert!(range.contains_parent(0x8000_1000));
+        assert!(range.contains_parent(0x8000_1fff));
+        assert!(!range.contains_parent(0x8000_2000));
+        assert!(!range.contains_parent(0x8000_0fff));
+
+        assert_eq!(range.translate_reverse(0x8000_1500), Ok(0x1500));
+        assert_eq!(
+            range.translate_reverse(0x8000_2000),
+            Err(DtbError::AddressTranslationError(0x8000_2000))
+        );
+        assert_eq!(
+            range.translate(range.translate_reverse(0x8000_1abc).unwrap()),
+            Ok(0x8000_1abc)
+        );
+    }
+
+    #[test]
+    fn test_untranslate_address() {
+        let mut node = DeviceTreeNode::new("test");
+        node.add_property(Property {
+            name: "#address-cells",
+            value: PropertyValue::U32(1),
+            raw: None,
+        });
+        node.add_property(Property {
+            name: "#size-cells",
+            value: PropertyValue::U32(1),
+            raw: None,
+        });
+        assert_eq!(
+            node.untranslate_address(0x9000_0000, None, 1),
+            Err(DtbError::AddressTranslationError(0x9000_0000))
+        );
+
+        // Two ranges: 0x0 -> 0x80000000 and 0x10000 -> 0x90000000, 0x1000 each
+        let ranges_data = vec![
+            0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, //
+            0x00, 0x01, 0x00, 0x00, 0x90, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00,
+        ];
+        node.add_property(Property {
+            name: "ranges",
+            value: PropertyValue::Bytes(&ranges_data),
+            raw: None,
+        });
+
+        assert_eq!(node.untranslate_address(0x8000_0040, None, 1), Ok(0x40));
+        assert_eq!(node.untranslate_address(0x9000_0ffc, None, 1), Ok(0x1_0ffc));
+        assert_eq!(
+            node.untranslate_address(0x8000_1000, None, 1),
+            Err(DtbError::AddressTranslationError(0x8000_1000))
+        );
+
+        // Empty ranges maps 1:1
+        node.set_property(Property {
+            name: "ranges",
+            value: PropertyValue::Empty,
+            raw: None,
+        });
+        assert_eq!(node.untranslate_address(0x1234, None, 1), Ok(0x1234));
+    }
+
     #[test]
     fn test_translate_address_multiple_ranges() {
         // Create a node with multiple address translation ranges
@@ -2654,10 +6194,12 @@ mod tests {
         node.add_property(Property {
             name: "#address-cells",
             value: PropertyValue::U32(2),
+            raw: None,
         });
         node.add_property(Property {
             name: "#size-cells",
             value: PropertyValue::U32(1),
+            raw: None,
         });
 
         // Create ranges data with multiple ranges:
@@ -2680,6 +6222,7 @@ mod tests {
         node.add_property(Property {
             name: "ranges",
             value: PropertyValue::Bytes(&ranges_data),
+            raw: None,
         });
 
         // Test translation in first range
@@ -2704,10 +6247,12 @@ mod tests {
         parent.add_property(Property {
             name: "#address-cells",
             value: PropertyValue::U32(1),
+            raw: None,
         });
         parent.add_property(Property {
             name: "#size-cells",
             value: PropertyValue::U32(1),
+            raw: None,
         });
 
         // Create child node that inherits parent's cells
@@ -2726,6 +6271,7 @@ mod tests {
         child.add_property(Property {
             name: "ranges",
             value: PropertyValue::Bytes(&ranges_data),
+            raw: None,
         });
 
         // Test translation with parent inheritance
@@ -2740,10 +6286,12 @@ mod tests {
         node.add_property(Property {
             name: "#address-cells",
             value: PropertyValue::U32(1),
+            raw: None,
         });
         node.add_property(Property {
             name: "#size-cells",
             value: PropertyValue::U32(1),
+            raw: None,
         });
 
         // Create ranges data: child=0x1000, parent=0x2000, size=0x1000
@@ -2757,6 +6305,7 @@ mod tests {
         node.add_property(Property {
             name: "ranges",
             value: PropertyValue::Bytes(&ranges_data),
+            raw: None,
         });
 
         // Test exactly at start of range
@@ -2787,10 +6336,12 @@ mod tests {
         node.add_property(Property {
             name: "#address-cells",
             value: PropertyValue::U32(1),
+            raw: None,
         });
         node.add_property(Property {
             name: "#size-cells",
             value: PropertyValue::U32(1),
+            raw: None,
         });
 
         // Create ranges data: child=0x1000, parent=0x1000, size=0x1000 (no translation)
@@ -2803,6 +6354,7 @@ mod tests {
         node.add_property(Property {
             name: "ranges",
             value: PropertyValue::Bytes(&ranges_data),
+            raw: None,
         });
 
         let translated = node.translate_address(0x1500, None, 1).unwrap();
@@ -2816,10 +6368,12 @@ mod tests {
         node.add_property(Property {
             name: "#address-cells",
             value: PropertyValue::U32(2),
+            raw: None,
         });
         node.add_property(Property {
             name: "#size-cells",
             value: PropertyValue::U32(2),
+            raw: None,
         });
 
         // Create ranges data with large addresses
@@ -2836,6 +6390,7 @@ mod tests {
         node.add_property(Property {
             name: "ranges",
             value: PropertyValue::Bytes(&ranges_data),
+            raw: None,
         });
 
         let translated = node.translate_address(0x150000000, None, 2).unwrap();
@@ -2849,10 +6404,12 @@ mod tests {
         node.add_property(Property {
             name: "#address-cells",
             value: PropertyValue::U32(2),
+            raw: None,
         });
         node.add_property(Property {
             name: "#size-cells",
             value: PropertyValue::U32(1),
+            raw: None,
         });
 
         // Create ranges data: child=0x1000, parent=0x80001000, size=0x1000
@@ -2865,6 +6422,7 @@ mod tests {
         node.add_property(Property {
             name: "ranges",
             value: PropertyValue::Bytes(&ranges_data),
+            raw: None,
         });
 
         // Test recursive translation
@@ -2889,6 +6447,7 @@ mod tests {
         node.add_property(Property {
             name: "ranges",
             value: PropertyValue::Empty,
+            raw: None,
         });
 
         // Should return the original address unchanged
@@ -2903,10 +6462,12 @@ mod tests {
         node.add_property(Property {
             name: "#address-cells",
             value: PropertyValue::U32(1),
+            raw: None,
         });
         node.add_property(Property {
             name: "#size-cells",
             value: PropertyValue::U32(1),
+            raw: None,
         });
 
         // Create ranges that would normally translate
@@ -2919,6 +6480,7 @@ mod tests {
         node.add_property(Property {
             name: "ranges",
             value: PropertyValue::Bytes(&ranges_data),
+            raw: None,
         });
 
         // Test with depth limit of 0 (should exceed immediately)
@@ -2935,10 +6497,12 @@ mod tests {
         node.add_property(Property {
             name: "#address-cells",
             value: PropertyValue::U32(1),
+            raw: None,
         });
         node.add_property(Property {
             name: "#size-cells",
             value: PropertyValue::U32(1),
+            raw: None,
         });
 
         // The cycle detection will prevent infinite recursion on the same node
@@ -2955,6 +6519,7 @@ mod tests {
         node.add_property(Property {
             name: "ranges",
             value: PropertyValue::Bytes(&ranges_data),
+            raw: None,
         });
 
         // This should fail with translation error since 0x1000 is not in the range
@@ -2971,10 +6536,12 @@ mod tests {
         node.add_property(Property {
             name: "#address-cells",
             value: PropertyValue::U32(1),
+            raw: None,
         });
         node.add_property(Property {
             name: "#size-cells",
             value: PropertyValue::U32(1),
+            raw: None,
         });
 
         // Create invalid ranges data (wrong size)
@@ -2983,6 +6550,7 @@ mod tests {
         node.add_property(Property {
             name: "ranges",
             value: PropertyValue::Bytes(&invalid_ranges_data),
+            raw: None,
         });
 
         // Should fail with ranges format error
@@ -2999,10 +6567,12 @@ mod tests {
         bus_node.add_property(Property {
             name: "#address-cells",
             value: PropertyValue::U32(2),
+            raw: None,
         });
         bus_node.add_property(Property {
             name: "#size-cells",
             value: PropertyValue::U32(1),
+            raw: None,
         });
 
         // Create ranges that map 0x1000-0x1FFF to 0x90001000-0x90001FFF
@@ -3017,6 +6587,7 @@ mod tests {
         bus_node.add_property(Property {
             name: "ranges",
             value: PropertyValue::Bytes(&ranges_data),
+            raw: None,
         });
 
         // Test successful recursive translation
@@ -3037,10 +6608,12 @@ mod tests {
         device.add_property(Property {
             name: "#address-cells",
             value: PropertyValue::U32(2),
+            raw: None,
         });
         device.add_property(Property {
             name: "#size-cells",
             value: PropertyValue::U32(1),
+            raw: None,
         });
 
         // Add reg property with device addresses
@@ -3056,6 +6629,7 @@ mod tests {
         device.add_property(Property {
             name: "reg",
             value: PropertyValue::U32Array(&reg_data),
+            raw: None,
         });
 
         // Add ranges for translation
@@ -3069,6 +6643,7 @@ mod tests {
         device.add_property(Property {
             name: "ranges",
             value: PropertyValue::Bytes(&ranges_data),
+            raw: None,
         });
 
         // Test address translation
@@ -3091,10 +6666,12 @@ mod tests {
         device.add_property(Property {
             name: "#address-cells",
             value: PropertyValue::U32(1),
+            raw: None,
         });
         device.add_property(Property {
             name: "#size-cells",
             value: PropertyValue::U32(1),
+            raw: None,
         });
 
         // Add reg property
@@ -3106,6 +6683,7 @@ mod tests {
         device.add_property(Property {
             name: "reg",
             value: PropertyValue::U32Array(&reg_data),
+            raw: None,
         });
 
         // Test without translation (no ranges property)
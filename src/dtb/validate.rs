@@ -0,0 +1,334 @@
+// ABOUTME: Structural sanity checks on a parsed device tree, mirroring dtc -W warnings
+// ABOUTME: Reports missing cell counts, malformed reg, duplicate unit addresses, empty compatible
+
+use super::tree::{AddressSpec, DeviceTreeNode, PropertyValue};
+use alloc::{format, string::String, vec::Vec};
+use core::fmt::{self, Display, Formatter};
+
+/// Issue found by [`DeviceTreeNode::validate`].
+///
+/// Each warning carries the path of the node it applies to (e.g.,
+/// `/soc/uart@1000`); the root is `/`. Warnings do not prevent the tree from
+/// being used, but usually indicate a mistake in the device tree source.
+///
+/// # Examples
+///
+/// ```rust
+/// # use device_tree_parser::{DeviceTreeParser, DtbError};
+/// # fn example() -> Result<(), DtbError> {
+/// # let dtb_data = vec![0u8; 64]; // Mock data
+/// let parser = DeviceTreeParser::new(&dtb_data);
+///
+/// for warning in parser.validate()? {
+///     println!("Warning: {}", warning);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationWarning {
+    /// Node has addressed children but no `#address-cells` property.
+    MissingAddressCells {
+        /// Path of the parent node.
+        path: String,
+    },
+    /// Node has addressed children but no `#size-cells` property.
+    MissingSizeCells {
+        /// Path of the parent node.
+        path: String,
+    },
+    /// `reg` length is not a non-zero multiple of the parent's cell counts.
+    RegLengthMismatch {
+        /// Path of the node holding `reg`.
+        path: String,
+        /// Length of the `reg` property in bytes.
+        len: usize,
+        /// Size of one address/size entry in bytes.
+        entry_size: usize,
+    },
+    /// Two or more sibling nodes share the same unit address.
+    DuplicateUnitAddress {
+        /// Path of the parent node.
+        path: String,
+        /// The duplicated unit address (the part after `@`).
+        unit_address: String,
+    },
+    /// `compatible` property is present but holds no strings.
+    EmptyCompatible {
+        /// Path of the node holding `compatible`.
+        path: String,
+    },
+}
+
+impl ValidationWarning {
+    /// Path of the node the warning applies to.
+    #[must_use]
+    pub fn path(&self) -> &str {
+        match self {
+            ValidationWarning::MissingAddressCells { path }
+            | ValidationWarning::MissingSizeCells { path }
+            | ValidationWarning::RegLengthMismatch { path, .. }
+            | ValidationWarning::DuplicateUnitAddress { path, .. }
+            | ValidationWarning::EmptyCompatible { path } => path,
+        }
+    }
+}
+
+impl Display for ValidationWarning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationWarning::MissingAddressCells { path } => {
+                write!(f, "{path}: missing #address-cells for addressed children")
+            }
+            ValidationWarning::MissingSizeCells { path } => {
+                write!(f, "{path}: missing #size-cells for addressed children")
+            }
+            ValidationWarning::RegLengthMismatch {
+                path,
+                len,
+                entry_size,
+            } => write!(
+                f,
+                "{path}: reg length {len} is not a multiple of {entry_size} bytes"
+            ),
+            ValidationWarning::DuplicateUnitAddress { path, unit_address } => {
+                write!(f, "{path}: duplicate unit address @{unit_address}")
+            }
+            ValidationWarning::EmptyCompatible { path } => {
+                write!(f, "{path}: empty compatible property")
+            }
+        }
+    }
+}
+
+impl DeviceTreeNode<'_> {
+    /// Check the tree for common structural mistakes.
+    ///
+    /// Mirrors a subset of the `dtc -W` checks:
+    /// - addressed children without `#address-cells`/`#size-cells` on the parent
+    /// - `reg` lengths that don't match the parent's cell counts
+    /// - sibling nodes sharing a unit address
+    /// - empty `compatible` properties
+    #[must_use]
+    pub fn validate(&self) -> Vec<ValidationWarning> {
+        let mut warnings = Vec::new();
+        validate_node(self, "", &mut warnings);
+        warnings
+    }
+}
+
+fn validate_node(node: &DeviceTreeNode<'_>, path: &str, warnings: &mut Vec<ValidationWarning>) {
+    let node_path = || {
+        if path.is_empty() {
+            String::from("/")
+        } else {
+            String::from(path)
+        }
+    };
+
+    if let Some(compatible) = node.find_property("compatible")
+        && matches!(compatible.value, PropertyValue::Empty)
+    {
+        warnings.push(ValidationWarning::EmptyCompatible { path: node_path() });
+    }
+
+    let addressed = node
+        .children
+        .iter()
+        .any(|c| c.name.contains('@') || c.has_property("reg"));
+    if addressed {
+        if !node.has_property("#address-cells") {
+            warnings.push(ValidationWarning::MissingAddressCells { path: node_path() });
+        }
+        if !node.has_property("#size-cells") {
+            warnings.push(ValidationWarning::MissingSizeCells { path: node_path() });
+        }
+    }
+
+    let address_cells = node
+        .prop_u32("#address-cells")
+        .unwrap_or(AddressSpec::DEFAULT_ADDRESS_CELLS);
+    let size_cells = node
+        .prop_u32("#size-cells")
+        .unwrap_or(AddressSpec::DEFAULT_SIZE_CELLS);
+    let entry_size = (address_cells as usize + size_cells as usize) * 4;
+
+    let mut unit_addresses: Vec<&str> = Vec::new();
+    let mut duplicates: Vec<&str> = Vec::new();
+    for child in &node.children {
+        let child_path = format!("{path}/{}", child.name);
+
+        if let Some(reg) = child.find_property("reg") {
+            let len = reg.value.encoded_len();
+            if entry_size > 0 && (len == 0 || !len.is_multiple_of(entry_size)) {
+                warnings.push(ValidationWarning::RegLengthMismatch {
+                    path: child_path.clone(),
+                    len,
+                    entry_size,
+                });
+            }
+        }
+
+        if let Some((_, unit_address)) = child.name.split_once('@') {
+            if unit_addresses.contains(&unit_address) {
+                if !duplicates.contains(&unit_address) {
+                    duplicates.push(unit_address);
+                }
+            } else {
+                unit_addresses.push(unit_address);
+            }
+        }
+
+        validate_node(child, &child_path, warnings);
+    }
+
+    for unit_address in duplicates {
+        warnings.push(ValidationWarning::DuplicateUnitAddress {
+            path: node_path(),
+            unit_address: String::from(unit_address),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dtb::DeviceTreeParser;
+    use crate::dtb::test_support::StructBuilder;
+    use crate::dtb::tree::Property;
+
+    fn node_with<'a>(name: &'a str, props: &[(&'a str, PropertyValue<'a>)]) -> DeviceTreeNode<'a> {
+        let mut node = DeviceTreeNode::new(name);
+        for (prop_name, value) in props {
+            node.add_property(Property {
+                name: prop_name,
+                value: value.clone(),
+                raw: None,
+            });
+        }
+        node
+    }
+
+    fn cells(address: u32, size: u32) -> [(&'static str, PropertyValue<'static>); 2] {
+        [
+            ("#address-cells", PropertyValue::U32(address)),
+            ("#size-cells", PropertyValue::U32(size)),
+        ]
+    }
+
+    const REG_1_1: &[u8] = &[0, 0, 0x10, 0, 0, 0, 0x01, 0];
+
+    #[test]
+    fn test_validate_clean_tree() {
+        let mut root = node_with("", &cells(1, 1));
+        root.add_child(node_with(
+            "uart@1000",
+            &[
+                ("compatible", PropertyValue::String("ns16550a")),
+                ("reg", PropertyValue::U32Array(REG_1_1)),
+            ],
+        ));
+        assert!(root.validate().is_empty());
+    }
+
+    #[test]
+    fn test_validate_missing_cells() {
+        let mut soc = DeviceTreeNode::new("soc");
+        soc.add_child(node_with(
+            "uart@1000",
+            &[("reg", PropertyValue::U32Array(&[0u8; 12]))],
+        ));
+        let mut root = node_with("", &cells(1, 1));
+        root.add_child(soc);
+
+        assert_eq!(
+            root.validate(),
+            [
+                ValidationWarning::MissingAddressCells {
+                    path: String::from("/soc")
+                },
+                ValidationWarning::MissingSizeCells {
+                    path: String::from("/soc")
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_reg_length() {
+        let mut root = node_with("", &cells(2, 2));
+        root.add_child(node_with(
+            "memory@40000000",
+            &[("reg", PropertyValue::U32Array(REG_1_1))],
+        ));
+
+        let warnings = root.validate();
+        assert_eq!(
+            warnings,
+            [ValidationWarning::RegLengthMismatch {
+                path: String::from("/memory@40000000"),
+                len: 8,
+                entry_size: 16,
+            }]
+        );
+        assert_eq!(warnings[0].path(), "/memory@40000000");
+    }
+
+    #[test]
+    fn test_validate_duplicate_unit_address() {
+        let mut root = node_with("", &cells(1, 1));
+        for name in ["uart@1000", "serial@1000", "gpio@2000"] {
+            root.add_child(node_with(
+                name,
+                &[("reg", PropertyValue::U32Array(REG_1_1))],
+            ));
+        }
+
+        let warnings = root.validate();
+        assert_eq!(
+            warnings,
+            [ValidationWarning::DuplicateUnitAddress {
+                path: String::from("/"),
+                unit_address: String::from("1000"),
+            }]
+        );
+        assert_eq!(
+            alloc::string::ToString::to_string(&warnings[0]),
+            "/: duplicate unit address @1000"
+        );
+    }
+
+    #[test]
+    fn test_validate_empty_compatible() {
+        let mut root = node_with("", &cells(1, 1));
+        root.add_child(node_with("widget", &[("compatible", PropertyValue::Empty)]));
+
+        assert_eq!(
+            root.validate(),
+            [ValidationWarning::EmptyCompatible {
+                path: String::from("/widget")
+            }]
+        );
+    }
+
+    #[test]
+    fn test_validate_parsed_empty_compatible() {
+        // compatible = "" is stored as a lone terminator
+        let dtb = StructBuilder::new()
+            .begin_node("")
+            .begin_node("widget")
+            .named_property("compatible", b"\0")
+            .end_node()
+            .end_node()
+            .end()
+            .build_dtb();
+        let root = DeviceTreeParser::new(&dtb).parse_tree().unwrap();
+
+        assert_eq!(
+            root.validate(),
+            [ValidationWarning::EmptyCompatible {
+                path: String::from("/widget")
+            }]
+        );
+    }
+}
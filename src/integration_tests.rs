@@ -1,8 +1,10 @@
 // ABOUTME: Integration tests using real DTB files from QEMU
 // ABOUTME: Validates parser functionality against actual device tree data
 
-use crate::dtb::{DeviceTreeParser, DtbHeader, DtbToken, MemoryReservation};
-use alloc::vec::Vec;
+use crate::dtb::{
+    DeviceTreeParser, DtbHeader, DtbToken, MemoryReservation, ParseOptions, PropertyValue,
+};
+use alloc::{string::String, vec, vec::Vec};
 
 /// Load the QEMU virt DTB file for testing
 fn load_qemu_dtb() -> Vec<u8> {
@@ -66,6 +68,16 @@ mod real_dtb_tests {
         assert!(header.version >= 16, "DTB version should be 16 or higher");
     }
 
+    #[test]
+    fn test_qemu_dtb_header_block_ranges() {
+        let dtb_data = load_qemu_dtb();
+        let (_, header) = DtbHeader::parse(&dtb_data).unwrap();
+
+        assert_eq!(header.struct_block_range().unwrap(), 64..64 + 6980);
+        assert_eq!(header.strings_block_range().unwrap(), 7044..7044 + 462);
+        assert_eq!(header.mem_rsvmap_offset().unwrap(), 48);
+    }
+
     #[test]
     fn test_qemu_dtb_memory_reservations() {
         let dtb_data = load_qemu_dtb();
@@ -80,21 +92,32 @@ mod real_dtb_tests {
         // Parse memory reservations
         let result = MemoryReservation::parse_all(mem_rsvmap_data);
 
-        match result {
-            Ok((_, _reservations)) => {
-                // Memory reservations parsed successfully
-                // QEMU virt machine typically has no memory reservations, so empty list is normal
-                // Length is always valid if parsing succeeded
-            }
-            Err(_) => {
-                // This might be expected if the alignment is not correct in our test setup
-                // Let's at least verify we can read the beginning of the block
-                assert!(
-                    mem_rsvmap_data.len() >= 16,
-                    "Should have at least one reservation entry"
-                );
-            }
-        }
+        // QEMU virt machine typically has no memory reservations, so empty list is normal
+        let (_, reservations) = result.expect("Failed to parse memory reservations");
+        assert!(reservations.is_empty());
+    }
+
+    #[test]
+    fn test_qemu_dtb_unaligned_buffer() {
+        let dtb_data = load_qemu_dtb();
+
+        // Embed the DTB at an odd offset within a larger buffer
+        let mut buffer = vec![0u8; dtb_data.len() + 1];
+        buffer[1..].copy_from_slice(&dtb_data);
+        let unaligned = &buffer[1..];
+        assert!(!(unaligned.as_ptr() as usize).is_multiple_of(4));
+
+        let parser = DeviceTreeParser::new(unaligned);
+        let reservations = parser
+            .parse_memory_reservations()
+            .expect("Failed to parse memory reservations");
+        assert!(reservations.is_empty());
+
+        let tree = parser.parse_tree().expect("Failed to parse device tree");
+        let aligned_tree = DeviceTreeParser::new(&dtb_data).parse_tree().unwrap();
+        assert_eq!(tree.iter_nodes().count(), aligned_tree.iter_nodes().count());
+        assert_eq!(parser.model().unwrap(), Some("linux,dummy-virt"));
+        assert_eq!(parser.uart_addresses().unwrap().len(), 1);
     }
 
     #[test]
@@ -264,6 +287,492 @@ mod real_dtb_tests {
             .expect("Failed to check timebase frequency");
     }
 
+    #[test]
+    fn test_qemu_dtb_enabled_compatible_nodes() {
+        let dtb_data = load_qemu_dtb();
+        let parser = DeviceTreeParser::new(&dtb_data);
+
+        // QEMU virt has no disabled nodes, so both searches agree
+        let all = parser.find_compatible_nodes("virtio,mmio").unwrap();
+        let enabled = parser.find_enabled_compatible_nodes("virtio,mmio").unwrap();
+        assert!(!all.is_empty());
+        assert_eq!(all.len(), enabled.len());
+        assert!(enabled.iter().all(|node| node.is_enabled()));
+    }
+
+    #[test]
+    fn test_qemu_dtb_model_and_root_compatible() {
+        let dtb_data = load_qemu_dtb();
+        let parser = DeviceTreeParser::new(&dtb_data);
+
+        assert_eq!(parser.model().unwrap(), Some("linux,dummy-virt"));
+        assert_eq!(parser.root_compatible().unwrap(), vec!["linux,dummy-virt"]);
+
+        // Streaming results agree with the full tree
+        let root = parser.parse_tree().unwrap();
+        assert_eq!(parser.model().unwrap(), root.prop_string("model"));
+        assert_eq!(parser.root_compatible().unwrap(), root.compatible());
+    }
+
+    #[test]
+    fn test_qemu_dtb_iter_paths() {
+        let dtb_data = load_qemu_dtb();
+        let parser = DeviceTreeParser::new(&dtb_data);
+        let root = parser.parse_tree().unwrap();
+
+        let paths: Vec<String> = root.iter_paths().map(|(path, _)| path).collect();
+        assert_eq!(paths.len(), root.iter_nodes().count());
+        for expected in [
+            "/",
+            "/chosen",
+            "/cpus/cpu@0",
+            "/pl011@9000000",
+            "/pcie@10000000",
+            "/virtio_mmio@a000000",
+        ] {
+            assert!(paths.iter().any(|p| p == expected), "missing {expected}");
+        }
+
+        let (path, node) = root
+            .iter_paths()
+            .find(|(_, node)| node.name == "pl011@9000000")
+            .unwrap();
+        assert_eq!(path, "/pl011@9000000");
+        assert!(node.is_compatible("arm,pl011"));
+    }
+
+    #[test]
+    fn test_qemu_dtb_path_index() {
+        let dtb_data = load_qemu_dtb();
+        let parser = DeviceTreeParser::new(&dtb_data);
+        let root = parser.parse_tree().unwrap();
+
+        let index = root.build_path_index();
+        assert_eq!(index.len(), root.iter_nodes().count());
+        for (path, node) in &index {
+            assert!(
+                core::ptr::eq(root.find_node(path).unwrap(), *node),
+                "{path}"
+            );
+        }
+        assert!(index.contains_key("/cpus/cpu@0"));
+    }
+
+    #[test]
+    fn test_qemu_dtb_checksum() {
+        let mut dtb_data = load_qemu_dtb();
+        let checksum = DeviceTreeParser::new(&dtb_data).checksum();
+        assert_eq!(checksum, 0xAEA1_48F1);
+
+        // Flipping a single bit anywhere in the blob changes the checksum
+        dtb_data[0x100] ^= 0x01;
+        assert_ne!(DeviceTreeParser::new(&dtb_data).checksum(), checksum);
+    }
+
+    #[test]
+    fn test_qemu_dtb_standard_schema() {
+        let dtb_data = load_qemu_dtb();
+        let heuristic = DeviceTreeParser::new(&dtb_data).parse_tree().unwrap();
+        let parser = DeviceTreeParser::new(&dtb_data).with_standard_schema();
+        let tree = parser.parse_tree().unwrap();
+
+        // Same shape, and accessors agree with the heuristic decoding
+        assert_eq!(tree.iter_nodes().count(), heuristic.iter_nodes().count());
+        for (path, node) in tree.iter_paths() {
+            let other = heuristic.find_node(&path).unwrap();
+            assert_eq!(node.compatible(), other.compatible(), "{path}");
+            assert_eq!(
+                node.prop_u32_array("reg"),
+                other.prop_u32_array("reg"),
+                "{path}"
+            );
+            assert_eq!(node.phandle(), other.phandle(), "{path}");
+        }
+        let pl011 = tree.find_node("/pl011@9000000").unwrap();
+        assert_eq!(
+            pl011.find_property("compatible").unwrap().value,
+            PropertyValue::StringList(vec!["arm,pl011", "arm,primecell"])
+        );
+        assert_eq!(parser.model().unwrap(), Some("linux,dummy-virt"));
+    }
+
+    #[test]
+    fn test_qemu_dtb_all_compatibles() {
+        let dtb_data = load_qemu_dtb();
+        let parser = DeviceTreeParser::new(&dtb_data);
+
+        let compatibles = parser.all_compatibles().unwrap();
+        assert_eq!(compatibles.len(), 20);
+        assert!(compatibles.windows(2).all(|w| w[0] < w[1]));
+        // 32 virtio-mmio transports share one binding
+        assert!(compatibles.contains(&"virtio,mmio"));
+        assert!(compatibles.contains(&"linux,dummy-virt"));
+        assert!(compatibles.contains(&"arm,primecell"));
+    }
+
+    #[test]
+    fn test_qemu_dtb_interrupt_routing() {
+        let dtb_data = load_qemu_dtb();
+        let parser = DeviceTreeParser::new(&dtb_data);
+
+        let pl011 = parser.find_node_ref("/pl011@9000000").unwrap().unwrap();
+        let routing = pl011.interrupt_routing(&parser).unwrap();
+        assert_eq!(routing.len(), 1);
+        assert_eq!(routing[0].controller, "/intc@8000000");
+        assert_eq!(routing[0].cells, [0, 1, 4]);
+        assert_eq!(pl011.interrupt_cells(&parser).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_qemu_dtb_header_to_bytes() {
+        let dtb_data = load_qemu_dtb();
+        let header = DeviceTreeParser::new(&dtb_data).parse_header().unwrap();
+
+        let bytes = header.to_bytes();
+        assert_eq!(bytes[..], dtb_data[..DtbHeader::SIZE]);
+        assert_eq!(DtbHeader::parse(&bytes).unwrap().1, header);
+    }
+
+    #[test]
+    fn test_qemu_dtb_stats() {
+        let dtb_data = load_qemu_dtb();
+        let parser = DeviceTreeParser::new(&dtb_data);
+        let stats = parser.stats().unwrap();
+        let root = parser.parse_tree().unwrap();
+
+        assert_eq!(stats.node_count, root.iter_nodes().count());
+        assert_eq!(
+            stats.property_count,
+            root.iter_nodes().map(|n| n.properties.len()).sum::<usize>()
+        );
+        assert_eq!(stats.node_count, 57);
+        assert_eq!(stats.max_depth, 6);
+        assert!(stats.property_bytes > 0);
+    }
+
+    #[test]
+    fn test_qemu_dtb_root_property() {
+        let dtb_data = load_qemu_dtb();
+        let parser = DeviceTreeParser::new(&dtb_data);
+        let root = parser.parse_tree().unwrap();
+
+        for name in ["#address-cells", "#size-cells", "model", "interrupt-parent"] {
+            assert_eq!(
+                parser.root_property(name).unwrap().as_ref(),
+                root.find_property(name).map(|p| &p.value),
+                "{name}"
+            );
+        }
+        assert_eq!(
+            parser.root_property("#address-cells").unwrap(),
+            Some(PropertyValue::U32(2))
+        );
+        // Properties of child nodes are not visible at the root
+        assert!(parser.root_property("bootargs").unwrap().is_none());
+        assert!(parser.root_property("no-such-property").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_qemu_dtb_property_names() {
+        let dtb_data = load_qemu_dtb();
+        let parser = DeviceTreeParser::new(&dtb_data);
+
+        let names = parser
+            .property_names()
+            .expect("Failed to read strings block");
+        assert_eq!(names.len(), 41, "QEMU virt strings block holds 41 names");
+        assert_eq!(names[0], "compatible");
+        assert!(names.contains(&"reg"));
+        assert!(names.contains(&"#address-cells"));
+
+        // Every property in the tree resolves to a name from the strings block
+        let root = parser.parse_tree().expect("Failed to parse device tree");
+        for node in root.iter_nodes() {
+            for prop in &node.properties {
+                assert!(names.contains(&prop.name), "Missing name {}", prop.name);
+            }
+        }
+    }
+
+    #[test]
+    fn test_qemu_dtb_strict_cells() {
+        let dtb_data = load_qemu_dtb();
+        let parser = DeviceTreeParser::new(&dtb_data);
+
+        let options = ParseOptions {
+            validate_cells: true,
+            ..ParseOptions::default()
+        };
+        let strict = parser
+            .parse_tree_with_options(&options)
+            .expect("QEMU virt cell counts are valid");
+        let lenient = parser.parse_tree().expect("Failed to parse device tree");
+        assert_eq!(strict.iter_nodes().count(), lenient.iter_nodes().count());
+    }
+
+    #[test]
+    fn test_qemu_dtb_iter_property() {
+        let dtb_data = load_qemu_dtb();
+        let parser = DeviceTreeParser::new(&dtb_data);
+        let root = parser.parse_tree().expect("Failed to parse device tree");
+
+        let regs: Vec<_> = root.iter_property("reg").collect();
+        assert_eq!(regs.len(), 42, "QEMU virt has 42 nodes with a reg property");
+        assert_eq!(regs.len(), root.find_nodes_with_property("reg").len());
+
+        let virtio_regs = regs
+            .iter()
+            .filter(|(node, _)| node.name.starts_with("virtio_mmio@"))
+            .count();
+        assert_eq!(virtio_regs, 32);
+    }
+
+    #[test]
+    fn test_qemu_dtb_cpus() {
+        let dtb_data = load_qemu_dtb();
+        let parser = DeviceTreeParser::new(&dtb_data);
+
+        let cpus = parser.cpus().expect("Failed to enumerate CPUs");
+        assert_eq!(cpus.len(), 1, "QEMU virt is configured with a single CPU");
+
+        let cpu = &cpus[0];
+        assert_eq!(cpu.name, "cpu@0");
+        assert_eq!(cpu.reg, 0);
+        assert_eq!(cpu.device_type, Some("cpu"));
+        assert!(!cpu.compatible.is_empty());
+    }
+
+    #[test]
+    fn test_qemu_dtb_bus_nodes() {
+        let dtb_data = load_qemu_dtb();
+        let parser = DeviceTreeParser::new(&dtb_data);
+        let root = parser.parse_tree().expect("Failed to parse device tree");
+
+        let mut buses: Vec<&str> = root.iter_bus_nodes().map(|node| node.name).collect();
+        buses.sort_unstable();
+        // The GIC's empty ranges maps its v2m child one-to-one
+        assert_eq!(
+            buses,
+            ["intc@8000000", "pcie@10000000", "platform-bus@c000000"]
+        );
+    }
+
+    #[test]
+    fn test_qemu_dtb_reg_lengths() {
+        let dtb_data = load_qemu_dtb();
+        let parser = DeviceTreeParser::new(&dtb_data);
+        let root = parser.parse_tree().expect("Failed to parse device tree");
+
+        let mut checked = 0;
+        for (parent, node) in root.iter_with_parent() {
+            if node.has_property("reg") {
+                assert_eq!(node.validate_reg(parent), Ok(()), "{}", node.name);
+                checked += 1;
+            }
+        }
+        assert!(checked > 32);
+    }
+
+    #[test]
+    fn test_qemu_dtb_header_alignment() {
+        let dtb_data = load_qemu_dtb();
+        let parser = DeviceTreeParser::new(&dtb_data);
+        let header = parser.parse_header().expect("Failed to parse header");
+        assert_eq!(header.validate_alignment(), Ok(()));
+    }
+
+    #[test]
+    fn test_qemu_dtb_blocks() {
+        let dtb_data = load_qemu_dtb();
+        let parser = DeviceTreeParser::new(&dtb_data);
+        let header = parser.parse_header().expect("Failed to parse header");
+
+        let struct_block = parser
+            .struct_block()
+            .expect("Failed to get structure block");
+        assert_eq!(struct_block.len(), header.size_dt_struct as usize);
+        let strings_block = parser.strings_block().expect("Failed to get strings block");
+        assert_eq!(strings_block.len(), header.size_dt_strings as usize);
+        assert_eq!(strings_block.last(), Some(&0));
+    }
+
+    #[test]
+    fn test_qemu_dtb_boot_cpu() {
+        let dtb_data = load_qemu_dtb();
+        let parser = DeviceTreeParser::new(&dtb_data);
+
+        let boot = parser
+            .boot_cpu()
+            .expect("Failed to find boot CPU")
+            .expect("QEMU virt should have a boot CPU");
+        assert_eq!(boot.name, "cpu@0");
+    }
+
+    #[test]
+    fn test_qemu_dtb_cached_tree() {
+        let dtb_data = load_qemu_dtb();
+        let parser = DeviceTreeParser::new(&dtb_data);
+
+        let cached = parser.parsed().expect("Failed to parse device tree");
+        let fresh = parser.parse_tree().expect("Failed to parse device tree");
+        assert_eq!(cached.iter_nodes().count(), fresh.iter_nodes().count());
+        for (a, b) in cached.iter_nodes().zip(fresh.iter_nodes()) {
+            assert_eq!(a.name, b.name);
+            assert_eq!(a.properties.len(), b.properties.len());
+        }
+
+        // Subsequent calls hand back the same cached tree
+        let again = parser.parsed().expect("Failed to parse device tree");
+        assert!(core::ptr::eq(cached, again));
+
+        // High-level helpers give the same answers whether or not the cache is warm
+        let cold = DeviceTreeParser::new(&dtb_data);
+        assert_eq!(
+            cold.uart_addresses().unwrap(),
+            parser.uart_addresses().unwrap()
+        );
+        assert_eq!(
+            cold.discover_mmio_regions().unwrap(),
+            parser.discover_mmio_regions().unwrap()
+        );
+        assert_eq!(
+            cold.find_node("/cpus").unwrap().map(|n| n.children.len()),
+            parser.find_node("/cpus").unwrap().map(|n| n.children.len())
+        );
+    }
+
+    #[test]
+    fn test_qemu_dtb_borrowed_lookups() {
+        let dtb_data = load_qemu_dtb();
+        let parser = DeviceTreeParser::new(&dtb_data);
+
+        let virtio_count = parser
+            .with_tree(|root| root.find_compatible_nodes("virtio,mmio").len())
+            .expect("Failed to parse device tree");
+        assert_eq!(virtio_count, 32);
+
+        let borrowed = parser.find_compatible_nodes_ref("virtio,mmio").unwrap();
+        assert_eq!(borrowed.len(), virtio_count);
+
+        let mut visited = Vec::new();
+        parser
+            .for_each_compatible("virtio,mmio", |node| visited.push(node.name))
+            .unwrap();
+        assert!(visited.iter().eq(borrowed.iter().map(|node| &node.name)));
+        assert_eq!(
+            parser.find_compatible_nodes("virtio,mmio").unwrap().len(),
+            virtio_count
+        );
+
+        let cpus = parser
+            .find_node_ref("/cpus")
+            .unwrap()
+            .expect("/cpus exists");
+        assert_eq!(cpus.name, "cpus");
+        assert!(parser.find_node_ref("/does-not-exist").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_qemu_dtb_alias_lookup() {
+        let dtb_data = load_qemu_dtb();
+        let parser = DeviceTreeParser::new(&dtb_data);
+
+        let serial = parser
+            .find_node_flexible("serial0")
+            .unwrap()
+            .expect("serial0 alias should resolve");
+        assert_eq!(serial.name, "pl011@9000000");
+        assert!(serial.is_compatible("arm,pl011"));
+
+        let by_path = parser.find_node_flexible("/pl011@9000000").unwrap();
+        assert_eq!(by_path.map(|node| node.name), Some("pl011@9000000"));
+        assert!(parser.find_node_flexible("serial1").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_qemu_dtb_device_at_address() {
+        let dtb_data = load_qemu_dtb();
+        let parser = DeviceTreeParser::new(&dtb_data);
+
+        // PL011 UART registers live at 0x9000000
+        assert_eq!(
+            parser.device_at_address(0x0900_0018).unwrap(),
+            Some((String::from("/pl011@9000000"), 0x18))
+        );
+        assert_eq!(
+            parser.device_at_address(0x0a00_0210).unwrap(),
+            Some((String::from("/virtio_mmio@a000200"), 0x10))
+        );
+        assert_eq!(parser.device_at_address(0x8_0000_0000_0000).unwrap(), None);
+    }
+
+    #[test]
+    fn test_qemu_dtb_address_space_summary() {
+        let dtb_data = load_qemu_dtb();
+        let parser = DeviceTreeParser::new(&dtb_data);
+
+        let summary = parser.address_space_summary().unwrap();
+        assert!(summary.windows(2).all(|w| w[0].1 <= w[1].1));
+        assert!(summary.iter().all(|(_, start, end)| start < end));
+        assert!(summary.contains(&(String::from("/pl011@9000000"), 0x0900_0000, 0x0900_1000)));
+        assert!(summary.contains(&(
+            String::from("/memory@40000000"),
+            0x4000_0000,
+            0x4000_0000 + 0x800_0000
+        )));
+        // CPU IDs have no size and are not address regions
+        assert!(!summary.iter().any(|(path, _, _)| path.starts_with("/cpus")));
+        // A node with several reg entries is listed once per entry
+        let flash: Vec<_> = summary.iter().filter(|(p, _, _)| p == "/flash@0").collect();
+        assert_eq!(flash.len(), 2);
+        assert_eq!(summary.last().unwrap().0, "/pcie@10000000");
+    }
+
+    #[test]
+    fn test_qemu_dtb_mmio_map() {
+        let dtb_data = load_qemu_dtb();
+        let parser = DeviceTreeParser::new(&dtb_data);
+
+        let map = parser.mmio_map().expect("Failed to build MMIO map");
+        assert_eq!(map.len(), parser.address_space_summary().unwrap().len());
+
+        let uart = map
+            .iter()
+            .find(|entry| entry.path == "/pl011@9000000")
+            .expect("UART should be mapped");
+        assert_eq!(uart.child_address, 0x0900_0000);
+        assert_eq!(uart.physical_address, 0x0900_0000);
+        assert_eq!(uart.size, 0x1000);
+
+        // The v2m frame sits behind the GIC's empty (identity) ranges
+        let v2m = map
+            .iter()
+            .find(|entry| entry.path == "/intc@8000000/v2m@8020000")
+            .expect("GICv2m frame should be mapped");
+        assert_eq!(v2m.child_address, 0x0802_0000);
+        assert_eq!(v2m.physical_address, 0x0802_0000);
+
+        assert!(!map.iter().any(|entry| entry.path.starts_with("/cpus")));
+    }
+
+    #[test]
+    fn test_qemu_dtb_parse_all() {
+        let dtb_data = load_qemu_dtb();
+        let parser = DeviceTreeParser::new(&dtb_data);
+
+        let (header, reservations, tree) = parser.parse().expect("Failed to parse DTB");
+
+        assert_eq!(header, parser.parse_header().unwrap());
+        assert_eq!(header.magic, DtbHeader::MAGIC);
+        assert_eq!(reservations, parser.parse_memory_reservations().unwrap());
+        assert!(reservations.is_empty());
+        assert_eq!(tree.prop_string("model"), Some("linux,dummy-virt"));
+        assert_eq!(
+            tree.iter_nodes().count(),
+            parser.parse_tree().unwrap().iter_nodes().count()
+        );
+    }
+
     #[test]
     fn test_qemu_dtb_address_translation() {
         let dtb_data = load_qemu_dtb();
@@ -336,8 +845,7 @@ mod real_dtb_tests {
                         let translation_result =
                             node.translate_address(test_addr, None, address_cells);
 
-                        if translation_result.is_ok() {
-                            let translated = translation_result.unwrap();
+                        if let Ok(translated) = translation_result {
                             assert_ne!(translated, 0, "Translated address should be non-zero");
                         }
                     }
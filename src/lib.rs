@@ -59,12 +59,16 @@ mod integration_tests;
 
 // Re-export main types
 pub use dtb::{
-    AddressRange, AddressSpec, DeviceTreeNode, DeviceTreeParser, DtbError, DtbHeader, DtbToken,
-    MemoryReservation, NodeIterator, Property, PropertyValue,
+    AddressRange, AddressSpec, CpuInfo, DeviceTreeNode, DeviceTreeParser, DtbError, DtbHeader,
+    DtbStats, DtbToken, GpioSpec, InterruptSpec, MemoryReservation, MmioEntry, NamedSpecifier,
+    NodeIterator, OwnedDeviceTree, OwnedProperty, OwnedPropertyValue, ParseOptions, PciAddress,
+    PciRange, PciSpace, PrintOptions, PropKind, Property, PropertyValue, RangesKind,
+    RepairedStrings, ReservedRegion, Status, StructCursor, StructEvent, TreeDiff,
+    ValidationWarning,
 };
 
 // Re-export utility functions
-pub use dtb::tree::parse_address_from_bytes;
+pub use dtb::tree::{parse_address_from_bytes, parse_address_from_bytes_full};
 
 #[cfg(test)]
 mod tests {